@@ -0,0 +1,53 @@
+// Clipboard: the `clipboard` object against the in-memory backend. The
+// copy / cut / paste DOM events need a real key event from the window
+// and are covered by the native-window examples.
+
+import test from "ava";
+
+import {clipboard, ClipboardEvent, UIEvent} from "./_shim.ts";
+
+test.beforeEach(() => {
+  clipboard.setBackend("memory");
+});
+
+test("memory backend starts empty", async (t) => {
+  t.is(await clipboard.readText(), "");
+  t.is(await clipboard.readImage(), null);
+});
+
+test("writeText / readText round-trip", async (t) => {
+  await clipboard.writeText("héllo 👋");
+  t.is(await clipboard.readText(), "héllo 👋");
+});
+
+test("writeImage / readImage round-trip RGBA pixels", async (t) => {
+  const data = new Uint8Array([255, 0, 0, 255, 0, 255, 0, 128]);
+  await clipboard.writeImage({width: 2, height: 1, data});
+  const image = await clipboard.readImage();
+  t.truthy(image);
+  t.is(image!.width, 2);
+  t.is(image!.height, 1);
+  t.deepEqual(Array.from(image!.data), Array.from(data));
+});
+
+test("writing one format replaces the other", async (t) => {
+  await clipboard.writeText("text");
+  await clipboard.writeImage({width: 1, height: 1, data: new Uint8Array(4)});
+  t.is(await clipboard.readText(), "");
+  await clipboard.writeText("again");
+  t.is(await clipboard.readImage(), null);
+});
+
+test("writeImage rejects a buffer of the wrong size", async (t) => {
+  await t.throwsAsync(() =>
+    clipboard.writeImage({width: 2, height: 2, data: new Uint8Array(4)}),
+  );
+});
+
+test("setBackend rejects unknown kinds", (t) => {
+  t.throws(() => clipboard.setBackend("nope" as "memory"));
+});
+
+test("ClipboardEvent is a UIEvent", (t) => {
+  t.true(ClipboardEvent.prototype instanceof UIEvent);
+});
@@ -0,0 +1,65 @@
+// `HTMLDocument.dispose()`: native state is freed and every later access
+// through the document or its nodes throws.
+
+import test from "ava";
+
+import {pluckDocument, pluckNode} from "./_helpers.ts";
+import {HTMLDocument} from "./_shim.ts";
+import type {InputDataHandle, NativeNode} from "./_shim.ts";
+
+const disposedError = {message: /document is disposed/};
+
+test("dispose flips `disposed` and is idempotent", (t) => {
+  const doc = HTMLDocument.create();
+  t.false(doc.disposed);
+  doc.dispose();
+  t.true(doc.disposed);
+  t.notThrows(() => doc.dispose());
+});
+
+test("nodes of a disposed document throw on access", (t) => {
+  const doc = HTMLDocument.create();
+  const el = doc.createElement("div");
+  doc.body!.appendChild(el);
+  const attrs = el.attributes;
+  const style = el.style;
+  doc.dispose();
+
+  t.throws(() => el.getAttribute("id"), disposedError);
+  t.throws(() => el.textContent, disposedError);
+  t.throws(() => attrs.id, disposedError);
+  t.throws(() => style.color, disposedError);
+  t.throws(() => doc.createElement("span"), disposedError);
+  t.throws(() => doc.body, disposedError);
+});
+
+test("raw native handles of a disposed document throw instead of aborting", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = '<div id="box"></div><input id="field" value="x">';
+  const native = pluckDocument(doc)._native;
+  const node = pluckNode(doc.getElementById("box")!)._handle as NativeNode;
+  const input = doc.getElementById("field")! as unknown as {
+    _inputDataHandle: InputDataHandle;
+  };
+  const inputData = input._inputDataHandle;
+  doc.dispose();
+
+  t.throws(() => node.getAttribute("id"), disposedError);
+  t.throws(() => node.textContent(), disposedError);
+  t.throws(() => {
+    node.scrollTop = 10;
+  }, disposedError);
+  t.throws(() => node.focus(), disposedError);
+  t.throws(() => inputData.value, disposedError);
+  t.throws(() => inputData.select(), disposedError);
+  t.throws(() => native.getAttribute(0n, "id"), disposedError);
+  t.throws(() => native.resolve(0), disposedError);
+  t.throws(() => native.scrollX, disposedError);
+  t.throws(() => native.elementFromPoint(0, 0), disposedError);
+});
+
+test("Symbol.dispose disposes the document", (t) => {
+  const doc = HTMLDocument.create();
+  doc[Symbol.dispose]();
+  t.true(doc.disposed);
+});
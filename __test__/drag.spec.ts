@@ -0,0 +1,112 @@
+// Drag-and-drop: element drags fed through the native pointer pipeline,
+// and `DataTransfer` access modes. For the latter the native `DragData` is
+// stood in for by a plain object with the same shape.
+
+import test from "ava";
+
+import {pluckDocument} from "./_helpers.ts";
+import {DataTransfer, type DragData, HTMLDocument} from "./_shim.ts";
+
+function fakeDrag(files: string[] = []): DragData {
+  const items = new Map<string, string>();
+  const data = {
+    getData: (format: string) => items.get(format) ?? "",
+    setData: (format: string, value: string) => void items.set(format, value),
+    clearData: (format?: string) => (format ? items.delete(format) : items.clear()),
+    get types() {
+      return [...items.keys()];
+    },
+  };
+  return {files, data, dropEffect: "none", clientX: 0, clientY: 0} as unknown as DragData;
+}
+
+test("dragstart data is readable in drop", (t) => {
+  const drag = fakeDrag();
+  new DataTransfer(drag, "read/write").setData("text/plain", "card-7");
+  const drop = new DataTransfer(drag, "read-only");
+  t.is(drop.getData("text/plain"), "card-7");
+  t.deepEqual(drop.types, ["text/plain"]);
+});
+
+test("protected mode exposes types only", (t) => {
+  const drag = fakeDrag(["/tmp/a.png"]);
+  new DataTransfer(drag, "read/write").setData("text/plain", "x");
+  const over = new DataTransfer(drag, "protected");
+  t.is(over.getData("text/plain"), "");
+  t.deepEqual(over.files, []);
+  t.deepEqual(over.types, ["text/plain", "Files"]);
+  over.setData("text/plain", "changed");
+  t.is(new DataTransfer(drag, "read-only").getData("text/plain"), "x");
+});
+
+test("read-only mode ignores writes", (t) => {
+  const drag = fakeDrag(["/tmp/a.png"]);
+  const drop = new DataTransfer(drag, "read-only");
+  drop.setData("text/plain", "nope");
+  t.deepEqual(drop.types, ["Files"]);
+  t.deepEqual(drop.files, ["/tmp/a.png"]);
+});
+
+test("dropEffect writes through to the shared drag state", (t) => {
+  const drag = fakeDrag();
+  new DataTransfer(drag, "protected").dropEffect = "move";
+  t.is(drag.dropEffect, "move");
+});
+
+function board(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="card" draggable="true" style="position:absolute;left:0;top:0;width:50px;height:50px">' +
+    '<span id="label">card</span></div>' +
+    '<div id="zone" style="position:absolute;left:100px;top:0;width:50px;height:50px"></div>';
+  doc.resolve();
+  return doc;
+}
+
+test("a pointer drag fires dragstart, drop and dragend but no pointerup or click", (t) => {
+  const doc = board();
+  const card = doc.getElementById("card")!;
+  const zone = doc.getElementById("zone")!;
+  const events: string[] = [];
+  card.addEventListener("dragstart", (e) => events.push(`${e.type}@card`));
+  card.addEventListener("dragend", (e) => events.push(`${e.type}@card`));
+  zone.addEventListener("dragover", (e) => e.preventDefault());
+  zone.addEventListener("drop", (e) => events.push(`${e.type}@zone`));
+  doc.body!.addEventListener("pointerup", () => events.push("pointerup"));
+  doc.body!.addEventListener("click", () => events.push("click"));
+
+  const native = pluckDocument(doc)._native;
+  native.dispatchPointerDown(10, 10);
+  native.dispatchPointerMove(20, 10, 1);
+  t.deepEqual(events, ["dragstart@card"]);
+  native.dispatchPointerMove(120, 10, 1);
+  native.dispatchPointerUp(120, 10);
+  t.deepEqual(events, ["dragstart@card", "drop@zone", "dragend@card"]);
+
+  // The release reached blitz: the next press and release is a click.
+  native.dispatchPointerDown(120, 10);
+  native.dispatchPointerUp(120, 10);
+  t.deepEqual(events.slice(3), ["pointerup", "click"]);
+});
+
+test("the drag image is hidden from script", (t) => {
+  const doc = board();
+  const body = doc.body!;
+  const zone = doc.getElementById("zone")!;
+  const before = body.innerHTML;
+  const native = pluckDocument(doc)._native;
+  native.dispatchPointerDown(10, 10);
+  native.dispatchPointerMove(20, 10, 1);
+
+  t.is(body.childNodes.length, 2);
+  t.is(body.lastChild, zone);
+  t.is(zone.nextSibling, null);
+  t.is(doc.querySelectorAll("span").length, 1);
+  t.is(doc.querySelectorAll("[draggable]").length, 1);
+  t.is(doc.getElementById("label"), doc.querySelector("#card span"));
+  t.is(body.innerHTML, before);
+
+  native.dispatchPointerUp(20, 10);
+  t.is(body.childNodes.length, 2);
+  t.is(body.innerHTML, before);
+});
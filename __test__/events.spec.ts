@@ -11,7 +11,7 @@
 
 import test from "ava";
 
-import {HTMLDocument, Node, PointerEvent, UIEvent} from './_shim.ts';
+import {GestureEvent, HTMLDocument, Node, PointerEvent, TouchEvent, UIEvent} from './_shim.ts';
 
 /**
  * Simulate the Rust-side bubble walk: dispatch `event` on `target`,
@@ -116,3 +116,44 @@ test("event.target stays pinned to the originating node", (t) => {
 
   t.is(observed, inner);
 });
+
+test("TouchEvent exposes indexable touch lists", (t) => {
+  const doc = HTMLDocument.create();
+  const el = doc.createElement("div");
+  const point = (identifier: number, clientX: number) => ({
+    identifier,
+    target: () => el,
+    clientX,
+    clientY: 0,
+    pageX: clientX,
+    pageY: 0,
+    screenX: clientX,
+    screenY: 0,
+    force: 0,
+  });
+  const first = point(3, 10);
+  const second = point(7, 20);
+
+  const event = new TouchEvent(
+    {type: "touchend", bubbles: true, cancelable: true} as never,
+    {touches: [first], targetTouches: [first], changedTouches: [second], ctrlKey: true} as never,
+  );
+
+  t.is(event.touches.length, 1);
+  t.is(event.touches[0].identifier, 3);
+  t.is(event.changedTouches.item(0)?.clientX, 20);
+  t.is(event.changedTouches.item(1), null);
+  t.is(event.targetTouches[0].target, el);
+  t.deepEqual([...event.touches].map((touch) => touch.identifier), [3]);
+  t.true(event.ctrlKey);
+});
+
+test("GestureEvent reports the accumulated gesture", (t) => {
+  const event = new GestureEvent(
+    {type: "gesturechange", bubbles: true, cancelable: true} as never,
+    {kind: "pinch", scale: 1.5, rotation: 0, deltaX: 0, deltaY: 0} as never,
+  );
+  t.is(event.kind, "pinch");
+  t.is(event.scale, 1.5);
+  t.true(event.cancelable);
+});
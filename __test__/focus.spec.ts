@@ -0,0 +1,83 @@
+// Focus: `focus()` / `blur()` fire focus events synchronously with
+// `relatedTarget`, `document.activeElement` tracks the focused element,
+// and `moveFocus` walks the `tabindex` order like the Tab key.
+
+import test from "ava";
+
+import {HTMLDocument, type HTMLElement} from "./_shim.ts";
+
+function form(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<input id="a">' +
+    '<div id="plain">not focusable</div>' +
+    '<button id="b">b</button>' +
+    '<div id="first" tabindex="1">first</div>' +
+    '<input id="skipped" tabindex="-1">' +
+    '<input id="hidden" style="display:none">';
+  doc.resolve();
+  return doc;
+}
+
+function byId(doc: HTMLDocument, id: string): HTMLElement {
+  return doc.getElementById(id) as HTMLElement;
+}
+
+test("activeElement falls back to body and follows focus()", (t) => {
+  const doc = form();
+  t.is(doc.activeElement, doc.body);
+  byId(doc, "a").focus();
+  t.is(doc.activeElement, byId(doc, "a"));
+  byId(doc, "a").blur();
+  t.is(doc.activeElement, doc.body);
+});
+
+test("focus() ignores elements that are not focusable", (t) => {
+  const doc = form();
+  byId(doc, "plain").focus();
+  t.is(doc.activeElement, doc.body);
+});
+
+test("focus change fires blur, focusout, focus, focusin with relatedTarget", (t) => {
+  const doc = form();
+  const a = byId(doc, "a");
+  const b = byId(doc, "b");
+  a.focus();
+
+  const log: string[] = [];
+  for (const type of ["blur", "focusout", "focus", "focusin"]) {
+    doc.body!.addEventListener(type, (e) => {
+      if (e.eventPhase !== Event.BUBBLING_PHASE) return;
+      const related = (e as FocusEvent).relatedTarget as HTMLElement | null;
+      log.push(`${type}:${(e.target as HTMLElement).id}:${related?.id ?? "null"}`);
+    });
+    a.addEventListener(type, (e) => {
+      if (e.eventPhase === Event.AT_TARGET) log.push(`${type}@a`);
+    });
+  }
+  b.focus();
+
+  // focus / blur do not bubble; only focusout / focusin reach body in the
+  // bubble phase.
+  t.deepEqual(log, ["blur@a", "focusout@a", "focusout:a:b", "focusin:b:a"]);
+});
+
+test("moveFocus follows tabindex order and wraps", (t) => {
+  const doc = form();
+  const order: string[] = [];
+  for (let i = 0; i < 4; i++) {
+    t.true(doc.moveFocus());
+    order.push((doc.activeElement as HTMLElement).id);
+  }
+  t.deepEqual(order, ["first", "a", "b", "first"]);
+
+  t.true(doc.moveFocus(false));
+  t.is((doc.activeElement as HTMLElement).id, "b");
+});
+
+test("removing the focused element resets activeElement", (t) => {
+  const doc = form();
+  byId(doc, "a").focus();
+  byId(doc, "a").remove();
+  t.is(doc.activeElement, doc.body);
+});
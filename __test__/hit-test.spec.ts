@@ -0,0 +1,47 @@
+// Hit testing: `elementFromPoint` / `elementsFromPoint` on a headless
+// document. Boxes use explicit absolute geometry so the result does not
+// depend on the (unset) viewport size.
+
+import test from "ava";
+
+import {HTMLDocument} from "./_shim.ts";
+
+function boxes(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="outer" style="position:absolute;left:0;top:0;width:200px;height:200px">' +
+    '<div id="inner" style="position:absolute;left:50px;top:50px;width:50px;height:50px">text</div>' +
+    "</div>";
+  doc.resolve();
+  return doc;
+}
+
+test("elementFromPoint returns the topmost element", (t) => {
+  const doc = boxes();
+  t.is(doc.elementFromPoint(75, 75), doc.getElementById("inner"));
+  t.is(doc.elementFromPoint(10, 10), doc.getElementById("outer"));
+});
+
+test("elementFromPoint returns null for negative coordinates", (t) => {
+  const doc = boxes();
+  t.is(doc.elementFromPoint(-1, 10), null);
+});
+
+test("elementsFromPoint lists the hit element and its ancestors", (t) => {
+  const doc = boxes();
+  const hits = doc.elementsFromPoint(75, 75);
+  t.is(hits[0], doc.getElementById("inner"));
+  t.is(hits[1], doc.getElementById("outer"));
+  t.false(hits.includes(doc.getElementById("inner")!.firstChild as never));
+});
+
+test("elementsFromPoint ends with body and html below the body's content", (t) => {
+  const doc = boxes();
+  // `<body>` only holds absolutely positioned boxes, so it has no height.
+  t.is(doc.body!.getBoundingClientRect()!.height, 0);
+  t.deepEqual(doc.elementsFromPoint(10, 150), [
+    doc.getElementById("outer"),
+    doc.body,
+    doc.documentElement,
+  ]);
+});
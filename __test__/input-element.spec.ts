@@ -175,6 +175,18 @@ test("HTMLInputElement.checked on parsed checkbox reads the attribute", (t) => {
   t.true(input.checked);
 });
 
+test("HTMLInputElement.files starts empty and can be cleared", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = '<input type="file" multiple>';
+  const input = doc.body!.firstChild as HTMLInputElement;
+  t.deepEqual(input.files, []);
+  input.files = null;
+  t.deepEqual(input.files, []);
+  t.throws(() => {
+    input.files = ["/tmp/a.png"];
+  });
+});
+
 // ---- HTMLTextAreaElement: attribute-backed properties ----------------------
 
 test("HTMLTextAreaElement.rows defaults to 2 and round-trips", (t) => {
@@ -279,3 +291,57 @@ test("HTMLInputElement wrapper identity is stable across queries", (t) => {
   const b = doc.querySelector("#i1");
   t.is(a, b);
 });
+
+// ---- Selection ----------------------------------------------------------------
+
+function laidOutInput(value: string): HTMLInputElement {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = `<input id="i" value="${value}">`;
+  doc.resolve();
+  return doc.getElementById("i") as HTMLInputElement;
+}
+
+test("setSelectionRange clamps and reports direction", (t) => {
+  const input = laidOutInput("hello");
+  input.setSelectionRange(1, 3, "backward");
+  t.is(input.selectionStart, 1);
+  t.is(input.selectionEnd, 3);
+  t.is(input.selectionDirection, "backward");
+
+  input.setSelectionRange(4, 99);
+  t.is(input.selectionStart, 4);
+  t.is(input.selectionEnd, 5);
+  t.is(input.selectionDirection, "forward");
+});
+
+test("selection offsets count UTF-16 code units", (t) => {
+  const input = laidOutInput("a😀b");
+  input.select();
+  t.is(input.selectionStart, 0);
+  t.is(input.selectionEnd, 4);
+});
+
+test("setRangeText replaces text and applies selectMode", (t) => {
+  const input = laidOutInput("hello world");
+  input.setRangeText("there", 6, 11, "select");
+  t.is(input.value, "hello there");
+  t.is(input.selectionStart, 6);
+  t.is(input.selectionEnd, 11);
+
+  input.setSelectionRange(0, 5);
+  input.setRangeText("hi");
+  t.is(input.value, "hi there");
+  t.is(input.selectionStart, 0);
+  t.is(input.selectionEnd, 2);
+
+  t.throws(() => input.setRangeText("x", 3, 1));
+});
+
+test("select() fires selectionchange and select", (t) => {
+  const input = laidOutInput("abc");
+  const seen: string[] = [];
+  input.addEventListener("selectionchange", () => seen.push("selectionchange"));
+  input.addEventListener("select", () => seen.push("select"));
+  input.select();
+  t.deepEqual(seen, ["selectionchange", "select"]);
+});
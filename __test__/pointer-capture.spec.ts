@@ -0,0 +1,62 @@
+// Pointer capture on a headless document: the bookkeeping, and the
+// got/lost events around pointer input fed by `NativeDoc.dispatchTouch`.
+
+import test from "ava";
+
+import {pluckDocument} from "./_helpers.ts";
+import {HTMLDocument} from "./_shim.ts";
+
+test("hasPointerCapture follows set/release", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = '<div id="a"></div><div id="b"></div>';
+  const a = doc.getElementById("a")!;
+  const b = doc.getElementById("b")!;
+
+  a.setPointerCapture(1);
+  t.true(a.hasPointerCapture(1));
+  t.false(a.hasPointerCapture(2));
+  t.false(b.hasPointerCapture(1));
+
+  // Releasing from a node that does not hold the capture is a no-op.
+  b.releasePointerCapture(1);
+  t.true(a.hasPointerCapture(1));
+
+  a.releasePointerCapture(1);
+  t.false(a.hasPointerCapture(1));
+});
+
+test("setPointerCapture throws on a detached element", (t) => {
+  const doc = HTMLDocument.create();
+  const div = doc.createElement("div");
+  t.throws(() => div.setPointerCapture(1));
+});
+
+test("removing the capturing element drops its capture", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = '<div id="a"><span id="s"></span></div>';
+  const s = doc.getElementById("s")!;
+  s.setPointerCapture(1);
+  doc.getElementById("a")!.remove();
+  t.false(s.hasPointerCapture(1));
+});
+
+test("pointercancel releases the capture and fires lostpointercapture", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="a" style="position:absolute;left:0;top:0;width:50px;height:50px"></div>';
+  doc.resolve();
+  const native = pluckDocument(doc)._native;
+  const a = doc.getElementById("a")!;
+  const seen: string[] = [];
+  a.addEventListener("pointerdown", (e) => a.setPointerCapture((e as PointerEvent).pointerId));
+  a.addEventListener("gotpointercapture", (e) => seen.push(e.type));
+  a.addEventListener("lostpointercapture", (e) => seen.push(e.type));
+
+  native.dispatchTouch("start", 3, 10, 10);
+  native.dispatchTouch("move", 3, 20, 10);
+  t.deepEqual(seen, ["gotpointercapture"]);
+  t.true(a.hasPointerCapture(3));
+  native.dispatchTouch("cancel", 3, 20, 10);
+  t.deepEqual(seen, ["gotpointercapture", "lostpointercapture"]);
+  t.false(a.hasPointerCapture(3));
+});
@@ -0,0 +1,285 @@
+// `NativeDoc.dispatchPointerMove`: headless pointer input through the
+// native dispatch pipeline (hit test, then the JS capture / bubble walk).
+// `queuePointerMove` coalesces moves until the next frame, as windows do.
+// Each dispatch also sweeps a bounded batch of collected wrappers.
+
+import {setFlagsFromString} from "node:v8";
+import {runInNewContext} from "node:vm";
+
+import test from "ava";
+
+import {pluckDocument} from "./_helpers.ts";
+import {HTMLDocument} from "./_shim.ts";
+
+function box(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="box" style="position:absolute;left:0;top:0;width:100px;height:100px"></div>';
+  doc.resolve();
+  return doc;
+}
+
+test("dispatchPointerMove targets the hit element and bubbles", (t) => {
+  const doc = box();
+  const target = doc.getElementById("box")!;
+  const seen: string[] = [];
+  let clientX = -1;
+  target.addEventListener("pointermove", (e) => {
+    seen.push("box");
+    clientX = (e as PointerEvent).clientX;
+  });
+  doc.body!.addEventListener("pointermove", (e) => {
+    seen.push("body");
+    t.is(e.target, target);
+  });
+
+  pluckDocument(doc)._native.dispatchPointerMove(40, 60);
+  t.deepEqual(seen, ["box", "body"]);
+  t.is(clientX, 40);
+});
+
+test("events nobody listens for wrap no nodes", (t) => {
+  const doc = box();
+  const native = pluckDocument(doc)._native;
+  const before = doc.stats().cachedWrappers;
+  native.dispatchPointerMove(40, 60);
+  t.is(doc.stats().cachedWrappers, before);
+
+  // A listener on the body only wraps the body, not the hit target.
+  let calls = 0;
+  doc.body!.addEventListener("pointermove", () => calls++);
+  const withBody = doc.stats().cachedWrappers;
+  native.dispatchPointerMove(41, 60);
+  t.is(calls, 1);
+  t.is(doc.stats().cachedWrappers, withBody);
+});
+
+test("duplicate adds and unknown removes keep listener counts exact", (t) => {
+  const doc = box();
+  const native = pluckDocument(doc)._native;
+  const target = doc.getElementById("box")!;
+  let calls = 0;
+  const listener = () => calls++;
+
+  target.addEventListener("pointermove", listener);
+  target.addEventListener("pointermove", listener);
+  target.removeEventListener("pointermove", () => {});
+  target.removeEventListener("pointermove", listener, {capture: true});
+  native.dispatchPointerMove(40, 60);
+  t.is(calls, 1);
+
+  target.removeEventListener("pointermove", listener);
+  target.addEventListener("pointermove", listener, {capture: true});
+  native.dispatchPointerMove(41, 60);
+  t.is(calls, 2);
+});
+
+test("document listeners receive events without wrapping the path", (t) => {
+  const doc = box();
+  let target: EventTarget | null = null;
+  doc.addEventListener("pointermove", (e) => {
+    target = e.target;
+  });
+  pluckDocument(doc)._native.dispatchPointerMove(40, 60);
+  t.is(target, doc.getElementById("box"));
+});
+
+test("an unqueued pointermove coalesces only itself", (t) => {
+  const doc = box();
+  let coalesced: PointerEvent[] = [];
+  let predicted: PointerEvent[] = [];
+  doc.getElementById("box")!.addEventListener("pointermove", (e) => {
+    coalesced = (e as PointerEvent).getCoalescedEvents();
+    predicted = (e as PointerEvent).getPredictedEvents();
+  });
+
+  pluckDocument(doc)._native.dispatchPointerMove(40, 60);
+  t.is(coalesced.length, 1);
+  t.is(coalesced[0].type, "pointermove");
+  t.is(coalesced[0].clientX, 40);
+  t.is(coalesced[0].clientY, 60);
+  t.false(coalesced[0].bubbles);
+  t.deepEqual(predicted, []);
+});
+
+test("pointer events carry natively computed DOM fields", (t) => {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="outer" style="position:absolute;left:0;top:0;width:200px;height:200px">' +
+    '<div id="box" style="position:absolute;left:20px;top:10px;width:100px;height:100px"></div>' +
+    "</div>";
+  doc.resolve();
+  const native = pluckDocument(doc)._native;
+  const outer = doc.getElementById("outer")!;
+  const box = doc.getElementById("box")!;
+  const moves: PointerEvent[] = [];
+  let over: EventTarget | null = null;
+  let out: EventTarget | null = null;
+  box.addEventListener("pointermove", (e) => moves.push(e as PointerEvent));
+  box.addEventListener("pointerover", (e) => {
+    over = (e as PointerEvent).relatedTarget;
+  });
+  box.addEventListener("pointerout", (e) => {
+    out = (e as PointerEvent).relatedTarget;
+  });
+
+  native.dispatchPointerMove(150, 150);
+  native.dispatchPointerMove(40, 60);
+  native.dispatchPointerMove(45, 58);
+  native.dispatchPointerMove(150, 150);
+
+  t.is(moves.length, 2);
+  t.is(moves[0].offsetX, 20);
+  t.is(moves[0].offsetY, 50);
+  t.is(moves[1].movementX, 5);
+  t.is(moves[1].movementY, -2);
+  t.false(moves[1].ctrlKey);
+  t.false(moves[1].getModifierState("Control"));
+  t.is(moves[1].detail, 0);
+  t.true(moves[0].timeStamp <= moves[1].timeStamp);
+  t.true(Math.abs(moves[1].timeStamp - performance.now()) < 1000);
+  t.is(over, outer);
+  t.is(out, outer);
+});
+
+function recordMoves(doc: HTMLDocument): PointerEvent[] {
+  const moves: PointerEvent[] = [];
+  doc.getElementById("box")!.addEventListener("pointermove", (e) => {
+    moves.push(e as PointerEvent);
+  });
+  return moves;
+}
+
+test("queued moves are dispatched once, on the next frame", (t) => {
+  const doc = box();
+  const moves = recordMoves(doc);
+  const native = pluckDocument(doc)._native;
+
+  native.queuePointerMove(10, 10);
+  native.queuePointerMove(20, 15);
+  native.queuePointerMove(30, 20);
+  t.is(moves.length, 0);
+  doc.resolve();
+  t.is(moves.length, 1);
+  t.is(moves[0].clientX, 30);
+  t.is(moves[0].clientY, 20);
+  doc.resolve();
+  t.is(moves.length, 1);
+});
+
+test("a coalesced move reports every sample and predicts the next", (t) => {
+  const doc = box();
+  const moves = recordMoves(doc);
+  const native = pluckDocument(doc)._native;
+
+  native.queuePointerMove(10, 10);
+  native.queuePointerMove(20, 15);
+  native.queuePointerMove(30, 20);
+  doc.resolve();
+  const coalesced = moves[0].getCoalescedEvents();
+  t.deepEqual(
+    coalesced.map((e) => [e.type, e.clientX, e.clientY]),
+    [
+      ["pointermove", 10, 10],
+      ["pointermove", 20, 15],
+      ["pointermove", 30, 20],
+    ],
+  );
+  const predicted = moves[0].getPredictedEvents();
+  t.is(predicted.length, 1);
+  t.is(predicted[0].clientX, 40);
+  t.is(predicted[0].clientY, 25);
+});
+
+test("a single-sample move is predicted from the previous frame", (t) => {
+  const doc = box();
+  const moves = recordMoves(doc);
+  const native = pluckDocument(doc)._native;
+
+  native.queuePointerMove(10, 10);
+  doc.resolve();
+  t.deepEqual(moves[0].getPredictedEvents(), []);
+  native.queuePointerMove(20, 30);
+  doc.resolve();
+  t.is(moves[1].getCoalescedEvents().length, 1);
+  const predicted = moves[1].getPredictedEvents();
+  t.is(predicted.length, 1);
+  t.is(predicted[0].clientX, 30);
+  t.is(predicted[0].clientY, 50);
+});
+
+test("other pointer input flushes queued moves first", (t) => {
+  const doc = box();
+  const target = doc.getElementById("box")!;
+  const native = pluckDocument(doc)._native;
+  const seen: string[] = [];
+  for (const type of ["pointermove", "pointerdown", "pointerup"]) {
+    target.addEventListener(type, (e) => seen.push(`${e.type}@${(e as PointerEvent).clientX}`));
+  }
+
+  native.queuePointerMove(10, 10);
+  native.queuePointerMove(20, 20);
+  native.dispatchPointerDown(20, 20);
+  native.dispatchPointerUp(20, 20);
+  t.deepEqual(seen, ["pointermove@20", "pointerdown@20", "pointerup@20"]);
+});
+
+test("pointerup forgets the sample moves are predicted from", (t) => {
+  const doc = box();
+  const moves = recordMoves(doc);
+  const native = pluckDocument(doc)._native;
+
+  native.queuePointerMove(10, 10);
+  doc.resolve();
+  native.dispatchPointerDown(10, 10);
+  native.dispatchPointerUp(10, 10);
+  native.queuePointerMove(20, 10);
+  doc.resolve();
+  t.is(moves.length, 2);
+  t.deepEqual(moves[1].getPredictedEvents(), []);
+});
+
+test("dispatchPointerMove on a disposed document throws", (t) => {
+  const doc = box();
+  const native = pluckDocument(doc)._native;
+  doc.dispose();
+  t.throws(() => native.dispatchPointerMove(0, 0), {message: /document is disposed/});
+});
+
+// `SWEEP_BUDGET` in src/dom/node_cache.rs.
+const SWEEP_BUDGET = 64;
+
+setFlagsFromString("--expose-gc");
+const gc = runInNewContext("gc") as () => void;
+
+/** Collect garbage until `count` wrappers of `doc` wait to be swept. */
+async function collectWrappers(doc: HTMLDocument, count: number): Promise<void> {
+  for (let i = 0; i < 20 && doc.stats().collectedWrappers < count; i++) {
+    gc();
+    // Finalizers run after the collection, on a later turn.
+    await new Promise((resolve) => setImmediate(resolve));
+  }
+}
+
+test("each dispatch sweeps at most SWEEP_BUDGET collected wrappers", async (t) => {
+  const doc = box();
+  const native = pluckDocument(doc)._native;
+  const count = SWEEP_BUDGET * 2 + 10;
+  for (let i = 0; i < count; i++) {
+    doc.createElement("span");
+  }
+  await collectWrappers(doc, count);
+  const {collectedWrappers: queued, cachedWrappers: cached} = doc.stats();
+  t.true(queued >= count);
+
+  native.dispatchPointerMove(40, 60);
+  t.is(doc.stats().collectedWrappers, queued - SWEEP_BUDGET);
+  t.is(doc.stats().cachedWrappers, cached - SWEEP_BUDGET);
+
+  // Later dispatches drain the rest of the queue.
+  for (let i = 1; i < Math.ceil(queued / SWEEP_BUDGET); i++) {
+    native.dispatchPointerMove(40, 60);
+  }
+  t.is(doc.stats().collectedWrappers, 0);
+  t.is(doc.stats().cachedWrappers, cached - queued);
+});
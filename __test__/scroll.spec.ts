@@ -0,0 +1,82 @@
+// Element scrolling on a headless document: clamping to the scroll range,
+// `scroll` / `scrollend` events on the next frame (`resolve`), smooth
+// scrolls driven by the frame time and `scrollIntoView`.
+
+import test from "ava";
+
+import {HTMLDocument} from "./_shim.ts";
+
+function scroller(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="box" style="width:100px;height:100px;overflow:auto">' +
+    '<div style="height:50px"></div>' +
+    '<div id="item" style="width:50px;height:400px"></div>' +
+    "</div>";
+  doc.resolve();
+  return doc;
+}
+
+test("scrollTop is clamped to the scroll range", (t) => {
+  const doc = scroller();
+  const box = doc.getElementById("box")!;
+  box.scrollTop = -20;
+  t.is(box.scrollTop, 0);
+  box.scrollTop = 10_000;
+  t.is(box.scrollTop, box.scrollHeight - box.clientHeight);
+  t.true(box.scrollTop > 0);
+});
+
+test("elements that are not scroll containers do not scroll", (t) => {
+  const doc = scroller();
+  const item = doc.getElementById("item")!;
+  item.scrollTop = 30;
+  t.is(item.scrollTop, 0);
+});
+
+test("scroll and scrollend fire once on the next frame", (t) => {
+  const doc = scroller();
+  const box = doc.getElementById("box")!;
+  const events: string[] = [];
+  box.addEventListener("scroll", (e) => events.push(`${e.type}:${e.bubbles}`));
+  box.addEventListener("scrollend", (e) => events.push(e.type));
+  box.scrollTop = 10;
+  box.scrollBy(0, 10);
+  t.deepEqual(events, []);
+  doc.resolve();
+  t.deepEqual(events, ["scroll:false", "scrollend"]);
+  t.is(box.scrollTop, 20);
+  doc.resolve();
+  t.is(events.length, 2);
+});
+
+test("smooth scrolls advance with the frame time", (t) => {
+  const doc = scroller();
+  const box = doc.getElementById("box")!;
+  let ended = 0;
+  box.addEventListener("scrollend", () => ended++);
+  box.scrollTo({top: 100, behavior: "smooth"});
+  doc.resolve(1000);
+  t.is(box.scrollTop, 0);
+  doc.resolve(1150);
+  t.true(box.scrollTop > 0 && box.scrollTop < 100);
+  t.is(ended, 0);
+  doc.resolve(1300);
+  t.is(box.scrollTop, 100);
+  t.is(ended, 1);
+});
+
+test("scrollIntoView scrolls the enclosing scroll container", (t) => {
+  const doc = scroller();
+  const box = doc.getElementById("box")!;
+  doc.getElementById("item")!.scrollIntoView();
+  t.is(box.scrollTop, 50);
+  doc.getElementById("item")!.scrollIntoView({block: "nearest"});
+  t.is(box.scrollTop, 50);
+});
+
+test("invalid scroll behaviors throw", (t) => {
+  const doc = scroller();
+  const box = doc.getElementById("box")!;
+  t.throws(() => box.scrollTo({top: 10, behavior: "fast"}));
+});
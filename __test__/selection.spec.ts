@@ -0,0 +1,67 @@
+// Document `Selection` and `Range` on a headless document. Geometry uses
+// the resolved layout; mouse drag selection needs a window and is not
+// covered here.
+
+import test from "ava";
+
+import {HTMLDocument, type Node} from "./_shim.ts";
+
+function para(): {doc: HTMLDocument; first: Node; second: Node} {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML = '<p id="p">Hello <b>bold</b> world</p>';
+  doc.resolve();
+  const p = doc.getElementById("p")!;
+  return {doc, first: p.firstChild!, second: p.lastChild!};
+}
+
+test("Range.toString spans text nodes", (t) => {
+  const {doc, first, second} = para();
+  const range = doc.createRange();
+  range.setStart(first, 2);
+  range.setEnd(second, 3);
+  t.is(range.toString(), "llo bold wo");
+  t.false(range.collapsed);
+});
+
+test("setEnd before the start collapses the range", (t) => {
+  const {doc, first} = para();
+  const range = doc.createRange();
+  range.setStart(first, 4);
+  range.setEnd(first, 1);
+  t.true(range.collapsed);
+  t.is(range.startOffset, 1);
+});
+
+test("selectNodeContents and getClientRects", (t) => {
+  const {doc} = para();
+  const range = doc.createRange();
+  range.selectNodeContents(doc.getElementById("p")!);
+  t.is(range.toString(), "Hello bold world");
+  const rect = range.getBoundingClientRect();
+  t.true(rect.width > 0);
+  t.true(rect.height > 0);
+});
+
+test("offsets past the node length throw", (t) => {
+  const {doc, first} = para();
+  const range = doc.createRange();
+  t.throws(() => range.setStart(first, 99));
+});
+
+test("getSelection reflects setBaseAndExtent and fires selectionchange", (t) => {
+  const {doc, first, second} = para();
+  const selection = doc.getSelection();
+  t.is(selection.type, "None");
+
+  let changes = 0;
+  doc.addEventListener("selectionchange", () => changes++);
+  selection.setBaseAndExtent(second, 2, first, 0);
+  t.is(changes, 1);
+  t.is(selection.type, "Range");
+  t.is(selection.direction, "backward");
+  t.is(selection.toString(), "Hello bold wo");
+
+  selection.removeAllRanges();
+  t.is(changes, 2);
+  t.is(selection.rangeCount, 0);
+});
@@ -0,0 +1,60 @@
+// `document.stats()` and `addonStats()`: node, wrapper-cache and
+// stylesheet counters.
+
+import test from "ava";
+
+import {addonStats, HTMLDocument} from "./_shim.ts";
+
+// First, before other tests create documents the GC could collect midway.
+test("addonStats sums live documents and skips disposed ones", (t) => {
+  const before = addonStats().documents;
+  const doc = HTMLDocument.create();
+  t.is(addonStats().documents, before + 1);
+  t.true(addonStats().nodes >= doc.stats().nodes);
+  doc.dispose();
+  t.is(addonStats().documents, before);
+  t.throws(() => doc.stats(), {message: /document is disposed/});
+});
+
+test("stats counts connected nodes and strong wrappers", (t) => {
+  const doc = HTMLDocument.create();
+  const before = doc.stats();
+  const el = doc.createElement("div");
+  doc.body!.appendChild(el);
+  const after = doc.stats();
+
+  t.is(after.nodes, before.nodes + 1);
+  t.true(after.cachedWrappers >= 1);
+  t.true(after.strongWrappers >= 1);
+  t.is(after.cachedWrappers, after.strongWrappers + after.weakWrappers);
+  t.is(after.detachedNodes, 0);
+  t.true(after.approxBytes > before.approxBytes);
+  t.true(after.fontFamilies > 0);
+});
+
+test("detached subtrees held by a wrapper are reported weak", (t) => {
+  const doc = HTMLDocument.create();
+  const el = doc.createElement("div");
+  el.appendChild(doc.createElement("span"));
+  doc.body!.appendChild(el);
+  el.remove();
+
+  const stats = doc.stats();
+  t.is(stats.detachedNodes, 2);
+  t.true(stats.weakWrappers >= 1);
+  t.is(stats.detachedStrongWrappers, 0);
+  t.false(el.isConnected);
+});
+
+test("stats counts style and stylesheet link elements", (t) => {
+  const doc = HTMLDocument.create();
+  const before = doc.stats().stylesheets;
+  doc.head!.appendChild(doc.createElement("style"));
+  const link = doc.createElement("link");
+  link.setAttribute("rel", "preload stylesheet");
+  doc.head!.appendChild(link);
+  const icon = doc.createElement("link");
+  icon.setAttribute("rel", "icon");
+  doc.head!.appendChild(icon);
+  t.is(doc.stats().stylesheets, before + 2);
+});
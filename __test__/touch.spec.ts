@@ -0,0 +1,115 @@
+// Touch and trackpad gesture events on a headless document, fed through
+// the native input pipeline by `NativeDoc.dispatchTouch` and
+// `dispatchGesture`.
+
+import test from "ava";
+
+import {pluckDocument} from "./_helpers.ts";
+import {GestureEvent, HTMLDocument, PointerEvent, TouchEvent} from "./_shim.ts";
+
+function board(): HTMLDocument {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="a" style="position:absolute;left:0;top:0;width:50px;height:50px"></div>' +
+    '<div id="b" style="position:absolute;left:100px;top:0;width:50px;height:50px"></div>';
+  doc.resolve();
+  return doc;
+}
+
+function label(e: Event): string {
+  return `${e.type}@${(e.target as unknown as {id: string}).id}`;
+}
+
+test("touch events follow the pointer event and target where the finger started", (t) => {
+  const doc = board();
+  const native = pluckDocument(doc)._native;
+  const seen: string[] = [];
+  for (const type of ["pointerdown", "touchstart", "touchmove", "touchend"]) {
+    doc.addEventListener(type, (e) => seen.push(label(e)));
+  }
+
+  native.dispatchTouch("start", 1, 10, 10);
+  native.dispatchTouch("move", 1, 120, 10);
+  native.dispatchTouch("end", 1, 120, 10);
+  t.deepEqual(seen, ["pointerdown@a", "touchstart@a", "touchmove@a", "touchend@a"]);
+});
+
+test("touch events list every finger, those on the target and the changed one", (t) => {
+  const doc = board();
+  const native = pluckDocument(doc)._native;
+  const events: TouchEvent[] = [];
+  const primaries: boolean[] = [];
+  doc.addEventListener("touchstart", (e) => events.push(e as TouchEvent));
+  doc.addEventListener("touchend", (e) => events.push(e as TouchEvent));
+  doc.addEventListener("pointerdown", (e) => primaries.push((e as PointerEvent).isPrimary));
+
+  native.dispatchTouch("start", 1, 10, 10);
+  native.dispatchTouch("start", 2, 120, 10);
+  native.dispatchTouch("end", 1, 10, 10);
+
+  const [, second, end] = events;
+  t.is(second.touches.length, 2);
+  t.is(second.targetTouches.length, 1);
+  t.is(second.targetTouches[0].identifier, 2);
+  t.is(second.changedTouches[0].identifier, 2);
+  t.is(second.changedTouches[0].target, doc.getElementById("b"));
+  t.is(end.touches.length, 1);
+  t.is(end.touches[0].identifier, 2);
+  t.is(end.changedTouches[0].identifier, 1);
+  t.deepEqual(primaries, [true, false]);
+});
+
+test("touch events are not cancelable", (t) => {
+  const doc = board();
+  const native = pluckDocument(doc)._native;
+  const prevented: boolean[] = [];
+  doc.getElementById("a")!.addEventListener("touchstart", (e) => {
+    t.false(e.cancelable);
+    e.preventDefault();
+    prevented.push(e.defaultPrevented);
+  });
+
+  native.dispatchTouch("start", 1, 10, 10);
+  t.deepEqual(prevented, [false]);
+});
+
+test("gestures target the hovered element and accumulate", (t) => {
+  const doc = board();
+  const native = pluckDocument(doc)._native;
+  const seen: string[] = [];
+  for (const type of ["gesturestart", "gesturechange", "gestureend"]) {
+    doc.addEventListener(type, (e) => {
+      const gesture = e as GestureEvent;
+      seen.push(`${label(e)}:${gesture.kind}:${gesture.scale}`);
+    });
+  }
+
+  native.dispatchPointerMove(120, 10);
+  t.false(native.dispatchGesture("pinch", "start", 0));
+  t.false(native.dispatchGesture("pinch", "change", 0.5));
+  t.false(native.dispatchGesture("pinch", "end", 0));
+  t.deepEqual(seen, [
+    "gesturestart@b:pinch:1",
+    "gesturechange@b:pinch:1.5",
+    "gestureend@b:pinch:1.5",
+  ]);
+});
+
+test("only gesturechange can be canceled", (t) => {
+  const doc = board();
+  const native = pluckDocument(doc)._native;
+  for (const type of ["gesturestart", "gesturechange", "gestureend"]) {
+    doc.addEventListener(type, (e) => e.preventDefault());
+  }
+
+  t.false(native.dispatchGesture("pan", "start", 4, 2));
+  t.true(native.dispatchGesture("pan", "change", 4, 2));
+  t.false(native.dispatchGesture("pan", "end", 0, 0));
+});
+
+test("invalid touch phases and gesture kinds throw", (t) => {
+  const native = pluckDocument(board())._native;
+  t.throws(() => native.dispatchTouch("press", 1, 0, 0), {message: /invalid phase/});
+  t.throws(() => native.dispatchGesture("swipe", "start", 0), {message: /invalid kind/});
+  t.throws(() => native.dispatchGesture("pinch", "begin", 0), {message: /invalid phase/});
+});
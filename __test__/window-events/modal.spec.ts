@@ -0,0 +1,37 @@
+// Case: a modal window vetoes its owner's close while open, hands its
+// return value to `result`, and an owned window closes with its owner.
+
+import {closeWindow, createApp, newDoc, openWindow, pump, testFn} from "../_helpers.ts";
+
+testFn("modal blocks the owner's close and resolves its result", async (t) => {
+  const app = createApp();
+  const owner = await openWindow(app);
+  const modalPromise = app.openWindow(newDoc(), {modalTo: owner});
+  pump(app);
+  const modal = await modalPromise;
+  t.is(modal.owner, owner);
+
+  await t.throwsAsync(() => owner.close(), {message: /close prevented by a modal window/});
+  t.false(owner.closed);
+
+  const closing = modal.close("ok");
+  pump(app);
+  await closing;
+  t.is(await modal.result, "ok");
+
+  await closeWindow(app, owner);
+  t.true(owner.closed);
+});
+
+testFn("owned windows close with their owner", async (t) => {
+  const app = createApp();
+  const owner = await openWindow(app);
+  const childPromise = app.openWindow(newDoc(), {owner});
+  pump(app);
+  const child = await childPromise;
+
+  await closeWindow(app, owner);
+  pump(app);
+  t.true(child.closed);
+  t.is(await child.result, undefined);
+});
@@ -0,0 +1,29 @@
+// Case: resizing a window dispatches a `resize` WindowEvent on the window
+// carrying the new surface size, and its `window:resize` echo on the app
+// carrying the Window. The window manager applies the resize
+// asynchronously, so pump until it arrives.
+
+import {closeWindow, createApp, openWindow, pump, testFn} from "../_helpers.ts";
+import {WindowEvent} from "../_shim.ts";
+
+testFn("resize dispatches a WindowEvent and a window:resize echo", async (t) => {
+  const app = createApp();
+  const w = await openWindow(app);
+  const seen: WindowEvent[] = [];
+  let echoWindow: unknown = null;
+  w.addEventListener("resize", (e) => seen.push(e as WindowEvent));
+  app.addEventListener("window:resize", (e) => {
+    echoWindow = (e as Event & {window: unknown}).window;
+  });
+  w.resize(320, 240);
+  for (let i = 0; i < 50 && seen.length === 0; i++) {
+    pump(app);
+    await new Promise((r) => setTimeout(r, 10));
+  }
+  t.true(seen.length > 0);
+  t.true(seen[0] instanceof WindowEvent);
+  t.is(typeof seen[0].width, "number");
+  t.is(seen[0].x, null);
+  t.is(echoWindow, w);
+  await closeWindow(app, w);
+});
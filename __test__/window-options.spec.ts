@@ -29,3 +29,25 @@ test("window surface dimensions are validated before reaching winit", async (t)
     message: /width must be finite/,
   });
 });
+
+test("window position is validated before reaching winit", async (t) => {
+  const fractional = WindowOptions.builder();
+  fractional.position(10.5, 0);
+  await t.throwsAsync(() => app.openWindow(newDoc(), fractional), {
+    message: /x must be an integer/,
+  });
+
+  const huge = WindowOptions.builder();
+  huge.position(0, 2 ** 40);
+  await t.throwsAsync(() => app.openWindow(newDoc(), huge), {
+    message: /y is out of the i32 range/,
+  });
+});
+
+test("window level is validated before reaching winit", async (t) => {
+  const options = WindowOptions.builder();
+  options.windowLevel("topmost");
+  await t.throwsAsync(() => app.openWindow(newDoc(), options), {
+    message: /unknown level "topmost"/,
+  });
+});
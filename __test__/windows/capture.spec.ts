@@ -0,0 +1,94 @@
+// `Window.capture()` on a real OS window: the frame matches the surface
+// size and holds the painted document with its selection and drag image.
+// CI-skipped via the shared `testFn` (no display / GPU in CI containers).
+
+import {HTMLDocument, WindowOptions} from "../_shim.ts";
+import {closeWindow, createApp, pluckDocument, pump, testFn} from "../_helpers.ts";
+
+testFn('capture renders the document at the surface size', async (t) => {
+  const app = createApp();
+  const document = HTMLDocument.create({
+    baseHtml:
+      '<!doctype html><html><body style="margin: 0; background: rgb(255, 0, 0)"></body></html>',
+  });
+  const options = WindowOptions.builder();
+  options.size(200, 100);
+  const winPromise = app.openWindow(document, options);
+  pump(app);
+  const window = await winPromise;
+
+  const frame = await window.capture();
+  const [width, height] = window.innerSize!;
+  t.is(frame.width, width);
+  t.is(frame.height, height);
+  t.is(frame.data.length, width * height * 4);
+  t.true(frame.scale > 0);
+  t.deepEqual([...frame.data.subarray(0, 4)], [255, 0, 0, 255]);
+
+  await closeWindow(app, window);
+  await t.throwsAsync(window.capture(), {message: /window is closed/});
+});
+
+testFn('capture paints the document selection highlight', async (t) => {
+  const app = createApp();
+  const document = HTMLDocument.create({
+    baseHtml:
+      '<!doctype html><html><body style="margin: 0">' +
+      '<p id="text" style="margin: 0; font-size: 40px; color: white">xxxxxxxx</p>' +
+      '</body></html>',
+  });
+  const options = WindowOptions.builder();
+  options.size(200, 100);
+  const winPromise = app.openWindow(document, options);
+  pump(app);
+  const window = await winPromise;
+
+  const pixel = (frame: {width: number; scale: number; data: Uint8Array}, x: number, y: number) => {
+    const i = (Math.round(y * frame.scale) * frame.width + Math.round(x * frame.scale)) * 4;
+    return [...frame.data.subarray(i, i + 4)];
+  };
+  const before = await window.capture();
+  t.deepEqual(pixel(before, 2, 20), [255, 255, 255, 255]);
+
+  document.getSelection().selectAllChildren(document.getElementById('text')!);
+  const after = await window.capture();
+  const [r, g, b] = pixel(after, 2, 20);
+  t.true(b > r && b > g, `expected a blue tint, got ${[r, g, b]}`);
+
+  await closeWindow(app, window);
+});
+
+testFn('capture paints the drag image under the pointer', async (t) => {
+  const app = createApp();
+  const document = HTMLDocument.create({
+    baseHtml:
+      '<!doctype html><html><body style="margin: 0">' +
+      '<div draggable="true" style="width: 40px; height: 40px; background: rgb(255, 0, 0)"></div>' +
+      '</body></html>',
+  });
+  const options = WindowOptions.builder();
+  options.size(200, 100);
+  const winPromise = app.openWindow(document, options);
+  pump(app);
+  const window = await winPromise;
+
+  const pixel = (frame: {width: number; scale: number; data: Uint8Array}, x: number, y: number) => {
+    const i = (Math.round(y * frame.scale) * frame.width + Math.round(x * frame.scale)) * 4;
+    return [...frame.data.subarray(i, i + 4)];
+  };
+  const native = pluckDocument(document)._native;
+  native.dispatchPointerDown(10, 10);
+  native.dispatchPointerMove(110, 50, 1);
+  const frame = await window.capture();
+  // The element stays in place; its translucent image keeps the pointer
+  // 10px into it.
+  t.deepEqual(pixel(frame, 20, 20), [255, 0, 0, 255]);
+  const [r, g, b] = pixel(frame, 120, 60);
+  t.true(r > 200 && g < 200 && g === b, `expected a red tint, got ${[r, g, b]}`);
+  t.deepEqual(pixel(frame, 150, 60), [255, 255, 255, 255]);
+
+  native.dispatchPointerUp(110, 50);
+  t.deepEqual(pixel(await window.capture(), 120, 60), [255, 255, 255, 255]);
+
+  await closeWindow(app, window);
+});
@@ -0,0 +1,39 @@
+// `dispose()` on windows and the app: windows close without a cancelable
+// `close`, and a document cannot be disposed while a window shows it.
+// CI-skipped via the shared `testFn` (no display in CI containers).
+
+import {createApp, newDoc, openWindow, pump, testFn} from "../_helpers.ts";
+import {WindowOptions} from "../_shim.ts";
+
+testFn("a document shown in an open window cannot be disposed", async (t) => {
+  const app = createApp();
+  const w = await openWindow(app);
+  t.throws(() => w.document.dispose(), {message: /close the window first/});
+  t.false(w.document.disposed);
+
+  let closing = 0;
+  w.addEventListener("close", () => closing++);
+  const closed = new Promise<void>((resolve) => w.addEventListener("closed", () => resolve()));
+  w.dispose();
+  pump(app);
+  await closed;
+  t.is(closing, 0);
+
+  w.document.dispose();
+  t.true(w.document.disposed);
+  app.dispose();
+});
+
+testFn("app.dispose closes windows and rejects pending opens", async (t) => {
+  const app = createApp();
+  const w = await openWindow(app);
+  const closed = new Promise<void>((resolve) => w.addEventListener("closed", () => resolve()));
+  const pending = app.openWindow(newDoc(), WindowOptions.builder().size(200, 150));
+
+  app.dispose();
+  t.true(app.disposed);
+  await closed;
+  await t.throwsAsync(pending, {message: /app is disposed/});
+  t.throws(() => app.pumpAppEvents(0), {message: /app is disposed/});
+  t.notThrows(() => w.document.dispose());
+});
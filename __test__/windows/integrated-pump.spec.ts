@@ -0,0 +1,70 @@
+// `pumpLoop({integrated: true})`: the native watcher wakes the loop
+// without JS timers. A DOM mutation wakes it promptly, and closing the
+// last window ends the loop with `reason: 'exit'`. Only Linux / BSD event
+// loops can be watched; elsewhere the loop is refused. CI-skipped via the
+// shared `testFn` (no display in CI containers).
+
+import test from "ava";
+
+import {createApp, openWindow, testFn} from "../_helpers.ts";
+
+const watchable = ["linux", "freebsd", "openbsd", "netbsd"].includes(process.platform);
+const watchTest = watchable ? testFn : test.skip;
+const refusedTest = watchable ? test.skip : testFn;
+
+watchTest("integrated pump loop wakes on DOM mutations and exits with the app", async (t) => {
+  const app = createApp();
+  const w = await openWindow(app);
+  let pumps = 0;
+  let endReason: unknown = null;
+  app.addEventListener("pump", () => pumps++);
+  app.addEventListener("pump:end", (e) => {
+    endReason = (e as CustomEvent).detail.reason;
+  });
+
+  const looping = app.pumpLoop({integrated: true, join: true, maxWait: 5000});
+  t.true(app.pumping);
+  t.throws(() => app.pumpLoop({integrated: true}), {message: /already running/});
+
+  // Well below `maxWait`: only the mutation's wakeup can cause the pump.
+  const before = pumps;
+  w.document.body!.appendChild(w.document.createElement("div"));
+  for (let i = 0; i < 100 && pumps === before; i++) {
+    await new Promise((r) => setTimeout(r, 10));
+  }
+  t.true(pumps > before);
+
+  await app.closeWindow(w);
+  await looping;
+  t.false(app.pumping);
+  t.is(endReason, "exit");
+});
+
+watchTest("integrated pump loop stops on abort", async (t) => {
+  const app = createApp();
+  const w = await openWindow(app);
+  const controller = new AbortController();
+  let endReason: unknown = null;
+  app.addEventListener("pump:end", (e) => {
+    endReason = (e as CustomEvent).detail.reason;
+  });
+  const looping = app.pumpLoop({integrated: true, join: true, signal: controller.signal});
+  controller.abort();
+  await looping;
+  t.is(endReason, "aborted");
+  t.false(app.pumping);
+
+  // The timer-driven loop can take over again.
+  const again = app.pumpLoop({join: true, signal: AbortSignal.timeout(50)});
+  await again;
+  await app.closeWindow(w);
+});
+
+refusedTest("integrated pump loop is refused where the event loop cannot be watched", async (t) => {
+  const app = createApp();
+  await t.throwsAsync(app.pumpLoop({integrated: true, join: true}), {
+    message: /not supported on this platform/,
+  });
+  t.false(app.pumping);
+  app.dispose();
+});
@@ -2,7 +2,7 @@
 // (openWindow + pump -> vello render). CI containers lack GPU support (same
 // as dom-mutation-style.spec.ts), so it is CI-skipped via the shared `testFn`.
 
-import {closeWindow, createApp, newDoc, pump, testFn} from "../_helpers.ts";
+import {closeWindow, createApp, newDoc, openWindow, pump, testFn} from "../_helpers.ts";
 import {WindowOptions} from "../_shim.ts";
 
 testFn("window resize dimensions are validated at the napi boundary", async (t) => {
@@ -22,3 +22,90 @@ testFn("window resize dimensions are validated at the napi boundary", async (t)
 
   await closeWindow(app, window);
 });
+
+testFn("window geometry getters report the live window", async (t) => {
+  const app = createApp();
+  const options = WindowOptions.builder();
+  options.size(120, 90).center();
+  const winPromise = app.openWindow(newDoc(), options);
+  pump(app);
+  const window = await winPromise;
+
+  const [outerWidth, outerHeight] = window.outerSize!;
+  const [innerWidth, innerHeight] = window.innerSize!;
+  t.true(outerWidth >= innerWidth && outerHeight >= innerHeight);
+  t.is(typeof window.safeArea?.top, "number");
+  t.throws(() => window.setPosition(0.5, 0), {message: /x must be an integer/});
+
+  await closeWindow(app, window);
+  t.is(window.position, null);
+  t.is(window.outerSize, null);
+});
+
+testFn("startResize rejects unknown directions", async (t) => {
+  const app = createApp();
+  const window = await openWindow(app);
+  t.throws(() => window.startResize("up" as never), {
+    message: /unknown direction "up"/,
+  });
+  await closeWindow(app, window);
+});
+
+testFn("cursor overrides are validated at the napi boundary", async (t) => {
+  const app = createApp();
+  const window = await openWindow(app);
+  t.notThrows(() => window.setCursor("pointer"));
+  t.notThrows(() => window.setCursor(null));
+  t.throws(() => window.setCursor("pointr"), {message: /unknown cursor "pointr"/});
+  t.throws(
+    () => window.setCursor({width: 2, height: 2, data: new Uint8Array(3)}),
+    {message: /expected 16 bytes for 2x2 RGBA/},
+  );
+  t.throws(() => window.setCursorGrab("held" as never), {message: /unknown mode "held"/});
+  await closeWindow(app, window);
+});
+
+testFn("window state getters track minimize", async (t) => {
+  const app = createApp();
+  const options = WindowOptions.builder();
+  options.size(100, 100).windowLevel("always-on-top").skipTaskbar(true);
+  const winPromise = app.openWindow(newDoc(), options);
+  pump(app);
+  const window = await winPromise;
+
+  t.is(window.isMaximized, false);
+  t.is(typeof window.isFocused, "boolean");
+  t.throws(() => window.requestUserAttention("urgent" as never), {
+    message: /unknown kind "urgent"/,
+  });
+  window.requestUserAttention(null);
+
+  await closeWindow(app, window);
+  t.is(window.isMinimized, null);
+});
+
+testFn("pointer move coalescing is on by default and can be turned off", async (t) => {
+  const app = createApp();
+  const winPromise = app.openWindow(newDoc(), WindowOptions.builder());
+  pump(app);
+  const window = await winPromise;
+
+  t.true(window.coalescePointerMoves);
+  window.coalescePointerMoves = false;
+  t.false(window.coalescePointerMoves);
+
+  await closeWindow(app, window);
+});
+
+testFn("pinch zoom is off by default and can be turned on", async (t) => {
+  const app = createApp();
+  const winPromise = app.openWindow(newDoc(), WindowOptions.builder());
+  pump(app);
+  const window = await winPromise;
+
+  t.false(window.pinchZoom);
+  window.pinchZoom = true;
+  t.true(window.pinchZoom);
+
+  await closeWindow(app, window);
+});
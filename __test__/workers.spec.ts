@@ -0,0 +1,50 @@
+// The addon loaded in `worker_threads` Workers: every N-API environment
+// keeps its own constructor / event registry, so workers build documents
+// in parallel and the main thread's registry survives their teardown.
+
+import test from "ava";
+import {Worker} from "node:worker_threads";
+
+import {HTMLDocument, HTMLElement} from "./_shim.ts";
+
+const WORKER_SOURCE = `
+const {parentPort, workerData} = require("node:worker_threads");
+import(workerData.shim).then(({HTMLDocument, HTMLElement}) => {
+  const doc = HTMLDocument.create();
+  const p = doc.createElement("p");
+  p.textContent = "worker " + workerData.index;
+  doc.body.appendChild(p);
+  const wrapped = doc.body.firstChild;
+  parentPort.postMessage({
+    isElement: wrapped instanceof HTMLElement,
+    text: wrapped.textContent,
+  });
+});
+`;
+
+function runWorker(index: number): Promise<{isElement: boolean; text: string}> {
+  const worker = new Worker(WORKER_SOURCE, {
+    eval: true,
+    workerData: {index, shim: new URL("./_shim.ts", import.meta.url).href},
+  });
+  return new Promise((resolve, reject) => {
+    worker.once("message", (message) => {
+      void worker.terminate();
+      resolve(message);
+    });
+    worker.once("error", reject);
+  });
+}
+
+test("workers own their documents and registries", async (t) => {
+  const results = await Promise.all([runWorker(0), runWorker(1)]);
+  t.deepEqual(results, [
+    {isElement: true, text: "worker 0"},
+    {isElement: true, text: "worker 1"},
+  ]);
+
+  // The main thread's constructors were neither replaced nor released.
+  const doc = HTMLDocument.create();
+  doc.body!.appendChild(doc.createElement("div"));
+  t.true(doc.body!.firstChild instanceof HTMLElement);
+});
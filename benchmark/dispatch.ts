@@ -0,0 +1,52 @@
+// Pointer dispatch cost against NodeCache size.
+//
+// Each document wraps `size` extra nodes (one NodeCache entry each) and
+// then receives headless `pointermove`s through the native dispatch
+// pipeline. Cache maintenance per input event is bounded, so the time per
+// event should stay flat as the cache grows.
+//
+//   npm run bench:dispatch
+
+import {HTMLDocument, NativeDoc} from "../src-js/index.ts";
+
+type NativeDocHandle = InstanceType<typeof NativeDoc>;
+
+const SIZES = [1_000, 10_000, 50_000];
+const WARMUP = 200;
+const EVENTS = 5_000;
+
+function setup(size: number): {doc: HTMLDocument; native: NativeDocHandle} {
+  const doc = HTMLDocument.create();
+  doc.body!.innerHTML =
+    '<div id="target" style="position:absolute;left:0;top:0;width:100px;height:100px"></div>' +
+    `<div style="display:none">${"<span></span>".repeat(size)}</div>`;
+  doc.resolve();
+  // Wrap every span so it gets a cache entry.
+  doc.querySelectorAll("span");
+  doc.getElementById("target")!.addEventListener("pointermove", () => {});
+  const native = (doc as unknown as {_native: NativeDocHandle})._native;
+  return {doc, native};
+}
+
+function perEvent(native: NativeDocHandle, count: number): number {
+  const start = performance.now();
+  for (let i = 0; i < count; i++) {
+    native.dispatchPointerMove(10 + (i % 50), 10 + (i % 50));
+  }
+  return ((performance.now() - start) * 1000) / count;
+}
+
+const rows = SIZES.map((size) => {
+  const {doc, native} = setup(size);
+  perEvent(native, WARMUP);
+  const micros = perEvent(native, EVENTS);
+  const {cachedWrappers} = doc.stats();
+  doc.dispose();
+  return {cachedWrappers, "µs / pointermove": Number(micros.toFixed(2))};
+});
+
+console.table(rows);
+
+const first = rows[0]["µs / pointermove"];
+const last = rows[rows.length - 1]["µs / pointermove"];
+console.log(`largest / smallest cache: ${(last / first).toFixed(2)}x`);
@@ -700,7 +700,10 @@ if (!nativeBinding) {
 }
 
 module.exports = nativeBinding
+module.exports.DataTransferItems = nativeBinding.DataTransferItems
+module.exports.DragData = nativeBinding.DragData
 module.exports.EventPayload = nativeBinding.EventPayload
+module.exports.GestureData = nativeBinding.GestureData
 module.exports.ImeData = nativeBinding.ImeData
 module.exports.InputData = nativeBinding.InputData
 module.exports.InputDataHandle = nativeBinding.InputDataHandle
@@ -711,10 +714,18 @@ module.exports.NativeDoc = nativeBinding.NativeDoc
 module.exports.NativeNode = nativeBinding.NativeNode
 module.exports.NativeWindow = nativeBinding.NativeWindow
 module.exports.PointerData = nativeBinding.PointerData
+module.exports.TouchData = nativeBinding.TouchData
+module.exports.TouchPoint = nativeBinding.TouchPoint
 module.exports.VideoModeInfo = nativeBinding.VideoModeInfo
 module.exports.WheelData = nativeBinding.WheelData
+module.exports.WindowEventData = nativeBinding.WindowEventData
 module.exports.WindowHandle = nativeBinding.WindowHandle
 module.exports.WindowOptions = nativeBinding.WindowOptions
+module.exports.addonStats = nativeBinding.addonStats
+module.exports.clipboardReadImage = nativeBinding.clipboardReadImage
+module.exports.clipboardReadText = nativeBinding.clipboardReadText
+module.exports.clipboardWriteImage = nativeBinding.clipboardWriteImage
+module.exports.clipboardWriteText = nativeBinding.clipboardWriteText
 module.exports.initEnv = nativeBinding.initEnv
 module.exports.pickFile = nativeBinding.pickFile
 module.exports.pickFiles = nativeBinding.pickFiles
@@ -725,3 +736,4 @@ module.exports.registerElementConstructor = nativeBinding.registerElementConstru
 module.exports.registerEventFactory = nativeBinding.registerEventFactory
 module.exports.registerNodeConstructor = nativeBinding.registerNodeConstructor
 module.exports.saveFile = nativeBinding.saveFile
+module.exports.setClipboardBackend = nativeBinding.setClipboardBackend
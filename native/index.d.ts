@@ -1,5 +1,37 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+/**
+ * String data of a `DataTransfer` (`clipboardData`, drag data), keyed by
+ * format. Clones share their items, so Rust reads back what listeners
+ * set.
+ */
+export declare class DataTransferItems {
+  /** Data stored for `format`, or "" when there is none. */
+  getData(format: string): string
+  setData(format: string, data: string): void
+  /** Remove `format`, or every format when omitted. */
+  clearData(format?: string | undefined | null): void
+  /** Formats currently stored, in insertion order. */
+  get types(): Array<string>
+}
+
+/**
+ * Details of a drag-and-drop event. Every event of one drag shares the
+ * same `data` and `drop_effect`, so values set by listeners (in
+ * `dragstart`, `dragover`) are seen by the later events and by Rust.
+ */
+export declare class DragData {
+  /** Paths of files dragged in from the OS; empty for element drags. */
+  get files(): Array<string>
+  /** String data set by `dragstart` listeners. */
+  get data(): DataTransferItems
+  /** "none" | "copy" | "link" | "move" */
+  get dropEffect(): string
+  set dropEffect(effect: string)
+  get clientX(): number
+  get clientY(): number
+}
+
 /**
  * One DomEvent serialized for JS consumption.
  *
@@ -13,6 +45,11 @@ export declare class EventPayload {
   get bubbles(): boolean
   /** `event.cancelable` */
   get cancelable(): boolean
+  /**
+   * Creation time in milliseconds since the Unix epoch; JS subtracts
+   * `performance.timeOrigin` for `event.timeStamp`.
+   */
+  get timeStamp(): number
   /** Pointer/mouse details, when applicable. */
   get pointer(): PointerData | null
   /** Wheel delta, when applicable. */
@@ -23,6 +60,32 @@ export declare class EventPayload {
   get input(): InputData | null
   /** IME details, when applicable. */
   get ime(): ImeData | null
+  /** `event.clipboardData` of clipboard events. */
+  get clipboardData(): DataTransferItems | null
+  /** Drag-and-drop details, when applicable. */
+  get drag(): DragData | null
+  /** Window lifecycle details (`resize`, `move`, ...), when applicable. */
+  get window(): WindowEventData | null
+  /** Touch contacts (`touchstart`, ...), when applicable. */
+  get touch(): TouchData | null
+  /** Trackpad gesture details (`gesturestart`, ...), when applicable. */
+  get gesture(): GestureData | null
+}
+
+/**
+ * Details of a trackpad gesture event (see `dom/gesture.rs`). Values are
+ * accumulated since `gesturestart`.
+ */
+export declare class GestureData {
+  /** "pinch" | "rotate" | "pan" */
+  get kind(): string
+  /** Pinch scale; `1` at the start, `2` for twice the distance. */
+  get scale(): number
+  /** Rotation in degrees, counterclockwise positive. */
+  get rotation(): number
+  /** Pan distance in CSS pixels. */
+  get deltaX(): number
+  get deltaY(): number
 }
 
 export declare class ImeData {
@@ -57,7 +120,38 @@ export declare class InputDataHandle {
   get checked(): boolean
   set checked(checked: boolean)
   /** Whether this input currently has focus. */
+  /**
+   * Paths selected in an `<input type=file>`, e.g. by dropping files
+   * onto it. Empty for other inputs.
+   */
+  get files(): Array<string>
+  /**
+   * Replace the selection. Only clearing (`[]`) is allowed from script,
+   * as in browsers.
+   */
+  set files(files: Array<string>)
   get focused(): boolean
+  /** Start of the selection (or the caret position). */
+  get selectionStart(): number | null
+  /** End of the selection (equal to `selectionStart` for a caret). */
+  get selectionEnd(): number | null
+  /** "forward" | "backward" */
+  get selectionDirection(): string | null
+  /**
+   * Mirrors `HTMLInputElement.setSelectionRange`. Offsets are clamped
+   * to the text length and `start` to `end`. Fires `selectionchange`
+   * (and `select` for a non-empty range) if the selection changed.
+   */
+  setSelectionRange(start: number, end: number, direction?: string | undefined | null): void
+  /** Mirrors `HTMLInputElement.select`: select the whole text. */
+  select(): void
+  /**
+   * Mirrors `HTMLInputElement.setRangeText`. Replaces `start..end`
+   * (default: the current selection) with `replacement`, then places
+   * the selection according to `select_mode`: "select" | "start" |
+   * "end" | "preserve" (default).
+   */
+  setRangeText(replacement: string, start?: number | undefined | null, end?: number | undefined | null, selectMode?: string | undefined | null): void
 }
 
 export declare class KeyData {
@@ -67,6 +161,15 @@ export declare class KeyData {
   get code(): string
   get location(): number
   get modsBits(): number
+  get ctrlKey(): boolean
+  get shiftKey(): boolean
+  get altKey(): boolean
+  get metaKey(): boolean
+  /**
+   * Whether the modifier or lock named by its DOM key value
+   * (`"Control"`, `"CapsLock"`, ...) is active.
+   */
+  getModifierState(key: string): boolean
   get repeat(): boolean
   get isComposing(): boolean
   /** "pressed" | "released" */
@@ -80,6 +183,12 @@ export declare class MonitorInfo {
   get name(): string | null
   get x(): number
   get y(): number
+  /**
+   * Width in physical pixels, from the current video mode. `0` when
+   * the platform does not report it.
+   */
+  get width(): number
+  get height(): number
   get scaleFactor(): number
   get currentVideoMode(): VideoModeInfo | null
   get videoModes(): Array<VideoModeInfo>
@@ -117,8 +226,13 @@ export declare class NativeApp {
    * ensure a pump is (or will be) running before `await`-ing the result;
    * otherwise the promise never resolves. Typical setup drives at least one
    * `pump_app_events` before awaiting.
+   *
+   * `owner` makes the new window an owned window of a live one: it stays
+   * above its owner (unless `options` names another parent) and closes
+   * with it. With `modal`, the owner also gets no input and cannot close
+   * while the new window is open.
    */
-  openWindow(doc: NativeDoc, options?: WindowOptions | undefined | null): Promise<NativeWindow>
+  openWindow(doc: NativeDoc, options?: WindowOptions | undefined | null, owner?: NativeWindow | undefined | null, modal?: boolean | undefined | null): Promise<NativeWindow>
   /**
    * Queue the given window for closure and return a promise that resolves
    * once the native `View` has actually been torn down (during the next
@@ -147,6 +261,33 @@ export declare class NativeApp {
   primaryMonitor(): MonitorInfo | null
   /** Pump pending winit events for at most `millis` milliseconds. */
   pumpAppEvents(millis: number): PumpResult
+  /**
+   * Integrated mode: call `callback` on the JS thread whenever the event
+   * loop has work (OS events, redraws, DOM mutations of a window's
+   * document), at least every `maxWait` ms (default 500). The callback
+   * should run `pumpAppEvents(0)`; no further callback comes until it
+   * does, or until `maxWait` passed (e.g. the callback threw before it
+   * pumped). Keeps the process alive until `unwatchEvents`.
+   *
+   * Only supported on Linux / BSD, where the watcher waits on the event
+   * loop's descriptor. Elsewhere nothing can see the loop's events while
+   * Node idles, and this throws.
+   */
+  watchEvents(callback: () => void, maxWait?: number | undefined | null): void
+  /**
+   * Close every window and drop the event loop now. Windows are torn
+   * down without a cancelable `close` (each still gets `closed`),
+   * pending `openWindow` promises reject, and any further use of the app
+   * throws. Calling it again is a no-op.
+   */
+  dispose(): void
+  /** Whether `dispose` has run. */
+  get disposed(): boolean
+  /**
+   * Stop the watcher started by `watchEvents`. A callback already queued
+   * may still run once. No-op when not watching.
+   */
+  unwatchEvents(): void
   /**
    * Set the document zoom level. `1.0` is unzoomed. Combined with the
    * system scale factor to produce the total viewport scale
@@ -159,7 +300,80 @@ export declare class NativeApp {
 
 export declare class NativeDoc {
   static create(config: DocHandleConfig): NativeDoc
+  /**
+   * Release the document now instead of when the GC collects it: the
+   * document tree, its font context and every cached node wrapper are
+   * dropped, and any further use of the document or its nodes throws.
+   * Fails while the document is shown in a window; close it first.
+   * Calling it again is a no-op.
+   */
+  dispose(): void
+  /** Whether `dispose` has run. */
+  get disposed(): boolean
+  /**
+   * Resolve styles and layout for frame time `time_ms`. For a document
+   * not shown in a window this is also its frame clock: moves queued by
+   * `queuePointerMove` are dispatched first, then smooth scrolls advance
+   * to `time_ms` and pending `scroll` / `scrollend` events fire.
+   */
   resolve(timeMs: number): void
+  /** Horizontal scroll offset of the viewport. Mirrors `window.scrollX`. */
+  get scrollX(): number
+  /** Vertical scroll offset of the viewport. Mirrors `window.scrollY`. */
+  get scrollY(): number
+  /**
+   * Scroll the viewport, clamped to the document's scroll range.
+   * Mirrors `window.scrollTo(options)`.
+   */
+  scrollTo(options: ScrollToOptions): void
+  /**
+   * Scroll the viewport by `left` / `top`. Mirrors
+   * `window.scrollBy(options)`.
+   */
+  scrollBy(options: ScrollToOptions): void
+  /**
+   * Feed a mouse `pointermove` at client coordinates through the same
+   * pipeline as window input: hit testing, hover state, drag-and-drop
+   * and the JS dispatch. `buttons` is the `MouseEvent.buttons` mask held
+   * during the move (none by default). Uses the last resolved layout;
+   * page and screen coordinates equal the client ones. Never coalesced;
+   * moves queued by `queuePointerMove` are dispatched first. For tests
+   * and benchmarks of documents that are not shown in a window.
+   */
+  dispatchPointerMove(clientX: number, clientY: number, buttons?: number | undefined | null): void
+  /**
+   * Queue a mouse `pointermove` the way a window coalesces them: the
+   * next `resolve` or `dispatchPointer*` call dispatches the latest
+   * sample, with every queued one as `getCoalescedEvents()` and the
+   * last step extrapolated as `getPredictedEvents()`.
+   */
+  queuePointerMove(clientX: number, clientY: number, buttons?: number | undefined | null): void
+  /**
+   * Press the primary mouse button at client coordinates, through the
+   * same pipeline as `dispatchPointerMove`.
+   */
+  dispatchPointerDown(clientX: number, clientY: number): void
+  /**
+   * Release the primary mouse button at client coordinates, through
+   * the same pipeline as `dispatchPointerMove`.
+   */
+  dispatchPointerUp(clientX: number, clientY: number): void
+  /**
+   * Feed a touchscreen contact through the same pipeline as
+   * `dispatchPointerMove`: the finger's pointer event, then its touch
+   * event. `phase` is "start" | "move" | "end" | "cancel";
+   * `identifier` tells fingers apart, and the first one down is the
+   * primary pointer.
+   */
+  dispatchTouch(phase: string, identifier: number, clientX: number, clientY: number): void
+  /**
+   * Feed one trackpad gesture step through the same path as a
+   * window's, without the pinch zoom. `kind` is "pinch" (`delta` is the
+   * scale change, `0.1` is 10% larger), "rotate" (`delta` in degrees)
+   * or "pan" (`delta` and `delta_y` in CSS pixels); `phase` is "start" |
+   * "change" | "end". Returns whether the step was canceled.
+   */
+  dispatchGesture(kind: string, phase: string, delta: number, deltaY?: number | undefined | null): boolean
   registerFont(data: Uint8Array, options?: RegisterFontOptions | undefined | null): number
   rootNodeId(): bigint
   rootElementId(): bigint
@@ -370,6 +584,82 @@ export declare class NativeDoc {
   htmlElement(): object | null
   headElement(): object | null
   bodyElement(): object | null
+  /**
+   * Topmost element at the given viewport coordinate. Mirrors
+   * `document.elementFromPoint`. Returns null for negative coordinates
+   * or when nothing was hit.
+   */
+  elementFromPoint(x: number, y: number): object | null
+  /**
+   * Elements at the given viewport coordinate, topmost first. Mirrors
+   * `document.elementsFromPoint`.
+   *
+   * blitz only reports a single hit, so this is the hit element followed
+   * by all of its ancestors up to `<html>`, as browsers list them even
+   * where `<body>` is shorter than the viewport. Siblings overlapping
+   * the point underneath the hit element are not reported.
+   */
+  elementsFromPoint(x: number, y: number): Array<object>
+  /**
+   * The focused element, or null when nothing has focus. The JS
+   * `document.activeElement` getter falls back to `<body>`.
+   */
+  focusedElement(): object | null
+  /**
+   * Move focus to the next (`forward`) or previous element in `tabindex`
+   * order, as the Tab key does, firing the focus events. Returns false
+   * when the document has nothing tabbable. Layout must be resolved:
+   * unstyled (`display: none` or never resolved) elements are skipped.
+   */
+  moveFocus(forward: boolean): boolean
+  /** Anchor (`focus == false`) or focus node of the document selection. */
+  selectionNode(focus: boolean): object | null
+  /** Anchor (`focus == false`) or focus offset of the document selection. */
+  selectionOffset(focus: boolean): number | null
+  /**
+   * Replace the document selection. Fires `selectionchange` at the
+   * document if it changed. Offsets beyond the node length are an error.
+   */
+  setSelection(anchorNode: NativeNode, anchorOffset: number, focusNode: NativeNode, focusOffset: number): void
+  /**
+   * Remove the document selection, firing `selectionchange` if there
+   * was one.
+   */
+  clearSelection(): void
+  /** Tree order of two boundary points: -1, 0 or 1. */
+  compareBoundaryPoints(aNode: NativeNode, aOffset: number, bNode: NativeNode, bOffset: number): number
+  /**
+   * Boundary length of a node: UTF-16 length for text, child count
+   * otherwise.
+   */
+  nodeLength(node: NativeNode): number
+  /** Text covered by a range. Mirrors `Range.toString()`. */
+  rangeText(startNode: NativeNode, startOffset: number, endNode: NativeNode, endOffset: number): string
+  /**
+   * Rects of the text covered by a range, one per line fragment, in the
+   * same coordinates as `getBoundingClientRect`. Uses the last resolved
+   * layout.
+   */
+  rangeClientRects(startNode: NativeNode, startOffset: number, endNode: NativeNode, endOffset: number): Array<DomRect>
+  /** `NativeNode.addListener` for the document node. */
+  addListener(eventType: string): void
+  /** `NativeNode.removeListener` for the document node. */
+  removeListener(eventType: string): void
+  /**
+   * Record a listener registration of `eventType` on the JS `Window`
+   * showing this document, which receives forwarded pointer events.
+   */
+  addWindowListener(eventType: string): void
+  /**
+   * Record the removal of a registration reported by
+   * `addWindowListener`.
+   */
+  removeWindowListener(eventType: string): void
+  /**
+   * Count the document's nodes, cached JS wrappers, stylesheets and
+   * fonts. Walks the whole tree: meant for diagnostics, not per frame.
+   */
+  stats(): DocStats
 }
 
 export declare class NativeNode {
@@ -404,20 +694,62 @@ export declare class NativeNode {
   querySelectorAll(selector: string): Array<object>
   getBoundingClientRect(): DomRect | null
   get scrollTop(): number
+  /**
+   * Scroll vertically, clamped to the scroll range. `scroll` fires on
+   * the next frame.
+   */
   set scrollTop(value: number)
   get scrollLeft(): number
+  /**
+   * Scroll horizontally, clamped to the scroll range. `scroll` fires on
+   * the next frame.
+   */
   set scrollLeft(value: number)
+  /** Client height plus the vertical scroll range. */
   get scrollHeight(): number
+  /** Client width plus the horizontal scroll range. */
   get scrollWidth(): number
+  /**
+   * Scroll to `left` / `top`, clamped. Mirrors `Element.scrollTo`; the
+   * root element scrolls the viewport.
+   */
+  scrollTo(options: ScrollToOptions): void
+  /** Scroll by `left` / `top`, clamped. Mirrors `Element.scrollBy`. */
+  scrollBy(options: ScrollToOptions): void
+  /**
+   * Scroll the enclosing scroll containers and the viewport to show
+   * this node. Mirrors `Element.scrollIntoView(options)`; uses the last
+   * resolved layout.
+   */
+  scrollIntoView(options?: ScrollIntoViewOptions | undefined | null): void
   get clientHeight(): number
   get clientWidth(): number
   /**
-   * Move focus to this node. Mirrors `HTMLElement.focus()`.
+   * Move focus to this node. Mirrors `HTMLElement.focus()`: a no-op for
+   * nodes that are not focusable or not in the document. Fires
+   * `blur`/`focusout` and `focus`/`focusin` synchronously.
    * Returns true if focus actually changed.
    */
   focus(): boolean
   /** Remove focus from this node (if focused). Mirrors `HTMLElement.blur()`. */
   blur(): void
+  /**
+   * Redirect subsequent events of `pointer_id` to this node. Mirrors
+   * `Element.setPointerCapture`. Takes effect (and fires
+   * `gotpointercapture`) at the next pointer event.
+   */
+  setPointerCapture(pointerId: number): void
+  /**
+   * Mirrors `Element.releasePointerCapture`. No-op if this node does not
+   * hold the capture.
+   */
+  releasePointerCapture(pointerId: number): void
+  /** Mirrors `Element.hasPointerCapture`. */
+  hasPointerCapture(pointerId: number): boolean
+  /** Record a new listener registration of `eventType` on this node. */
+  addListener(eventType: string): void
+  /** Record the removal of a registration reported by `addListener`. */
+  removeListener(eventType: string): void
 }
 
 /**
@@ -428,6 +760,13 @@ export declare class NativeNode {
  * cell, which releases the OS window even if this JS handle is still alive.
  */
 export declare class NativeWindow {
+  /**
+   * Close the window now, without a cancelable `close`: it is torn down
+   * at the next pump and then gets `closed`, like a window closing with
+   * its owner. Its owned windows close too. The document is left alone;
+   * dispose it separately once `closed` fired. No-op once closed.
+   */
+  dispose(): void
   /** Whether `closeWindow` has run for this handle. */
   get closed(): boolean
   /**
@@ -444,13 +783,107 @@ export declare class NativeWindow {
   windowHandle(): WindowHandle
   setTitle(title: string): void
   setSize(width: number, height: number): void
+  /**
+   * Render the current document into an RGBA buffer at the window's
+   * surface size and scale (device scale factor times zoom). Painted by
+   * the CPU renderer, so the frame matches the window's content but not
+   * necessarily its GPU output pixel for pixel. The document is laid
+   * out at the window's latest frame time and recorded right away;
+   * only the rasterization runs off the JS thread.
+   */
+  capture(): Promise<BufferFrame>
   getSize(): Array<number>
+  /** Outer size including decorations, as `[width, height]`. */
+  getOuterSize(): Array<number>
+  /**
+   * Position of the outer top-left corner on the virtual desktop, as
+   * `[x, y]`.
+   */
+  getPosition(): Array<number>
+  /**
+   * Move the outer top-left corner. Ignored on platforms that do not
+   * let clients position windows (Wayland).
+   */
+  setPosition(x: number, y: number): void
+  /**
+   * Start an OS-driven window move, as if the user grabbed the title
+   * bar. Call from a primary-button `pointerdown` listener; the window
+   * manager ends it when the button is released.
+   */
+  startDrag(): void
+  /**
+   * Start an OS-driven resize from an edge or corner: `"n"`, `"ne"`,
+   * `"e"`, `"se"`, `"s"`, `"sw"`, `"w"` or `"nw"`. Same rules as
+   * `startDrag`.
+   */
+  startResize(direction: string): void
+  /**
+   * Override the CSS cursor with a CSS cursor keyword (`"pointer"`,
+   * `"ew-resize"`, ...). `null` or `"auto"` hands the cursor back to
+   * CSS from the next pointer move.
+   */
+  setCursor(cursor?: string | undefined | null): void
+  /**
+   * Override the CSS cursor with an image. It is shown from the next
+   * event-loop pump on; clear it with `setCursor(null)`.
+   */
+  setCursorImage(image: CursorImage): void
+  setCursorVisible(visible: boolean): void
+  /**
+   * `"none"` | `"confined"` (kept inside the window) | `"locked"` (held
+   * in place; the window then receives raw `motion` events). Not every
+   * platform supports both modes: X11 cannot lock and macOS cannot
+   * confine, which is reported as an error.
+   */
+  setCursorGrab(mode: string): void
+  /**
+   * Deliver at most one `pointermove` per pointer and frame (the
+   * default), with the skipped samples available from
+   * `getCoalescedEvents()`. `false` dispatches every move as it arrives.
+   */
+  setCoalescePointerMoves(value: boolean): void
+  getCoalescePointerMoves(): boolean
+  /**
+   * Let trackpad pinches zoom the document, like `NativeApp.setZoom`,
+   * unless a `gesturechange` listener cancels them. Off by default.
+   */
+  setPinchZoom(value: boolean): void
+  getPinchZoom(): boolean
+  getSafeArea(): WindowInsets
   getResizable(): boolean
   currentMonitor(): MonitorInfo | null
   setMinSize(width: number, height: number): void
   setMaxSize(width: number, height: number): void
   setResizable(value: boolean): void
   setMaximized(value: boolean): void
+  setMinimized(value: boolean): void
+  /** `None` when the platform cannot tell (Wayland). */
+  isMinimized(): boolean | null
+  isMaximized(): boolean
+  isFocused(): boolean
+  /**
+   * Bring the window to the front and give it input focus. Platforms
+   * may refuse to steal focus from another application.
+   */
+  focusWindow(): void
+  /**
+   * Flash the taskbar entry / bounce the dock icon until the window
+   * is focused: `"critical"` or `"informational"`. `null` cancels a
+   * pending request.
+   */
+  requestUserAttention(kind?: string | undefined | null): void
+  /** `"normal"` | `"always-on-top"` | `"always-on-bottom"`. */
+  setWindowLevel(level: string): void
+  /**
+   * Keep the window out of the taskbar. Windows only; ignored
+   * elsewhere.
+   */
+  setSkipTaskbar(value: boolean): void
+  /**
+   * Exclude the window's content from screenshots and screen sharing
+   * (Windows and macOS).
+   */
+  setContentProtected(value: boolean): void
   setVisible(value: boolean): void
   setTransparent(value: boolean): void
   setBlur(value: boolean): void
@@ -481,6 +914,75 @@ export declare class PointerData {
   get tiltY(): number
   get twist(): number
   get modsBits(): number
+  get ctrlKey(): boolean
+  get shiftKey(): boolean
+  get altKey(): boolean
+  get metaKey(): boolean
+  /**
+   * Whether the modifier or lock named by its DOM key value
+   * (`"Control"`, `"CapsLock"`, ...) is active.
+   */
+  getModifierState(key: string): boolean
+  /** Position relative to the target's border box. */
+  get offsetX(): number
+  get offsetY(): number
+  /**
+   * Distance moved since the previous move of the same pointer, in
+   * screen pixels; `0` for other events.
+   */
+  get movementX(): number
+  get movementY(): number
+  /**
+   * Click count of `mousedown` / `mouseup` / `click` (`2` for a
+   * double click); `0` for other events.
+   */
+  get detail(): number
+  /**
+   * Samples merged into this `pointermove`, oldest first and ending with
+   * the event itself. Empty for other events.
+   */
+  getCoalescedEvents(): Array<PointerData>
+  /**
+   * Expected next samples of this `pointermove`, extrapolated from the
+   * last movement. Empty for other events or before a second sample.
+   */
+  getPredictedEvents(): Array<PointerData>
+}
+
+/** Contact lists of a touch event (see `dom/touch.rs`). */
+export declare class TouchData {
+  /** Every finger still on the surface. */
+  get touches(): Array<TouchPoint>
+  /** Fingers still on the surface that started on the event's target. */
+  get targetTouches(): Array<TouchPoint>
+  /** The finger this event is about. */
+  get changedTouches(): Array<TouchPoint>
+  get modsBits(): number
+  get ctrlKey(): boolean
+  get shiftKey(): boolean
+  get altKey(): boolean
+  get metaKey(): boolean
+  /**
+   * Whether the modifier or lock named by its DOM key value
+   * (`"Control"`, `"CapsLock"`, ...) is active.
+   */
+  getModifierState(key: string): boolean
+}
+
+/** One finger of a touch event. */
+export declare class TouchPoint {
+  /** Finger id, stable while the finger stays down. */
+  get identifier(): number
+  /** The element the contact started on. */
+  target(): object | null
+  get pageX(): number
+  get pageY(): number
+  get clientX(): number
+  get clientY(): number
+  get screenX(): number
+  get screenY(): number
+  /** Touch pressure in `[0, 1]`; `0` when the screen does not report it. */
+  get force(): number
 }
 
 /** A fullscreen video mode of a monitor. Wraps winit's `VideoMode`. */
@@ -502,6 +1004,40 @@ export declare class WheelData {
   get clientY(): number
   get buttons(): number
   get modsBits(): number
+  get ctrlKey(): boolean
+  get shiftKey(): boolean
+  get altKey(): boolean
+  get metaKey(): boolean
+  /**
+   * Whether the modifier or lock named by its DOM key value
+   * (`"Control"`, `"CapsLock"`, ...) is active.
+   */
+  getModifierState(key: string): boolean
+}
+
+/**
+ * Details of a window lifecycle event. Only the fields relevant to the
+ * event are set; sizes and positions are physical pixels, like
+ * `NativeWindow.getSize`.
+ */
+export declare class WindowEventData {
+  /** New surface width (`resize`). */
+  get width(): number | null
+  /** New surface height (`resize`). */
+  get height(): number | null
+  /** New outer position (`move`). */
+  get x(): number | null
+  get y(): number | null
+  /** New scale factor (`scalefactorchange`). */
+  get scaleFactor(): number | null
+  /** "light" | "dark" (`themechange`). */
+  get theme(): string | null
+  /**
+   * Raw pointer motion in device units (`motion`, while the cursor is
+   * locked).
+   */
+  get movementX(): number | null
+  get movementY(): number | null
 }
 
 /**
@@ -524,6 +1060,15 @@ export declare class WindowOptions {
   static builder(): WindowOptions
   title(value: string): this
   size(width: number, height: number): this
+  /**
+   * Initial position of the window's outer top-left corner, in
+   * physical pixels. A position that would leave the window off every
+   * monitor (e.g. a saved layout from a monitor that is gone) is
+   * clamped onto the nearest one.
+   */
+  position(x: number, y: number): this
+  /** Center the window on `monitor`, or on the monitor it opens on. */
+  center(monitor?: MonitorInfo | undefined | null): this
   resizable(value: boolean): this
   minSize(width: number, height: number): this
   maxSize(width: number, height: number): this
@@ -544,16 +1089,92 @@ export declare class WindowOptions {
    * Pass a `RawWindowHandle` obtained from `NativeWindow.windowHandle()`.
    */
   parentWindow(handle: WindowHandle): this
+  /** `"normal"` | `"always-on-top"` | `"always-on-bottom"`. */
+  windowLevel(value: string): this
+  /**
+   * Keep the window out of the taskbar. Windows only; ignored
+   * elsewhere.
+   */
+  skipTaskbar(value: boolean): this
+  /**
+   * Exclude the window's content from screenshots and screen sharing
+   * (Windows and macOS).
+   */
+  contentProtected(value: boolean): this
 }
 
 /** Plain attribute pair used by the create/insert APIs. */
+/**
+ * Sum `NativeDoc.stats` over the live documents of the calling
+ * environment. Font collections are per document and not summed.
+ */
+export declare function addonStats(): AddonStats
+
+/**
+ * `DocStats` summed over every live document of the calling environment
+ * (main thread or Worker).
+ */
+export interface AddonStats {
+  /** Documents neither disposed nor garbage-collected. */
+  documents: number
+  nodes: number
+  detachedNodes: number
+  cachedWrappers: number
+  strongWrappers: number
+  weakWrappers: number
+  detachedStrongWrappers: number
+  collectedWrappers: number
+  stylesheets: number
+  approxBytes: number
+}
+
 export interface AttrInit {
   name: string
   value: string
   namespace?: string
 }
 
+/** RGBA frame rendered on the CPU. */
+export interface BufferFrame {
+  /** Frame width in physical pixels. */
+  width: number
+  /** Frame height in physical pixels. */
+  height: number
+  /** Device scale factor used to render the frame. */
+  scale: number
+  /** RGBA8 pixels, row-major, 4 bytes per pixel. */
+  data: Uint8Array
+}
+
+/** Image read from / written to the clipboard: RGBA8 pixels, row-major. */
+export interface ClipboardImage {
+  width: number
+  height: number
+  data: Uint8Array
+}
+
+/** Clipboard image, or `null` when the clipboard holds no image. */
+export declare function clipboardReadImage(): ClipboardImage | null
+
+/** Clipboard text, or `null` when the clipboard holds no text. */
+export declare function clipboardReadText(): string | null
+
+/** Write an image. `data` must hold `width * height * 4` bytes. */
+export declare function clipboardWriteImage(image: ClipboardImage): void
+
+export declare function clipboardWriteText(text: string): void
+
 /** Options shared by all dialog methods. */
+/** An RGBA cursor image for `NativeWindow.setCursorImage`. */
+export interface CursorImage {
+  width: number
+  height: number
+  /** `width * height * 4` bytes of RGBA. */
+  data: Uint8Array
+  hotspotX?: number
+  hotspotY?: number
+}
+
 export interface DialogOptions {
   /** Dialog title. */
   title?: string
@@ -571,6 +1192,48 @@ export interface DocHandleConfig {
   baseHtml?: string
 }
 
+/** Memory and cache counters of one document. */
+export interface DocStats {
+  /** Nodes connected to the document. */
+  nodes: number
+  /**
+   * Nodes of detached subtrees that blitz still stores because a cached
+   * JS wrapper in the subtree may be alive.
+   */
+  detachedNodes: number
+  /** `NodeCache` entries (JS wrappers known to the document). */
+  cachedWrappers: number
+  /** Entries holding their wrapper strong (connected nodes). */
+  strongWrappers: number
+  /** Entries the GC may collect (detached nodes). */
+  weakWrappers: number
+  /**
+   * Strong entries whose node is detached. Normally `0`; anything else
+   * keeps a detached subtree alive indefinitely.
+   */
+  detachedStrongWrappers: number
+  /**
+   * Entries whose wrapper was collected, waiting to be swept. Each
+   * input event sweeps a bounded batch of them.
+   */
+  collectedWrappers: number
+  /**
+   * `<style>` and `<link rel=stylesheet>` elements connected to the
+   * document. User-agent sheets are not counted.
+   */
+  stylesheets: number
+  /**
+   * Font families in the document's font collection, system fonts
+   * included.
+   */
+  fontFamilies: number
+  /**
+   * Rough size in bytes of the node storage, text, attribute values
+   * and cache entries. Style and layout data are not included.
+   */
+  approxBytes: number
+}
+
 export interface DomRect {
   x: number
   y: number
@@ -591,9 +1254,9 @@ export interface FileFilter {
 }
 
 /**
- * One-time env injection. JS calls this during addon init (before any
- * register_* calls) so that `global::env()` works in callbacks that don't
- * receive an `Env` parameter (e.g. `EventHandler::handle_event`).
+ * Create this environment's registry. JS calls this during addon init,
+ * before any register_* call; the register functions also create it on
+ * demand, so calling it again is harmless.
  */
 export declare function initEnv(): void
 
@@ -635,5 +1298,43 @@ export interface RegisterFontOptions {
 
 export declare function registerNodeConstructor(nodeType: number, constructor: { new (handle: NativeNode, document: object): object }): void
 
+/**
+ * Switch the clipboard backend: `"system"` (the OS clipboard, default)
+ * or `"memory"` (a fresh, empty in-process clipboard).
+ */
+export declare function setClipboardBackend(kind: string): void
+
 /** Open a save-file dialog. Returns the chosen path or `null`. */
 export declare function saveFile(options?: DialogOptions | undefined | null, parent?: WindowHandle | undefined | null): Promise<string | null>
+
+/** Options of `scrollIntoView`, as in the DOM's `ScrollIntoViewOptions`. */
+export interface ScrollIntoViewOptions {
+  /** `"start"` (default), `"center"`, `"end"` or `"nearest"`. */
+  block?: string
+  /** `"start"`, `"center"`, `"end"` or `"nearest"` (default). */
+  inline?: string
+  /** `"auto"`, `"instant"` or `"smooth"`. */
+  behavior?: string
+}
+
+/**
+ * Options of `scrollTo` / `scrollBy`, as in the DOM's `ScrollToOptions`.
+ * `scrollBy` reads `left` / `top` as deltas.
+ */
+export interface ScrollToOptions {
+  left?: number
+  top?: number
+  /** `"auto"`, `"instant"` or `"smooth"`. */
+  behavior?: string
+}
+
+/**
+ * Insets of a window's safe area: the part of the surface not covered
+ * by notches, rounded corners or overlaid title bars. Physical pixels.
+ */
+export interface WindowInsets {
+  top: number
+  right: number
+  bottom: number
+  left: number
+}
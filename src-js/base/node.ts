@@ -7,10 +7,13 @@
 // and doc reference; all getters forward to the native handle. Tree
 // relationship getters return already-wrapped JS Node objects (Rust
 // wraps via NodeCache). `EventTarget` is inherited from the Node.js
-// built-in.
+// built-in; `addEventListener` / `removeEventListener` also tell the
+// native side which event types the node listens for, so events nobody
+// listens for are never dispatched into JS.
 
 import {NativeNode} from "../native";
-import type {DocumentInternals, NodeInternals} from "../internal/internal";
+import {assertLive, type DocumentInternals, type NodeInternals} from "../internal/internal";
+import {ListenerSet} from "../events/listener-set";
 
 /** DOM nodeType constants. Mirrors the web spec. */
 export const NodeTypes = {
@@ -21,8 +24,9 @@ export const NodeTypes = {
 } as const;
 
 export abstract class Node extends EventTarget {
-  protected readonly _handle: InstanceType<typeof NativeNode>;
+  private readonly _nativeHandle: InstanceType<typeof NativeNode>;
   protected readonly _doc: DocumentInternals;
+  private _listeners: ListenerSet | null = null;
 
   /**
    * @internal Constructed by Rust via `registerNodeConstructor`. JS
@@ -30,10 +34,40 @@ export abstract class Node extends EventTarget {
    */
   constructor(handle: InstanceType<typeof NativeNode>, doc: DocumentInternals) {
     super();
-    this._handle = handle;
+    this._nativeHandle = handle;
     this._doc = doc;
   }
 
+  /** The native handle; throws once the document is disposed. */
+  protected get _handle(): InstanceType<typeof NativeNode> {
+    assertLive(this._doc);
+    return this._nativeHandle;
+  }
+
+  override addEventListener(
+    type: string,
+    callback: EventListenerOrEventListenerObject | null,
+    options?: boolean | AddEventListenerOptions,
+  ): void {
+    const handle = this._handle;
+    super.addEventListener(type, callback, options);
+    if (callback === null) return;
+    this._listeners ??= new ListenerSet();
+    if (this._listeners.add(type, callback, options)) handle.addListener(type);
+  }
+
+  override removeEventListener(
+    type: string,
+    callback: EventListenerOrEventListenerObject | null,
+    options?: boolean | EventListenerOptions,
+  ): void {
+    super.removeEventListener(type, callback, options);
+    if (callback === null || this._doc._disposed) return;
+    if (this._listeners?.delete(type, callback, options)) {
+      this._handle.removeListener(type);
+    }
+  }
+
   /** DOM-style numeric nodeType. */
   get nodeType(): number {
     return this._handle.nodeType();
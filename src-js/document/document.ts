@@ -7,14 +7,20 @@
 // registered globally in `register.ts`. All node wrapping is done by
 // Rust (NodeCache + wrap_node); JS methods forward to the native
 // handle, which returns already-wrapped JS Node objects.
+//
+// `dispose()` (or a `using` declaration) releases the native document
+// without waiting for the GC. Afterwards every native-backed member of
+// the document and its nodes throws.
 
-import {NativeDoc, NativeNode} from "../native";
+import {NativeDoc, NativeNode, type DocStats} from "../native";
 import {Node} from "../base/node";
 import {Element} from "../element/element";
 import {Text} from "../base/text";
 import {Comment} from "../base/comment";
 import {FontFaceSet} from "../fonts/font-face-set";
-import type {DocumentInternals} from "../internal/internal";
+import {Range} from "../selection/range";
+import {Selection} from "../selection/selection";
+import {assertLive, type DocumentInternals} from "../internal/internal";
 
 export interface DocumentInit {
   uaStylesheets?: string[];
@@ -27,21 +33,69 @@ export interface DocumentInit {
  * `instanceof` checks and shared code.
  */
 export abstract class Document extends Node implements DocumentInternals {
-  readonly _native: InstanceType<typeof NativeDoc>;
+  private readonly _nativeDoc: InstanceType<typeof NativeDoc>;
+
+  /** @internal Set by `dispose()`. */
+  _disposed = false;
 
   /** Lazily-built `FontFaceSet` exposed via `document.fonts`. */
   private _fontsSet: FontFaceSet | null = null;
 
+  /** Lazily-built `Selection` returned by `getSelection()`. */
+  private _selection: Selection | null = null;
+
   /**
    * @internal Constructed by Rust via `registerNodeConstructor` or by
    * `HTMLDocument.create()`. The `handle` is the native document handle.
    */
   constructor(handle: InstanceType<typeof NativeDoc>) {
     super(handle as unknown as InstanceType<typeof NativeNode>, handle as unknown as Document);
-    this._native = handle;
+    this._nativeDoc = handle;
     // Set the JS Document object reference so Rust can pass `doc` to
     // each JS Node constructor.
-    this._native.setDocumentRef(this);
+    this._nativeDoc.setDocumentRef(this);
+  }
+
+  /** @internal The native document; throws once disposed. */
+  get _native(): InstanceType<typeof NativeDoc> {
+    assertLive(this);
+    return this._nativeDoc;
+  }
+
+  protected override get _handle(): InstanceType<typeof NativeNode> {
+    return this._native as unknown as InstanceType<typeof NativeNode>;
+  }
+
+  /** Whether `dispose()` has run. */
+  get disposed(): boolean {
+    return this._disposed;
+  }
+
+  /**
+   * Release the native document now: its tree, fonts and node wrappers
+   * are dropped, and the document and its nodes throw on further use.
+   * Throws while the document is shown in an open window; close the
+   * window first. Calling it again is a no-op.
+   */
+  dispose(): void {
+    if (this._disposed) return;
+    this._nativeDoc.dispose();
+    this._disposed = true;
+    this._fontsSet = null;
+    this._selection = null;
+  }
+
+  [Symbol.dispose](): void {
+    this.dispose();
+  }
+
+  /**
+   * Node, wrapper-cache, stylesheet and font counters for this document,
+   * for tracking down leaks. Walks the whole tree; see `addonStats()` for
+   * the sum over all documents.
+   */
+  stats(): DocStats {
+    return this._native.stats();
   }
 
   // ----- Standard DOM root accessors --------------------------------------
@@ -157,6 +211,58 @@ export abstract class Document extends Node implements DocumentInternals {
     return this._native.findAllByClassName(className) as Element[];
   }
 
+  // ----- Hit testing ------------------------------------------------------
+
+  /**
+   * Topmost element at viewport coordinate `(x, y)` in CSS pixels, or
+   * `null`. Uses the last resolved layout.
+   */
+  elementFromPoint(x: number, y: number): Element | null {
+    return this._native.elementFromPoint(x, y) as Element | null;
+  }
+
+  /**
+   * Elements at viewport coordinate `(x, y)`, topmost first: the hit
+   * element followed by its ancestors up to `<html>`.
+   */
+  elementsFromPoint(x: number, y: number): Element[] {
+    return this._native.elementsFromPoint(x, y) as Element[];
+  }
+
+  // ----- Focus ------------------------------------------------------------
+
+  /** The focused element, or `<body>` when nothing has focus. */
+  get activeElement(): Element | null {
+    return (this._native.focusedElement() as Element | null) ?? this.body;
+  }
+
+  /**
+   * Move focus like Tab (`forward`) / Shift+Tab would, following
+   * `tabindex` order and wrapping around. Returns false when nothing in
+   * the document is tabbable. Non-standard.
+   */
+  moveFocus(forward = true): boolean {
+    return this._native.moveFocus(forward);
+  }
+
+  // ----- Selection / Range ------------------------------------------------
+
+  /**
+   * The document's text selection (one per document). Mouse drags over
+   * text in a window update it; `selectionchange` fires on this document.
+   */
+  getSelection(): Selection {
+    if (this._selection === null) {
+      this._selection = new Selection(this);
+    }
+    return this._selection;
+  }
+
+  /** A new range collapsed at the start of the root element. */
+  createRange(): Range {
+    return new Range(this, this.documentElement);
+  }
+
   // ----- Layout / lifecycle ----------------------------------------------
 
   resolve(timeMs = 0): void {
@@ -20,44 +20,44 @@ import {NativeNode} from "../native";
 export type AttributesMap = Record<string, string>;
 
 export function makeAttributesProxy(
-  handle: InstanceType<typeof NativeNode>,
+  handle: () => InstanceType<typeof NativeNode>,
 ): AttributesMap {
   // The proxy target is just a placeholder object; we route every
-  // operation through `handle` so reads always reflect the latest
-  // native state.
+  // operation through `handle()` so reads always reflect the latest
+  // native state (and throw once the document is disposed).
   const target: Record<string, unknown> = Object.create(null);
 
   return new Proxy(target, {
     get(_, prop): unknown {
       if (typeof prop !== "string") return undefined;
-      const value = handle.getAttribute(prop);
+      const value = handle().getAttribute(prop);
       return value === null ? undefined : value;
     },
 
     set(_, prop, value): boolean {
       if (typeof prop !== "string") return false;
-      handle.setAttribute(prop, String(value), null);
+      handle().setAttribute(prop, String(value), null);
       return true;
     },
 
     has(_, prop): boolean {
       if (typeof prop !== "string") return false;
-      return handle.getAttribute(prop) !== null;
+      return handle().getAttribute(prop) !== null;
     },
 
     deleteProperty(_, prop): boolean {
       if (typeof prop !== "string") return false;
-      handle.removeAttribute(prop, null);
+      handle().removeAttribute(prop, null);
       return true;
     },
 
     ownKeys(): string[] {
-      return handle.getAttributes().map((a: AttrInit) => a.name);
+      return handle().getAttributes().map((a: AttrInit) => a.name);
     },
 
     getOwnPropertyDescriptor(_, prop): PropertyDescriptor | undefined {
       if (typeof prop !== "string") return undefined;
-      const value = handle.getAttribute(prop);
+      const value = handle().getAttribute(prop);
       if (value === null) return undefined;
       return {
         value,
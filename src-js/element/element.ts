@@ -3,9 +3,22 @@
 // `Element` interface that are independent of the HTML namespace.
 
 import {Node} from "../base/node";
-import type {AttrInit, DomRect} from "../native";
+import type {AttrInit, DomRect, ScrollIntoViewOptions, ScrollToOptions} from "../native";
 import {type AttributesMap, makeAttributesProxy} from "./attributes";
 
+/**
+ * Normalize the `(options)` and `(x, y)` forms of `scrollTo` /
+ * `scrollBy` to `ScrollToOptions`.
+ */
+export function scrollToOptions(
+  optionsOrX?: ScrollToOptions | number,
+  y?: number,
+): ScrollToOptions {
+  if (optionsOrX === undefined) return {};
+  if (typeof optionsOrX === "object" && optionsOrX !== null) return optionsOrX;
+  return {left: Number(optionsOrX ?? 0), top: Number(y ?? 0)};
+}
+
 export class Element extends Node {
   private _attributesProxy: AttributesMap | null = null;
 
@@ -31,7 +44,7 @@ export class Element extends Node {
    */
   get attributes(): AttributesMap {
     if (this._attributesProxy === null) {
-      this._attributesProxy = makeAttributesProxy(this._handle);
+      this._attributesProxy = makeAttributesProxy(() => this._handle);
     }
     return this._attributesProxy;
   }
@@ -168,6 +181,41 @@ export class Element extends Node {
     return this._handle.scrollWidth;
   }
 
+  /**
+   * Scroll to a position, clamped to the scroll range. `behavior:
+   * "smooth"` animates over the next frames; `scroll` fires on the next
+   * frame either way and `scrollend` once the scroll is done.
+   */
+  scrollTo(options?: ScrollToOptions): void;
+  scrollTo(x: number, y: number): void;
+  scrollTo(optionsOrX?: ScrollToOptions | number, y?: number): void {
+    this._handle.scrollTo(scrollToOptions(optionsOrX, y));
+  }
+
+  /** Alias of `scrollTo`. */
+  scroll(options?: ScrollToOptions): void;
+  scroll(x: number, y: number): void;
+  scroll(optionsOrX?: ScrollToOptions | number, y?: number): void {
+    this._handle.scrollTo(scrollToOptions(optionsOrX, y));
+  }
+
+  /** Scroll by an offset; like `scrollTo` otherwise. */
+  scrollBy(options?: ScrollToOptions): void;
+  scrollBy(x: number, y: number): void;
+  scrollBy(optionsOrX?: ScrollToOptions | number, y?: number): void {
+    this._handle.scrollBy(scrollToOptions(optionsOrX, y));
+  }
+
+  /**
+   * Scroll the enclosing scroll containers and the viewport to show this
+   * element. `true` (default) aligns it to the top, `false` to the
+   * bottom. Uses the last resolved layout.
+   */
+  scrollIntoView(arg: boolean | ScrollIntoViewOptions = true): void {
+    const options = typeof arg === "object" ? arg : {block: arg ? "start" : "end"};
+    this._handle.scrollIntoView(options);
+  }
+
   get clientHeight(): number {
     return this._handle.clientHeight;
   }
@@ -175,4 +223,23 @@ export class Element extends Node {
   get clientWidth(): number {
     return this._handle.clientWidth;
   }
+
+  // ---- Pointer capture ---------------------------------------------------
+
+  /**
+   * Redirect further events of `pointerId` to this element until
+   * `pointerup` or `releasePointerCapture`. Throws if the element is not
+   * connected. `gotpointercapture` fires before the next pointer event.
+   */
+  setPointerCapture(pointerId: number): void {
+    this._handle.setPointerCapture(pointerId);
+  }
+
+  releasePointerCapture(pointerId: number): void {
+    this._handle.releasePointerCapture(pointerId);
+  }
+
+  hasPointerCapture(pointerId: number): boolean {
+    return this._handle.hasPointerCapture(pointerId);
+  }
 }
@@ -8,6 +8,8 @@
 import {Element} from "./element";
 import {makeStyleProxy, type StyleDeclaration} from "./style";
 
+const NATIVELY_FOCUSABLE = new Set(["a", "area", "button", "input", "select", "textarea"]);
+
 export class HTMLElement extends Element {
   private _styleProxy: StyleDeclaration | null = null;
 
@@ -28,12 +30,32 @@ export class HTMLElement extends Element {
    */
   get style(): StyleDeclaration {
     if (this._styleProxy === null) {
-      this._styleProxy = makeStyleProxy(this._handle);
+      this._styleProxy = makeStyleProxy(() => this._handle);
     }
     return this._styleProxy;
   }
 
-  /** Move focus to this element. Mirrors `HTMLElement.focus()`. */
+  /**
+   * Position in Tab order from the `tabindex` attribute. Without one,
+   * links and form controls report 0 and everything else -1, like
+   * browsers.
+   */
+  get tabIndex(): number {
+    const value = parseInt(this.getAttribute("tabindex") ?? "", 10);
+    if (!Number.isNaN(value)) return value;
+    return NATIVELY_FOCUSABLE.has(this.localName) ? 0 : -1;
+  }
+
+  set tabIndex(value: number) {
+    this.setAttribute("tabindex", String(Math.trunc(value)));
+  }
+
+  /**
+   * Move focus to this element. Mirrors `HTMLElement.focus()`: a no-op
+   * unless the element is focusable (form control, link, or `tabindex`).
+   * Fires `blur`/`focusout` on the previous element and `focus`/`focusin`
+   * here, synchronously.
+   */
   focus(): void {
     this._handle.focus();
   }
@@ -2,15 +2,17 @@
 //
 // Extends HTMLElement per the DOM standard. An `InputDataHandle` (native)
 // is passed as the third constructor argument by `wrap_node` and stored
-// for properties that need native-side access (value, checked, focused).
+// for properties that need native-side access (value, checked, files,
+// focused, selection).
 // Pure attribute-backed properties (type, disabled, placeholder, etc.)
 // use the inherited getAttribute/setAttribute from Element.
 
 import {HTMLElement} from "./html-element";
+import {assertLive} from "../internal/internal";
 import {InputDataHandle, NativeNode} from "../native";
 
 export class HTMLInputElement extends HTMLElement {
-  private readonly _inputData: InstanceType<typeof InputDataHandle> | null;
+  private readonly _inputDataHandle: InstanceType<typeof InputDataHandle> | null;
 
   /** @internal */
   constructor(
@@ -19,7 +21,12 @@ export class HTMLInputElement extends HTMLElement {
     inputData?: InstanceType<typeof InputDataHandle>,
   ) {
     super(handle, doc);
-    this._inputData = inputData ?? null;
+    this._inputDataHandle = inputData ?? null;
+  }
+
+  private get _inputData(): InstanceType<typeof InputDataHandle> | null {
+    assertLive(this._doc);
+    return this._inputDataHandle;
   }
 
   // ---- Properties backed by InputDataHandle (native editor / special_data) ---
@@ -50,10 +57,77 @@ export class HTMLInputElement extends HTMLElement {
     }
   }
 
+  /**
+   * Paths of the files selected in an `<input type="file">` — set by
+   * dropping files from the OS onto it. Script can only clear the list.
+   */
+  get files(): readonly string[] {
+    return this._inputData?.files ?? [];
+  }
+
+  set files(v: readonly string[] | null) {
+    if (this._inputData) {
+      this._inputData.files = v ? [...v] : [];
+    }
+  }
+
   get focused(): boolean {
     return this._inputData?.focused ?? false;
   }
 
+  // ---- Selection (native editor; offsets in UTF-16 code units) ----------------
+
+  get selectionStart(): number | null {
+    return this._inputData?.selectionStart ?? null;
+  }
+
+  set selectionStart(v: number) {
+    const end = Math.max(v, this.selectionEnd ?? 0);
+    this.setSelectionRange(v, end, this.selectionDirection ?? undefined);
+  }
+
+  get selectionEnd(): number | null {
+    return this._inputData?.selectionEnd ?? null;
+  }
+
+  set selectionEnd(v: number) {
+    this.setSelectionRange(this.selectionStart ?? 0, v, this.selectionDirection ?? undefined);
+  }
+
+  get selectionDirection(): "forward" | "backward" | null {
+    return (this._inputData?.selectionDirection ?? null) as "forward" | "backward" | null;
+  }
+
+  set selectionDirection(v: "forward" | "backward") {
+    this.setSelectionRange(this.selectionStart ?? 0, this.selectionEnd ?? 0, v);
+  }
+
+  /**
+   * Select `start..end`. Fires `selectionchange`, plus `select` when the
+   * range is not empty. No-op until the element has been laid out.
+   */
+  setSelectionRange(start: number, end: number, direction?: "forward" | "backward" | "none"): void {
+    this._inputData?.setSelectionRange(start, end, direction);
+  }
+
+  /** Select the whole value. */
+  select(): void {
+    this._inputData?.select();
+  }
+
+  /**
+   * Replace `start..end` (default: the selection) with `replacement`.
+   * `selectMode` places the selection afterwards, as in browsers.
+   */
+  setRangeText(
+    replacement: string,
+    start?: number,
+    end?: number,
+    selectMode?: "select" | "start" | "end" | "preserve",
+  ): void {
+    this._inputData?.setRangeText(replacement, start, end, selectMode);
+  }
+
   // ---- Pure attribute-backed properties ---------------------------------------
 
   get type(): string {
@@ -2,15 +2,16 @@
 //
 // Extends HTMLElement per the DOM standard. An `InputDataHandle` (native)
 // is passed as the third constructor argument by `wrap_node` and stored
-// for the `value` property (which needs native editor access).
+// for the properties that need native editor access (value, selection).
 // Pure attribute-backed properties (rows, cols, placeholder, etc.)
 // use the inherited getAttribute/setAttribute from Element.
 
 import {HTMLElement} from "./html-element";
+import {assertLive} from "../internal/internal";
 import {InputDataHandle, NativeNode} from "../native";
 
 export class HTMLTextAreaElement extends HTMLElement {
-  private readonly _inputData: InstanceType<typeof InputDataHandle> | null;
+  private readonly _inputDataHandle: InstanceType<typeof InputDataHandle> | null;
 
   /** @internal */
   constructor(
@@ -19,7 +20,12 @@ export class HTMLTextAreaElement extends HTMLElement {
     inputData?: InstanceType<typeof InputDataHandle>,
   ) {
     super(handle, doc);
-    this._inputData = inputData ?? null;
+    this._inputDataHandle = inputData ?? null;
+  }
+
+  private get _inputData(): InstanceType<typeof InputDataHandle> | null {
+    assertLive(this._doc);
+    return this._inputDataHandle;
   }
 
   // ---- Properties backed by InputDataHandle (native editor) ------------------
@@ -40,6 +46,59 @@ export class HTMLTextAreaElement extends HTMLElement {
     return this._inputData?.focused ?? false;
   }
 
+  // ---- Selection (native editor; offsets in UTF-16 code units) ----------------
+
+  get selectionStart(): number | null {
+    return this._inputData?.selectionStart ?? null;
+  }
+
+  set selectionStart(v: number) {
+    const end = Math.max(v, this.selectionEnd ?? 0);
+    this.setSelectionRange(v, end, this.selectionDirection ?? undefined);
+  }
+
+  get selectionEnd(): number | null {
+    return this._inputData?.selectionEnd ?? null;
+  }
+
+  set selectionEnd(v: number) {
+    this.setSelectionRange(this.selectionStart ?? 0, v, this.selectionDirection ?? undefined);
+  }
+
+  get selectionDirection(): "forward" | "backward" | null {
+    return (this._inputData?.selectionDirection ?? null) as "forward" | "backward" | null;
+  }
+
+  set selectionDirection(v: "forward" | "backward") {
+    this.setSelectionRange(this.selectionStart ?? 0, this.selectionEnd ?? 0, v);
+  }
+
+  /**
+   * Select `start..end`. Fires `selectionchange`, plus `select` when the
+   * range is not empty. No-op until the element has been laid out.
+   */
+  setSelectionRange(start: number, end: number, direction?: "forward" | "backward" | "none"): void {
+    this._inputData?.setSelectionRange(start, end, direction);
+  }
+
+  /** Select the whole value. */
+  select(): void {
+    this._inputData?.select();
+  }
+
+  /**
+   * Replace `start..end` (default: the selection) with `replacement`.
+   * `selectMode` places the selection afterwards, as in browsers.
+   */
+  setRangeText(
+    replacement: string,
+    start?: number,
+    end?: number,
+    selectMode?: "select" | "start" | "end" | "preserve",
+  ): void {
+    this._inputData?.setRangeText(replacement, start, end, selectMode);
+  }
+
   // ---- Pure attribute-backed properties ---------------------------------------
 
   get rows(): number {
@@ -6,9 +6,24 @@
 // `target`, `currentTarget`, `eventPhase`, `type`, `timeStamp`,
 // `isTrusted`. We extend it with the standard UIEvent → MouseEvent →
 // PointerEvent / WheelEvent chain and the KeyboardEvent, InputEvent,
-// CompositionEvent, FocusEvent subclasses.
-
-import type {EventPayload, ImeData, InputData, KeyData, PointerData, WheelData,} from "../native";
+// CompositionEvent, FocusEvent, ClipboardEvent, DragEvent, TouchEvent
+// subclasses, plus GestureEvent for trackpad gestures and WindowEvent for
+// the window lifecycle notifications.
+
+import type {
+  DataTransferItems,
+  DragData,
+  EventPayload,
+  GestureData,
+  ImeData,
+  InputData,
+  KeyData,
+  PointerData,
+  TouchData,
+  TouchPoint,
+  WheelData,
+  WindowEventData,
+} from "../native";
 
 /**
  * Base class for every event we dispatch into the JS layer.
@@ -25,6 +40,14 @@ export class UIEvent extends Event {
       composed: false,
       ...init,
     });
+    // Rust stamps payloads in Unix epoch milliseconds, when the event was
+    // created natively. Payloads built in JS (tests) keep Node's stamp.
+    if (payload.timeStamp !== undefined) {
+      Object.defineProperty(this, "timeStamp", {
+        value: payload.timeStamp - performance.timeOrigin,
+        configurable: true,
+      });
+    }
   }
 }
 
@@ -70,19 +93,56 @@ export class MouseEvent extends UIEvent {
   }
 
   get ctrlKey() {
-    return (this._ptr.modsBits & 2) !== 0;
+    return this._ptr.ctrlKey;
   }
 
   get shiftKey() {
-    return (this._ptr.modsBits & 1) !== 0;
+    return this._ptr.shiftKey;
   }
 
   get altKey() {
-    return (this._ptr.modsBits & 4) !== 0;
+    return this._ptr.altKey;
   }
 
   get metaKey() {
-    return (this._ptr.modsBits & 8) !== 0;
+    return this._ptr.metaKey;
+  }
+
+  /** Whether a modifier or lock key ("Control", "CapsLock", ...) is active. */
+  getModifierState(key: string): boolean {
+    return this._ptr.getModifierState(key);
+  }
+
+  /** Position relative to the target's border box. */
+  get offsetX() {
+    return this._ptr.offsetX;
+  }
+
+  get offsetY() {
+    return this._ptr.offsetY;
+  }
+
+  /** Distance moved since the previous move, for `*move` events. */
+  get movementX() {
+    return this._ptr.movementX;
+  }
+
+  get movementY() {
+    return this._ptr.movementY;
+  }
+
+  /** Click count of `mousedown` / `mouseup` / `click` / `dblclick`. */
+  get detail() {
+    return this._ptr.detail;
+  }
+
+  /**
+   * The element the pointer came from (`*over` / `*enter`) or moved to
+   * (`*out` / `*leave`). Installed by Rust as a lazy own property when
+   * there is one; this getter supplies the `null` default.
+   */
+  get relatedTarget(): EventTarget | null {
+    return null;
   }
 }
 
@@ -115,6 +175,31 @@ export class PointerEvent extends MouseEvent {
   get twist() {
     return this._ptr.twist;
   }
+
+  /**
+   * The samples merged into this `pointermove` when moves are coalesced
+   * (see `Window.coalescePointerMoves`), oldest first and ending with this
+   * event's own position. Empty for other pointer events.
+   */
+  getCoalescedEvents(): PointerEvent[] {
+    return this._ptr.getCoalescedEvents().map((ptr) => this._sample(ptr));
+  }
+
+  /** Expected next samples of this `pointermove`, extrapolated. */
+  getPredictedEvents(): PointerEvent[] {
+    return this._ptr.getPredictedEvents().map((ptr) => this._sample(ptr));
+  }
+
+  private _sample(pointer: PointerData): PointerEvent {
+    // Samples are never dispatched: they neither bubble nor cancel.
+    const payload = {
+      type: this.type,
+      bubbles: false,
+      cancelable: false,
+      timeStamp: this.timeStamp + performance.timeOrigin,
+    } as EventPayload;
+    return new PointerEvent(payload, pointer);
+  }
 }
 
 /** Wheel / scroll events. */
@@ -159,19 +244,24 @@ export class WheelEvent extends UIEvent {
   }
 
   get ctrlKey() {
-    return (this._wheel.modsBits & 2) !== 0;
+    return this._wheel.ctrlKey;
   }
 
   get shiftKey() {
-    return (this._wheel.modsBits & 1) !== 0;
+    return this._wheel.shiftKey;
   }
 
   get altKey() {
-    return (this._wheel.modsBits & 4) !== 0;
+    return this._wheel.altKey;
   }
 
   get metaKey() {
-    return (this._wheel.modsBits & 8) !== 0;
+    return this._wheel.metaKey;
+  }
+
+  /** Whether a modifier or lock key ("Control", "CapsLock", ...) is active. */
+  getModifierState(key: string): boolean {
+    return this._wheel.getModifierState(key);
   }
 }
 
@@ -205,19 +295,24 @@ export class KeyboardEvent extends UIEvent {
   }
 
   get ctrlKey() {
-    return (this._key.modsBits & 2) !== 0;
+    return this._key.ctrlKey;
   }
 
   get shiftKey() {
-    return (this._key.modsBits & 1) !== 0;
+    return this._key.shiftKey;
   }
 
   get altKey() {
-    return (this._key.modsBits & 4) !== 0;
+    return this._key.altKey;
   }
 
   get metaKey() {
-    return (this._key.modsBits & 8) !== 0;
+    return this._key.metaKey;
+  }
+
+  /** Whether a modifier or lock key ("Control", "CapsLock", ...) is active. */
+  getModifierState(key: string): boolean {
+    return this._key.getModifierState(key);
   }
 
   get text() {
@@ -257,11 +352,321 @@ export class CompositionEvent extends UIEvent {
   }
 }
 
-/** Focus events: focus, blur, focusin, focusout. */
+const FOCUS_EVENT_TYPES = new Set(["focus", "blur", "focusin", "focusout"]);
+
+/**
+ * Focus events: focus, blur, focusin, focusout.
+ *
+ * `relatedTarget` (the element losing / gaining focus on the other side)
+ * is installed by Rust as a lazy own property when there is one; this
+ * prototype getter supplies the `null` default.
+ */
 export class FocusEvent extends UIEvent {
   constructor(payload: EventPayload) {
     super(payload);
   }
+
+  get relatedTarget(): EventTarget | null {
+    return null;
+  }
+}
+
+/**
+ * Clipboard events: copy, cut, paste.
+ *
+ * `clipboardData` is shared with Rust. For `paste` it holds the clipboard
+ * text; `copy` / `cut` listeners that call `preventDefault()` put the
+ * data to copy into it with `setData()`.
+ */
+export class ClipboardEvent extends UIEvent {
+  readonly clipboardData: DataTransferItems;
+
+  constructor(payload: EventPayload, clipboardData: DataTransferItems) {
+    super(payload);
+    this.clipboardData = clipboardData;
+  }
+}
+
+type DataTransferMode = "read/write" | "read-only" | "protected";
+
+type DropEffect = "none" | "copy" | "link" | "move";
+
+/**
+ * `event.dataTransfer` of drag events, backed by the drag's shared
+ * native state.
+ *
+ * Access follows the HTML drag data store modes: `dragstart` listeners
+ * may write data, `drop` listeners may read it, and every other event
+ * only sees `types`. Files dragged in from the OS are exposed as paths
+ * (listed in `types` as "Files").
+ */
+export class DataTransfer {
+  private readonly _drag: DragData;
+  private readonly _mode: DataTransferMode;
+  effectAllowed = "all";
+
+  /** @internal */
+  constructor(drag: DragData, mode: DataTransferMode) {
+    this._drag = drag;
+    this._mode = mode;
+  }
+
+  get dropEffect(): DropEffect {
+    return this._drag.dropEffect as DropEffect;
+  }
+
+  set dropEffect(v: DropEffect) {
+    this._drag.dropEffect = v;
+  }
+
+  get types(): string[] {
+    const types = this._drag.data.types;
+    return this._drag.files.length > 0 ? [...types, "Files"] : types;
+  }
+
+  get files(): readonly string[] {
+    return this._mode === "protected" ? [] : this._drag.files;
+  }
+
+  getData(format: string): string {
+    return this._mode === "protected" ? "" : this._drag.data.getData(format);
+  }
+
+  setData(format: string, data: string): void {
+    if (this._mode === "read/write") this._drag.data.setData(format, data);
+  }
+
+  clearData(format?: string): void {
+    if (this._mode === "read/write") this._drag.data.clearData(format);
+  }
+}
+
+function dataTransferMode(type: string): DataTransferMode {
+  if (type === "dragstart") return "read/write";
+  if (type === "drop") return "read-only";
+  return "protected";
+}
+
+/**
+ * Drag-and-drop events: dragstart, drag, dragenter, dragover, dragleave,
+ * drop, dragend.
+ *
+ * Canceling `dragover` accepts the drop; only an accepted drag gets a
+ * `drop`, otherwise the target sees `dragleave`.
+ */
+export class DragEvent extends UIEvent {
+  private readonly _drag: DragData;
+  readonly dataTransfer: DataTransfer;
+
+  constructor(payload: EventPayload, drag: DragData) {
+    super(payload);
+    this._drag = drag;
+    this.dataTransfer = new DataTransfer(drag, dataTransferMode(payload.type));
+  }
+
+  get clientX() {
+    return this._drag.clientX;
+  }
+
+  get clientY() {
+    return this._drag.clientY;
+  }
+}
+
+/** One finger of a `TouchEvent`. */
+export class Touch {
+  private readonly _point: TouchPoint;
+
+  /** @internal */
+  constructor(point: TouchPoint) {
+    this._point = point;
+  }
+
+  get identifier() {
+    return this._point.identifier;
+  }
+
+  /** The element the touch started on, even after moving off it. */
+  get target(): EventTarget | null {
+    return this._point.target() as EventTarget | null;
+  }
+
+  get clientX() {
+    return this._point.clientX;
+  }
+
+  get clientY() {
+    return this._point.clientY;
+  }
+
+  get pageX() {
+    return this._point.pageX;
+  }
+
+  get pageY() {
+    return this._point.pageY;
+  }
+
+  get screenX() {
+    return this._point.screenX;
+  }
+
+  get screenY() {
+    return this._point.screenY;
+  }
+
+  get force() {
+    return this._point.force;
+  }
+}
+
+/** Read-only list of `Touch`es, indexable like an array. */
+export class TouchList {
+  readonly length: number;
+  readonly [index: number]: Touch;
+
+  /** @internal */
+  constructor(points: TouchPoint[]) {
+    this.length = points.length;
+    points.forEach((point, i) => {
+      (this as unknown as Touch[])[i] = new Touch(point);
+    });
+  }
+
+  item(index: number): Touch | null {
+    return this[index] ?? null;
+  }
+
+  *[Symbol.iterator](): IterableIterator<Touch> {
+    for (let i = 0; i < this.length; i++) yield this[i];
+  }
+}
+
+/**
+ * Touch events: touchstart, touchmove, touchend, touchcancel. Fired at the
+ * element each touch started on, after the finger's pointer event and its
+ * default actions, so they are not cancelable; cancel the pointer event
+ * instead.
+ */
+export class TouchEvent extends UIEvent {
+  private readonly _touch: TouchData;
+  readonly touches: TouchList;
+  readonly targetTouches: TouchList;
+  readonly changedTouches: TouchList;
+
+  constructor(payload: EventPayload, touch: TouchData) {
+    super(payload);
+    this._touch = touch;
+    this.touches = new TouchList(touch.touches);
+    this.targetTouches = new TouchList(touch.targetTouches);
+    this.changedTouches = new TouchList(touch.changedTouches);
+  }
+
+  get ctrlKey() {
+    return this._touch.ctrlKey;
+  }
+
+  get shiftKey() {
+    return this._touch.shiftKey;
+  }
+
+  get altKey() {
+    return this._touch.altKey;
+  }
+
+  get metaKey() {
+    return this._touch.metaKey;
+  }
+
+  /** Whether a modifier or lock key ("Control", "CapsLock", ...) is active. */
+  getModifierState(key: string): boolean {
+    return this._touch.getModifierState(key);
+  }
+}
+
+/**
+ * Trackpad gestures: gesturestart, gesturechange, gestureend, for the
+ * `kind`s "pinch", "rotate" and "pan". Values are accumulated since
+ * `gesturestart`. Canceling a pinch's `gesturechange` keeps it from
+ * zooming a window with `pinchZoom` on.
+ */
+export class GestureEvent extends UIEvent {
+  private readonly _gesture: GestureData;
+
+  constructor(payload: EventPayload, gesture: GestureData) {
+    super(payload);
+    this._gesture = gesture;
+  }
+
+  get kind() {
+    return this._gesture.kind as "pinch" | "rotate" | "pan";
+  }
+
+  get scale() {
+    return this._gesture.scale;
+  }
+
+  get rotation() {
+    return this._gesture.rotation;
+  }
+
+  get deltaX() {
+    return this._gesture.deltaX;
+  }
+
+  get deltaY() {
+    return this._gesture.deltaY;
+  }
+}
+
+/**
+ * Window lifecycle events: resize, move, focus, blur, scalefactorchange,
+ * themechange, occluded, visible, minimize, restore, the raw pointer
+ * `motion` of a locked cursor, and their `window:*` echoes on the app.
+ *
+ * Only the fields of the event's own kind are set (e.g. `width` /
+ * `height` for `resize`); the others are `null`. Sizes and positions are
+ * physical pixels.
+ */
+export class WindowEvent extends UIEvent {
+  private readonly _window: WindowEventData;
+
+  constructor(payload: EventPayload, window: WindowEventData) {
+    super(payload);
+    this._window = window;
+  }
+
+  get width() {
+    return this._window.width;
+  }
+
+  get height() {
+    return this._window.height;
+  }
+
+  get x() {
+    return this._window.x;
+  }
+
+  get y() {
+    return this._window.y;
+  }
+
+  get scaleFactor() {
+    return this._window.scaleFactor;
+  }
+
+  get theme() {
+    return this._window.theme;
+  }
+
+  get movementX() {
+    return this._window.movementX;
+  }
+
+  get movementY() {
+    return this._window.movementY;
+  }
 }
 
 /**
@@ -273,5 +678,11 @@ export function buildEvent(payload: EventPayload): Event {
   if (payload.key) return new KeyboardEvent(payload, payload.key);
   if (payload.input) return new InputEvent(payload, payload.input);
   if (payload.ime) return new CompositionEvent(payload, payload.ime);
+  if (payload.drag) return new DragEvent(payload, payload.drag);
+  if (payload.clipboardData) return new ClipboardEvent(payload, payload.clipboardData);
+  if (payload.window) return new WindowEvent(payload, payload.window);
+  if (payload.touch) return new TouchEvent(payload, payload.touch);
+  if (payload.gesture) return new GestureEvent(payload, payload.gesture);
+  if (FOCUS_EVENT_TYPES.has(payload.type)) return new FocusEvent(payload);
   return new UIEvent(payload);
 }
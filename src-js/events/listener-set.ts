@@ -0,0 +1,59 @@
+// `ListenerSet` — the (type, callback, capture) registrations of one
+// `EventTarget`, mirrored so the native side hears about each distinct
+// registration exactly once (`addListener` / `removeListener`).
+// `EventTarget` ignores duplicate adds and unknown removes; counting them
+// natively would let the counts drift.
+//
+// `once` listeners and ones removed by an aborted `signal` leave the
+// EventTarget without passing through `removeEventListener`, so they stay
+// counted: the native side then dispatches an event nobody handles, which
+// is harmless.
+
+const BUBBLE = 1;
+const CAPTURE = 2;
+
+function captureFlag(options?: boolean | EventListenerOptions): number {
+  const capture = typeof options === "boolean" ? options : (options?.capture ?? false);
+  return capture ? CAPTURE : BUBBLE;
+}
+
+export class ListenerSet {
+  private readonly _byType = new Map<string, Map<EventListenerOrEventListenerObject, number>>();
+
+  /** Record a registration. `true` if `EventTarget` did not have it yet. */
+  add(
+    type: string,
+    callback: EventListenerOrEventListenerObject,
+    options?: boolean | AddEventListenerOptions,
+  ): boolean {
+    const flag = captureFlag(options);
+    let callbacks = this._byType.get(type);
+    if (callbacks === undefined) {
+      callbacks = new Map();
+      this._byType.set(type, callbacks);
+    }
+    const flags = callbacks.get(callback) ?? 0;
+    if (flags & flag) return false;
+    callbacks.set(callback, flags | flag);
+    return true;
+  }
+
+  /** Forget a registration. `true` if it was recorded. */
+  delete(
+    type: string,
+    callback: EventListenerOrEventListenerObject,
+    options?: boolean | EventListenerOptions,
+  ): boolean {
+    const flag = captureFlag(options);
+    const callbacks = this._byType.get(type);
+    const flags = callbacks?.get(callback) ?? 0;
+    if (!(flags & flag)) return false;
+    if (flags === flag) {
+      callbacks!.delete(callback);
+      if (callbacks!.size === 0) this._byType.delete(type);
+    } else {
+      callbacks!.set(callback, flags & ~flag);
+    }
+    return true;
+  }
+}
@@ -17,6 +17,9 @@
 //      Otherwise native sets the closed flag immediately, tears the window
 //      down on the next pump, then dispatches `closed` on the window plus
 //      `window:closed` on the app.
+//   5. `app.dispose()` (or `using app = ...`) closes every window without
+//      a cancelable `close` and drops the event loop. Documents outlive
+//      their windows and are disposed on their own (`document.dispose()`).
 //
 // All of these lifecycle events are dispatched from the Rust side
 // (`JsShellEventHandler`) — JS holds no parallel dispatch model.
@@ -31,8 +34,15 @@
 //   - `window:close`  (cancelable — the app-level echo of the window's
 //                     `close`, same moment; may preventDefault() to veto)
 //   - `window:closed` (non-cancelable — after teardown)
+//   - `window:resize`, `window:move`, `window:focus`, `window:blur`,
+//     `window:scalefactorchange`, `window:themechange`,
+//     `window:occluded`, `window:visible`, `window:minimize`,
+//     `window:restore` (non-cancelable echoes of the window's
+//     `WindowEvent`s; `event.window` is the Window they came from)
 //
-// Pump-loop lifecycle (`pumpLoop` / `pumpStart`):
+// Pump-loop lifecycle (`pumpLoop`, timer-driven or, with
+// `integrated: true` on Linux / BSD, woken by the native side when the
+// loop has work):
 //
 //   - `pump:start` (non-cancelable)
 //   - `pump`       (non-cancelable, `detail: { result }`)
@@ -58,6 +68,27 @@ interface DocumentInternalsForApp {
   readonly _native: InstanceType<typeof NativeDoc>;
 }
 
+/**
+ * Second argument of `BlitzApp.openWindow` when the new window belongs to
+ * another one.
+ */
+export interface OpenWindowInit {
+  /** Window attributes, as when passing a `WindowOptions` directly. */
+  options?: InstanceType<typeof WindowOptions>;
+  /**
+   * Owner window. The new window stays above it and closes along with
+   * it (receiving `closed`, but no cancelable `close`).
+   */
+  owner?: Window;
+  /**
+   * Owner window of a modal: like `owner`, and while the new window is
+   * open the owner receives no input and its `close` is vetoed
+   * (`closeWindow` rejects). Await the modal's `result` for the value it
+   * was closed with.
+   */
+  modalTo?: Window;
+}
+
 /**
  * Options for `BlitzApp.pumpLoop`. All durations are in milliseconds.
  */
@@ -77,6 +108,19 @@ export interface PumpOptions {
    * pump returns and `pump:end` fires with `reason: 'aborted'`.
    */
   signal?: AbortSignal;
+  /**
+   * Let the native side wake the loop instead of polling it from a timer:
+   * each pump runs as soon as the event loop has work (OS events, redraws,
+   * DOM mutations), never blocks the JS thread, and an idle app costs no
+   * CPU. `targetPeriod` and `timeout` are ignored. Linux / BSD only: on
+   * other platforms the loop rejects (see `NativeApp.watchEvents`).
+   */
+  integrated?: boolean;
+  /**
+   * With `integrated`, the longest gap between two pumps, so winit's own
+   * timers still run. Defaults to `500`.
+   */
+  maxWait?: number;
 }
 
 function pluckDoc(doc: HTMLDocument): DocumentInternalsForApp {
@@ -93,6 +137,9 @@ export class BlitzApp extends EventTarget {
   /** True while a `pumpLoop` loop is running (re-entrancy guard). */
   private _pumping = false;
 
+  /** Ends a running integrated `pumpLoop`, which has no pump to notice. */
+  private _endWatch: (() => void) | null = null;
+
   private constructor(native: InstanceType<typeof NativeApp>) {
     super();
     this._native = native;
@@ -115,8 +162,20 @@ export class BlitzApp extends EventTarget {
    * this resolves once the OS window exists and `windowId` is valid. Safe to
    * call from inside an event handler (e.g. a click) — the native side never
    * recursively pumps the event loop.
+   *
+   * Pass an `OpenWindowInit` instead of the options to open an owned or
+   * modal window, e.g. `openWindow(doc, {modalTo: window})`.
    */
-  async openWindow(document: HTMLDocument, options?: InstanceType<typeof WindowOptions>): Promise<Window> {
+  async openWindow(
+    document: HTMLDocument,
+    init?: InstanceType<typeof WindowOptions> | OpenWindowInit,
+  ): Promise<Window> {
+    const {options, owner, modalTo}: OpenWindowInit =
+      init instanceof WindowOptions ? {options: init} : (init ?? {});
+    if (owner && modalTo) {
+      throw new TypeError("openWindow: pass either owner or modalTo, not both");
+    }
+    const ownerWindow = modalTo ?? owner;
     // Rust dispatches the cancelable app-level `window:open` event while
     // creating the window, before this promise resolves. A listener's
     // `preventDefault()` rejects `openWindow` (the native side drops the
@@ -124,9 +183,15 @@ export class BlitzApp extends EventTarget {
     const nativeWindow: InstanceType<typeof NativeWindow> = await this._native.openWindow(
       pluckDoc(document)._native,
       options,
+      ownerWindow && pluckWindow(ownerWindow)._nativeWindow,
+      modalTo !== undefined,
     );
-    const window = new Window(this, nativeWindow, document);
-    this._windows.set(nativeWindow.windowId, window);
+    const window = new Window(this, nativeWindow, document, ownerWindow ?? null);
+    const id = nativeWindow.windowId;
+    this._windows.set(id, window);
+    // Windows also close without `closeWindow` (the OS close button, or
+    // along with their owner).
+    window.addEventListener("closed", () => this._windows.delete(id), {once: true});
     return window;
   }
 
@@ -197,6 +262,11 @@ export class BlitzApp extends EventTarget {
    *     the background, and a thrown error is surfaced as `pump:error`
    *     instead of an unhandled rejection.
    *
+   * With `integrated: true` there is no timer: the native side calls back
+   * whenever the event loop has work and each pump uses a zero timeout.
+   * Integrated mode is Linux / BSD only; on macOS and Windows the loop
+   * rejects, so use the timer-driven loop there.
+   *
    * Only one pump loop may run per app; calling again while one is active
    * throws. Start the loop from top-level setup, not from inside an event
    * handler — pumping from within a pump re-enters the native loop.
@@ -212,12 +282,16 @@ export class BlitzApp extends EventTarget {
       timeout = targetPeriod,
       signal,
       join,
+      integrated,
+      maxWait,
     } = options;
 
     this._pumping = true;
     this.dispatchEvent(new CustomEvent("pump:start"));
 
-    const looping = this._pumpLoop(targetPeriod, timeout, signal);
+    const looping = integrated
+      ? this._watchLoop(maxWait, signal)
+      : this._pumpLoop(targetPeriod, timeout, signal);
 
     if (join) return looping;
 
@@ -247,6 +321,7 @@ export class BlitzApp extends EventTarget {
           reason = "aborted";
           break;
         }
+        if (native.disposed) break;
         const result = native.pumpAppEvents(timeout);
         this.dispatchEvent(
           new CustomEvent("pump", {detail: {result}}),
@@ -268,6 +343,71 @@ export class BlitzApp extends EventTarget {
     }
   }
 
+  private _watchLoop(maxWait?: number, signal?: AbortSignal): Promise<void> {
+    const native = this._native;
+    return new Promise<void>((resolve, reject) => {
+      let done = false;
+      const finish = (reason: "exit" | "aborted", error?: unknown) => {
+        if (done) return;
+        done = true;
+        this._endWatch = null;
+        native.unwatchEvents();
+        signal?.removeEventListener("abort", onAbort);
+        this._pumping = false;
+        this.dispatchEvent(
+          new CustomEvent("pump:end", {detail: {reason}}),
+        );
+        if (error === undefined) resolve();
+        else reject(error);
+      };
+      const onAbort = () => finish("aborted");
+      if (signal?.aborted) {
+        onAbort();
+        return;
+      }
+      signal?.addEventListener("abort", onAbort);
+      this._endWatch = () => finish("exit");
+      try {
+        native.watchEvents(() => {
+          // A wakeup queued before `unwatchEvents` may still arrive.
+          if (done) return;
+          try {
+            const result = native.pumpAppEvents(0);
+            this.dispatchEvent(
+              new CustomEvent("pump", {detail: {result}}),
+            );
+            if (result.exit) finish("exit");
+          } catch (error) {
+            finish("exit", error);
+          }
+        }, maxWait);
+      } catch (error) {
+        finish("exit", error);
+      }
+    });
+  }
+
+  /** Whether `dispose()` has run. */
+  get disposed(): boolean {
+    return this._native.disposed;
+  }
+
+  /**
+   * Close every window and drop the native event loop now. Windows get
+   * `closed` (but no cancelable `close`), pending `openWindow` promises
+   * reject, a running `pumpLoop` ends with `reason: 'exit'`, and further
+   * use of the app throws. Documents are not disposed.
+   */
+  dispose(): void {
+    this._native.dispose();
+    this._windows.clear();
+    this._endWatch?.();
+  }
+
+  [Symbol.dispose](): void {
+    this.dispose();
+  }
+
   /** Set the document zoom level for a window. `1.0` is unzoomed. */
   setZoom(window: Window, zoom: number): void {
     this._native.setZoom(pluckWindow(window)._nativeWindow, zoom);
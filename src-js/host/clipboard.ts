@@ -0,0 +1,50 @@
+// `clipboard` — app-level clipboard access, shaped like the web's
+// `navigator.clipboard`: every method returns a promise so code written
+// against the browser API ports over unchanged. The native calls are
+// synchronous underneath.
+//
+// Besides text, images can be exchanged as raw RGBA8 pixels
+// (`ClipboardImage`), which is what the OS clipboards store natively.
+//
+// All access — including the default actions of the `copy` / `cut` /
+// `paste` DOM events — goes through one backend per thread. It defaults
+// to the OS clipboard; tests call `clipboard.setBackend("memory")` to
+// work without a display server and without touching the user's
+// clipboard.
+
+import {
+  clipboardReadImage,
+  clipboardReadText,
+  clipboardWriteImage,
+  clipboardWriteText,
+  setClipboardBackend,
+} from "../native";
+import type {ClipboardImage} from "../native";
+
+export type ClipboardBackendKind = "system" | "memory";
+
+export const clipboard = {
+  /** Clipboard text; "" when the clipboard holds no text. */
+  async readText(): Promise<string> {
+    return clipboardReadText() ?? "";
+  },
+
+  async writeText(text: string): Promise<void> {
+    clipboardWriteText(text);
+  },
+
+  /** Clipboard image, or `null` when the clipboard holds no image. */
+  async readImage(): Promise<ClipboardImage | null> {
+    return clipboardReadImage();
+  },
+
+  /** Write RGBA8 pixels; `data.length` must be `width * height * 4`. */
+  async writeImage(image: ClipboardImage): Promise<void> {
+    clipboardWriteImage(image);
+  },
+
+  /** Swap the backend. `"memory"` starts out empty. */
+  setBackend(kind: ClipboardBackendKind): void {
+    setClipboardBackend(kind);
+  },
+};
@@ -21,6 +21,25 @@
 //                drop references and let the GC reclaim the
 //                associated document tree. The app gets `window:closed`.
 //
+//   - Non-cancelable notifications translated from the OS, dispatched as
+//     `WindowEvent`s: `resize` (`width`, `height`), `move` (`x`, `y`),
+//     `focus` / `blur`, `scalefactorchange` (`scaleFactor`),
+//     `themechange` (`theme`), `occluded` / `visible` and `minimize` /
+//     `restore`, and `motion` (`movementX`, `movementY`) while the cursor
+//     is locked. Each one is echoed to the app as `window:<type>`.
+//
+// A window opened with `openWindow(doc, {owner})` closes along with its
+// owner; one opened with `{modalTo}` additionally blocks the owner's input
+// and vetoes its `close` until the modal is gone. `close(value)` plus the
+// `result` promise hand a value back from a dialog window.
+//
+// Frameless windows with HTML titlebars mark elements with the
+// `app-region` attribute (or an inline `-app-region` style declaration):
+// `drag` moves the window, `resize-<n|ne|e|se|s|sw|w|nw>` resizes it, and
+// `no-drag` opts a descendant such as a close button back out. A primary
+// press on a region is handed to the window manager before the document
+// sees it; `startDrag()` / `startResize()` are the scripted equivalents.
+//
 // Closing is explicit, not GC-driven: a user calling `close()` expects
 // the window to disappear immediately. The Rust side sets the closed
 // flag immediately and queues the actual `View` teardown for the next
@@ -28,10 +47,41 @@
 
 import type {BlitzApp} from "./app";
 import type {HTMLDocument} from "../document/html-document";
-import type {MonitorInfo, VideoModeInfo, WindowHandle} from "../native";
+import type {
+  BufferFrame,
+  CursorImage,
+  MonitorInfo,
+  ScrollToOptions,
+  VideoModeInfo,
+  WindowHandle,
+  WindowInsets,
+} from "../native";
 import {NativeWindow} from "../native";
+import {ListenerSet} from "../events/listener-set";
+import {scrollToOptions} from "../element/element";
+
+export type CursorGrabMode = "none" | "confined" | "locked";
+
+export type UserAttentionKind = "critical" | "informational";
+
+export type WindowLevel = "normal" | "always-on-top" | "always-on-bottom";
+
+/** Edge or corner a window resize starts from. */
+export type ResizeDirection = "n" | "ne" | "e" | "se" | "s" | "sw" | "w" | "nw";
 
 export class Window extends EventTarget {
+  /**
+   * Value the window was closed with: set by `close(returnValue)`, or
+   * directly before the window closes by other means. `result` resolves
+   * to it.
+   */
+  returnValue: unknown = undefined;
+
+  private readonly _result: Promise<unknown>;
+
+  /** Registrations reported to the document, which forwards pointer events. */
+  private readonly _listeners = new ListenerSet();
+
   /**
    * @internal Constructed by `BlitzApp.openWindow`. Direct construction
    * outside the package is unsupported.
@@ -40,14 +90,42 @@ export class Window extends EventTarget {
     private readonly _app: BlitzApp,
     private readonly _nativeWindow: InstanceType<typeof NativeWindow>,
     private readonly _document: HTMLDocument,
+    private readonly _owner: Window | null = null,
   ) {
     super();
+    this._result = new Promise((resolve) => {
+      this.addEventListener("closed", () => resolve(this.returnValue), {once: true});
+    });
     // Store a ref to this Window so Rust can forward pointer events
     // (pointermove/pointerup) to window-level listeners via the
     // registered dispatch function.
     this._document._native.setWindowRef(this);
   }
 
+  override addEventListener(
+    type: string,
+    callback: EventListenerOrEventListenerObject | null,
+    options?: boolean | AddEventListenerOptions,
+  ): void {
+    super.addEventListener(type, callback, options);
+    if (callback === null || this._document.disposed) return;
+    if (this._listeners.add(type, callback, options)) {
+      this._document._native.addWindowListener(type);
+    }
+  }
+
+  override removeEventListener(
+    type: string,
+    callback: EventListenerOrEventListenerObject | null,
+    options?: boolean | EventListenerOptions,
+  ): void {
+    super.removeEventListener(type, callback, options);
+    if (callback === null || this._document.disposed) return;
+    if (this._listeners.delete(type, callback, options)) {
+      this._document._native.removeWindowListener(type);
+    }
+  }
+
   /** The HTMLDocument painted in this window. */
   get document(): HTMLDocument {
     return this._document;
@@ -62,6 +140,20 @@ export class Window extends EventTarget {
     return this._nativeWindow.windowId;
   }
 
+  /** The window this one was opened for (`owner` / `modalTo`), if any. */
+  get owner(): Window | null {
+    return this._owner;
+  }
+
+  /**
+   * Resolves with `returnValue` once the window has closed, however it
+   * was closed. The natural way to await a modal:
+   * `const answer = await (await app.openWindow(doc, {modalTo: w})).result`.
+   */
+  get result(): Promise<unknown> {
+    return this._result;
+  }
+
   /** Whether the window has been closed. */
   get closed(): boolean {
     return this._nativeWindow.closed;
@@ -77,9 +169,33 @@ export class Window extends EventTarget {
    * On success the promise resolves once the native `View` has actually
    * been torn down (on the next pump); the JS-side `closed` flag flips
    * immediately.
+   *
+   * `returnValue`, when given, becomes the value `result` resolves to; a
+   * vetoed close leaves the previous value in place.
+   */
+  async close(returnValue?: unknown): Promise<void> {
+    const previous = this.returnValue;
+    if (arguments.length > 0) this.returnValue = returnValue;
+    try {
+      await this._app.closeWindow(this);
+    } catch (error) {
+      this.returnValue = previous;
+      throw error;
+    }
+  }
+
+  /**
+   * Close the window without a cancelable `close`, as when its owner
+   * closes: it is torn down on the next pump and then fires `closed`.
+   * The document stays usable; dispose it separately after `closed`.
+   * No-op on a closed window.
    */
-  async close(): Promise<void> {
-    await this._app.closeWindow(this);
+  dispose(): void {
+    this._nativeWindow.dispose();
+  }
+
+  [Symbol.dispose](): void {
+    this.dispose();
   }
 
   /**
@@ -96,6 +212,159 @@ export class Window extends EventTarget {
     }
   }
 
+  /**
+   * Outer size including decorations in physical pixels, as
+   * `[width, height]`. `null` while the window is uninitialised or
+   * closed.
+   */
+  get outerSize(): [number, number] | null {
+    try {
+      const dims = this._nativeWindow.getOuterSize();
+      return [dims[0], dims[1]];
+    } catch {
+      return null;
+    }
+  }
+
+  /**
+   * Position of the outer top-left corner on the virtual desktop in
+   * physical pixels, as `[x, y]`. `null` while the window is
+   * uninitialised or closed, and on platforms that do not expose window
+   * positions (Wayland). Together with `outerSize` this is what a saved
+   * layout needs; restore it with `WindowOptions.position`.
+   */
+  get position(): [number, number] | null {
+    try {
+      const pos = this._nativeWindow.getPosition();
+      return [pos[0], pos[1]];
+    } catch {
+      return null;
+    }
+  }
+
+  /**
+   * Move the outer top-left corner, in physical pixels. Observe `move`
+   * events for the actual outcome.
+   */
+  setPosition(x: number, y: number): void {
+    this._nativeWindow.setPosition(x, y);
+  }
+
+  /**
+   * Start moving the window with the pointer, as if the user grabbed its
+   * title bar. Call from a primary-button `pointerdown` listener; the
+   * move ends when the button is released. For frameless windows
+   * (`WindowOptions.decorations(false)`), marking the titlebar element
+   * with `app-region="drag"` does the same without any script.
+   */
+  startDrag(): void {
+    this._nativeWindow.startDrag();
+  }
+
+  /**
+   * Start resizing the window from an edge or corner, like `startDrag`.
+   * The declarative form is `app-region="resize-<direction>"`.
+   */
+  startResize(direction: ResizeDirection): void {
+    this._nativeWindow.startResize(direction);
+  }
+
+  /**
+   * Override the cursor that CSS `cursor` picks: a CSS cursor keyword
+   * (`"pointer"`, `"grab"`, ...) or an RGBA image. `null` (or `"auto"`)
+   * hands the cursor back to CSS from the next pointer move.
+   */
+  setCursor(cursor: string | CursorImage | null): void {
+    if (cursor !== null && typeof cursor === "object") {
+      this._nativeWindow.setCursorImage(cursor);
+    } else {
+      this._nativeWindow.setCursor(cursor);
+    }
+  }
+
+  setCursorVisible(visible: boolean): void {
+    this._nativeWindow.setCursorVisible(visible);
+  }
+
+  /**
+   * Keep the cursor inside the window (`"confined"`) or hold it in place
+   * (`"locked"`), e.g. for games and drawing tools. While locked the
+   * window receives `motion` events with the raw `movementX` /
+   * `movementY`. Throws when the platform lacks the mode (X11 cannot
+   * lock, macOS cannot confine), so callers can fall back to the other.
+   */
+  setCursorGrab(mode: CursorGrabMode): void {
+    this._nativeWindow.setCursorGrab(mode);
+  }
+
+  /**
+   * Whether `pointermove` is throttled to one event per pointer and
+   * frame (default `true`). The moves in between are still reported by
+   * `PointerEvent.getCoalescedEvents()`. Set to `false` for every raw
+   * sample, e.g. in drawing apps that handle their own batching.
+   */
+  get coalescePointerMoves(): boolean {
+    return this._nativeWindow.getCoalescePointerMoves();
+  }
+
+  set coalescePointerMoves(value: boolean) {
+    this._nativeWindow.setCoalescePointerMoves(value);
+  }
+
+  /**
+   * Whether trackpad pinches zoom the document (default `false`). A
+   * listener can still keep a pinch from zooming by canceling its
+   * `gesturechange`.
+   */
+  get pinchZoom(): boolean {
+    return this._nativeWindow.getPinchZoom();
+  }
+
+  set pinchZoom(value: boolean) {
+    this._nativeWindow.setPinchZoom(value);
+  }
+
+  /** Horizontal scroll offset of the document's viewport. */
+  get scrollX(): number {
+    return this._document._native.scrollX;
+  }
+
+  /** Vertical scroll offset of the document's viewport. */
+  get scrollY(): number {
+    return this._document._native.scrollY;
+  }
+
+  /**
+   * Scroll the viewport, clamped to the document's size. `behavior:
+   * "smooth"` animates over the next frames. `scroll` and `scrollend`
+   * fire at the document and bubble here.
+   */
+  scrollTo(options?: ScrollToOptions): void;
+  scrollTo(x: number, y: number): void;
+  scrollTo(optionsOrX?: ScrollToOptions | number, y?: number): void {
+    this._document._native.scrollTo(scrollToOptions(optionsOrX, y));
+  }
+
+  /** Scroll the viewport by an offset; like `scrollTo` otherwise. */
+  scrollBy(options?: ScrollToOptions): void;
+  scrollBy(x: number, y: number): void;
+  scrollBy(optionsOrX?: ScrollToOptions | number, y?: number): void {
+    this._document._native.scrollBy(scrollToOptions(optionsOrX, y));
+  }
+
+  /**
+   * Safe-area insets of the surface in physical pixels: the parts
+   * covered by notches, rounded corners or overlaid title bars. `null`
+   * while the window is uninitialised or closed.
+   */
+  get safeArea(): WindowInsets | null {
+    try {
+      return this._nativeWindow.getSafeArea();
+    } catch {
+      return null;
+    }
+  }
+
   /**
    * Request a new surface size. winit may settle on a different size
    * depending on the platform's window manager; observe `resize`
@@ -134,6 +403,15 @@ export class Window extends EventTarget {
     this._nativeWindow.setSize(width, height);
   }
 
+  /**
+   * Render the document into an RGBA buffer at the current `innerSize`
+   * and scale (`scaleFactor` times zoom), e.g. for screenshots or visual
+   * tests. Rejects while the window is uninitialised or closed.
+   */
+  async capture(): Promise<BufferFrame> {
+    return this._nativeWindow.capture();
+  }
+
   setMinSize(width: number, height: number): void {
     this._nativeWindow.setMinSize(width, height);
   }
@@ -150,6 +428,76 @@ export class Window extends EventTarget {
     this._nativeWindow.setMaximized(value);
   }
 
+  setMinimized(value: boolean): void {
+    this._nativeWindow.setMinimized(value);
+  }
+
+  /** Minimize the window. Observe `minimize` / `restore` for the outcome. */
+  minimize(): void {
+    this._nativeWindow.setMinimized(true);
+  }
+
+  /**
+   * Whether the window is minimized. `null` while the window is
+   * uninitialised or closed, or when the platform cannot tell (Wayland).
+   */
+  get isMinimized(): boolean | null {
+    try {
+      return this._nativeWindow.isMinimized();
+    } catch {
+      return null;
+    }
+  }
+
+  /** Whether the window is maximized; `null` while uninitialised or closed. */
+  get isMaximized(): boolean | null {
+    try {
+      return this._nativeWindow.isMaximized();
+    } catch {
+      return null;
+    }
+  }
+
+  /** Whether the window has input focus; `null` while uninitialised or closed. */
+  get isFocused(): boolean | null {
+    try {
+      return this._nativeWindow.isFocused();
+    } catch {
+      return null;
+    }
+  }
+
+  /**
+   * Bring the window to the front and focus it. The OS may refuse to
+   * steal focus from another application; `requestUserAttention` is the
+   * polite alternative.
+   */
+  focusWindow(): void {
+    this._nativeWindow.focusWindow();
+  }
+
+  /**
+   * Flash the taskbar entry / bounce the dock icon until the user focuses
+   * the window. `null` cancels a pending request.
+   */
+  requestUserAttention(kind: UserAttentionKind | null): void {
+    this._nativeWindow.requestUserAttention(kind);
+  }
+
+  setWindowLevel(level: WindowLevel): void {
+    this._nativeWindow.setWindowLevel(level);
+  }
+
+  /** Keep the window out of the taskbar. Windows only; ignored elsewhere. */
+  setSkipTaskbar(value: boolean): void {
+    this._nativeWindow.setSkipTaskbar(value);
+  }
+
+  /** Exclude the window from screenshots and screen sharing (Windows, macOS). */
+  setContentProtected(value: boolean): void {
+    this._nativeWindow.setContentProtected(value);
+  }
+
   setVisible(value: boolean): void {
     this._nativeWindow.setVisible(value);
   }
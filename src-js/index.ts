@@ -4,7 +4,16 @@
 // the shared DOM API. Headless buffer rendering lives in `@ylcc/wasm-blitz`.
 export * from "./native";
 export {BlitzApp} from "./host/app";
+export type {OpenWindowInit} from "./host/app";
 export {Window} from "./host/window";
+export type {
+  CursorGrabMode,
+  ResizeDirection,
+  UserAttentionKind,
+  WindowLevel,
+} from "./host/window";
+export {clipboard} from "./host/clipboard";
+export type {ClipboardBackendKind} from "./host/clipboard";
 
 export {Document} from "./document/document";
 export type {DocumentInit} from "./document/document";
@@ -33,6 +42,9 @@ export type {
 export {FontFaceSet} from "./fonts/font-face-set";
 export type {FontFaceSetLoadStatus} from "./fonts/font-face-set";
 
+export {Range} from "./selection/range";
+export {Selection} from "./selection/selection";
+
 export {
   UIEvent,
   MouseEvent,
@@ -42,6 +54,14 @@ export {
   InputEvent,
   CompositionEvent,
   FocusEvent,
+  ClipboardEvent,
+  DragEvent,
+  DataTransfer,
+  Touch,
+  TouchList,
+  TouchEvent,
+  GestureEvent,
+  WindowEvent,
 } from "./events/events";
 
 import "./register"; // side effect: registers JS constructors with Rust
@@ -16,6 +16,17 @@ export interface NodeInternals {
 /** Shape of a `Document`'s package-private fields, viewed by friends. */
 export interface DocumentInternals {
   readonly _native: InstanceType<typeof NativeDoc>;
+  /** Set by `Document.dispose()`. */
+  readonly _disposed?: boolean;
+}
+
+/**
+ * Throw if `doc` was disposed. Node, element and document accessors of
+ * the native side check this first, so a disposed document is never
+ * touched natively.
+ */
+export function assertLive(doc: DocumentInternals): void {
+  if (doc._disposed) throw new Error("document is disposed");
 }
 
 /** Read the package-private fields off a `Node` instance. */
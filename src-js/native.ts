@@ -32,6 +32,7 @@ export const NativeWindow = mod.NativeWindow;
 export const NativeNode = mod.NativeNode;
 export const WindowOptions = mod.WindowOptions;
 export const initEnv = mod.initEnv;
+export const addonStats = mod.addonStats;
 export const registerNodeConstructor = mod.registerNodeConstructor;
 export const registerElementConstructor = mod.registerElementConstructor;
 export const registerEventFactory = mod.registerEventFactory;
@@ -41,3 +42,9 @@ export const pickFiles = mod.pickFiles;
 export const pickFolder = mod.pickFolder;
 export const pickFolders = mod.pickFolders;
 export const saveFile = mod.saveFile;
+export const DataTransferItems = mod.DataTransferItems;
+export const setClipboardBackend = mod.setClipboardBackend;
+export const clipboardReadText = mod.clipboardReadText;
+export const clipboardWriteText = mod.clipboardWriteText;
+export const clipboardReadImage = mod.clipboardReadImage;
+export const clipboardWriteImage = mod.clipboardWriteImage;
@@ -1,9 +1,11 @@
 // One-time registration of JS constructors and event factory with the
-// Rust global registry. This module is imported for its side effects.
+// Rust registry of the current N-API environment (one per thread / worker).
+// This module is imported for its side effects.
 //
 // Must be imported after all DOM classes are defined. Importing this
 // module registers everything; subsequent imports are no-ops (the Rust
-// side stores in a global, and JS classes don't change).
+// side keeps the registry for the life of the environment, and JS classes
+// don't change).
 
 import type {EventPayload} from "./native";
 import {
@@ -0,0 +1,224 @@
+// `Range` - a pair of DOM boundary points, mirroring the web `Range`
+// interface (https://developer.mozilla.org/en-US/docs/Web/API/Range).
+//
+// Boundary points are kept here as (node, offset) pairs. Everything that
+// needs the tree or the layout (ordering, text extraction, geometry) is
+// forwarded to the native document, which owns both.
+//
+// Unlike browser ranges, ours are not live: mutating the tree does not
+// adjust the boundary points. Mutation methods (`deleteContents`,
+// `insertNode`, ...) are not implemented.
+
+import type {DomRect} from "../native";
+import type {Node} from "../base/node";
+import {pluckNode, type DocumentInternals} from "../internal/internal";
+
+export class Range {
+  private readonly _doc: DocumentInternals;
+  private _startContainer: Node;
+  private _startOffset = 0;
+  private _endContainer: Node;
+  private _endOffset = 0;
+
+  /** @internal Use `document.createRange()`. */
+  constructor(doc: DocumentInternals, root: Node) {
+    this._doc = doc;
+    this._startContainer = root;
+    this._endContainer = root;
+  }
+
+  static readonly START_TO_START = 0;
+  static readonly START_TO_END = 1;
+  static readonly END_TO_END = 2;
+  static readonly END_TO_START = 3;
+
+  get startContainer(): Node {
+    return this._startContainer;
+  }
+
+  get startOffset(): number {
+    return this._startOffset;
+  }
+
+  get endContainer(): Node {
+    return this._endContainer;
+  }
+
+  get endOffset(): number {
+    return this._endOffset;
+  }
+
+  get collapsed(): boolean {
+    return this._startContainer === this._endContainer && this._startOffset === this._endOffset;
+  }
+
+  /** Deepest node containing both boundary points. */
+  get commonAncestorContainer(): Node {
+    const ancestors = new Set<Node>();
+    for (let n: Node | null = this._startContainer; n !== null; n = n.parentNode) {
+      ancestors.add(n);
+    }
+    for (let n: Node | null = this._endContainer; n !== null; n = n.parentNode) {
+      if (ancestors.has(n)) return n;
+    }
+    return this._startContainer;
+  }
+
+  // ---- Setting boundary points --------------------------------------------
+
+  /** Per spec, a start after the end collapses the range onto the start. */
+  setStart(node: Node, offset: number): void {
+    this._check(node, offset);
+    this._startContainer = node;
+    this._startOffset = offset;
+    if (this._compare(node, offset, this._endContainer, this._endOffset) > 0) {
+      this._endContainer = node;
+      this._endOffset = offset;
+    }
+  }
+
+  /** Per spec, an end before the start collapses the range onto the end. */
+  setEnd(node: Node, offset: number): void {
+    this._check(node, offset);
+    this._endContainer = node;
+    this._endOffset = offset;
+    if (this._compare(this._startContainer, this._startOffset, node, offset) > 0) {
+      this._startContainer = node;
+      this._startOffset = offset;
+    }
+  }
+
+  setStartBefore(node: Node): void {
+    const [parent, index] = this._position(node);
+    this.setStart(parent, index);
+  }
+
+  setStartAfter(node: Node): void {
+    const [parent, index] = this._position(node);
+    this.setStart(parent, index + 1);
+  }
+
+  setEndBefore(node: Node): void {
+    const [parent, index] = this._position(node);
+    this.setEnd(parent, index);
+  }
+
+  setEndAfter(node: Node): void {
+    const [parent, index] = this._position(node);
+    this.setEnd(parent, index + 1);
+  }
+
+  collapse(toStart = false): void {
+    if (toStart) {
+      this._endContainer = this._startContainer;
+      this._endOffset = this._startOffset;
+    } else {
+      this._startContainer = this._endContainer;
+      this._startOffset = this._endOffset;
+    }
+  }
+
+  selectNode(node: Node): void {
+    const [parent, index] = this._position(node);
+    this._startContainer = this._endContainer = parent;
+    this._startOffset = index;
+    this._endOffset = index + 1;
+  }
+
+  selectNodeContents(node: Node): void {
+    this._startContainer = this._endContainer = node;
+    this._startOffset = 0;
+    this._endOffset = this._doc._native.nodeLength(pluckNode(node)._handle);
+  }
+
+  cloneRange(): Range {
+    const clone = new Range(this._doc, this._startContainer);
+    clone._startOffset = this._startOffset;
+    clone._endContainer = this._endContainer;
+    clone._endOffset = this._endOffset;
+    return clone;
+  }
+
+  // ---- Comparison ---------------------------------------------------------
+
+  compareBoundaryPoints(how: number, source: Range): number {
+    switch (how) {
+      case Range.START_TO_START:
+        return this._compare(this._startContainer, this._startOffset, source._startContainer, source._startOffset);
+      case Range.START_TO_END:
+        return this._compare(this._endContainer, this._endOffset, source._startContainer, source._startOffset);
+      case Range.END_TO_END:
+        return this._compare(this._endContainer, this._endOffset, source._endContainer, source._endOffset);
+      case Range.END_TO_START:
+        return this._compare(this._startContainer, this._startOffset, source._endContainer, source._endOffset);
+      default:
+        throw new RangeError(`compareBoundaryPoints: invalid how ${how}`);
+    }
+  }
+
+  /** -1, 0 or 1 for a point before, inside or after the range. */
+  comparePoint(node: Node, offset: number): number {
+    this._check(node, offset);
+    if (this._compare(node, offset, this._startContainer, this._startOffset) < 0) return -1;
+    if (this._compare(node, offset, this._endContainer, this._endOffset) > 0) return 1;
+    return 0;
+  }
+
+  isPointInRange(node: Node, offset: number): boolean {
+    return this.comparePoint(node, offset) === 0;
+  }
+
+  // ---- Content and geometry -----------------------------------------------
+
+  /** The text of every text node in the range. */
+  toString(): string {
+    return this._doc._native.rangeText(...this._bounds());
+  }
+
+  /** One rect per line fragment of the text in the range. */
+  getClientRects(): DomRect[] {
+    return this._doc._native.rangeClientRects(...this._bounds());
+  }
+
+  /** Union of `getClientRects()`; all zero for a range without text. */
+  getBoundingClientRect(): DomRect {
+    const rects = this.getClientRects();
+    if (rects.length === 0) {
+      return {x: 0, y: 0, width: 0, height: 0, top: 0, left: 0, bottom: 0, right: 0};
+    }
+    const left = Math.min(...rects.map((r) => r.left));
+    const top = Math.min(...rects.map((r) => r.top));
+    const right = Math.max(...rects.map((r) => r.right));
+    const bottom = Math.max(...rects.map((r) => r.bottom));
+    return {x: left, y: top, width: right - left, height: bottom - top, top, left, bottom, right};
+  }
+
+  // ---- Internals ----------------------------------------------------------
+
+  private _bounds() {
+    return [
+      pluckNode(this._startContainer)._handle,
+      this._startOffset,
+      pluckNode(this._endContainer)._handle,
+      this._endOffset,
+    ] as const;
+  }
+
+  private _compare(a: Node, aOffset: number, b: Node, bOffset: number): number {
+    return this._doc._native.compareBoundaryPoints(pluckNode(a)._handle, aOffset, pluckNode(b)._handle, bOffset);
+  }
+
+  private _check(node: Node, offset: number): void {
+    if (offset < 0 || offset > this._doc._native.nodeLength(pluckNode(node)._handle)) {
+      throw new RangeError(`offset ${offset} is larger than the node's length`);
+    }
+  }
+
+  private _position(node: Node): [Node, number] {
+    const parent = node.parentNode;
+    if (parent === null) {
+      throw new TypeError("the node has no parent");
+    }
+    return [parent, parent.childNodes.indexOf(node)];
+  }
+}
@@ -0,0 +1,147 @@
+// `Selection` - the document's text selection, mirroring the web
+// `Selection` interface
+// (https://developer.mozilla.org/en-US/docs/Web/API/Selection).
+//
+// `document.getSelection()` returns one instance per document. It holds
+// no state of its own: anchor and focus live on the native document,
+// where mouse drags over text update them too. Every change fires
+// `selectionchange` at the document.
+//
+// A selection has at most one range. `getRangeAt(0)` returns a snapshot
+// `Range`; changing it does not change the selection.
+
+import type {Node} from "../base/node";
+import {pluckNode, type DocumentInternals} from "../internal/internal";
+import {Range} from "./range";
+
+export class Selection {
+  private readonly _doc: DocumentInternals;
+
+  /** @internal Use `document.getSelection()`. */
+  constructor(doc: DocumentInternals) {
+    this._doc = doc;
+  }
+
+  get anchorNode(): Node | null {
+    return this._doc._native.selectionNode(false) as Node | null;
+  }
+
+  get anchorOffset(): number {
+    return this._doc._native.selectionOffset(false) ?? 0;
+  }
+
+  get focusNode(): Node | null {
+    return this._doc._native.selectionNode(true) as Node | null;
+  }
+
+  get focusOffset(): number {
+    return this._doc._native.selectionOffset(true) ?? 0;
+  }
+
+  get rangeCount(): number {
+    return this.anchorNode === null ? 0 : 1;
+  }
+
+  get isCollapsed(): boolean {
+    return this.anchorNode === this.focusNode && this.anchorOffset === this.focusOffset;
+  }
+
+  get type(): "None" | "Caret" | "Range" {
+    if (this.rangeCount === 0) return "None";
+    return this.isCollapsed ? "Caret" : "Range";
+  }
+
+  get direction(): "none" | "forward" | "backward" {
+    const anchor = this.anchorNode;
+    const focus = this.focusNode;
+    if (anchor === null || focus === null || this.isCollapsed) return "none";
+    const order = this._doc._native.compareBoundaryPoints(
+      pluckNode(anchor)._handle,
+      this.anchorOffset,
+      pluckNode(focus)._handle,
+      this.focusOffset,
+    );
+    return order > 0 ? "backward" : "forward";
+  }
+
+  getRangeAt(index: number): Range {
+    const anchor = this.anchorNode;
+    const focus = this.focusNode;
+    if (index !== 0 || anchor === null || focus === null) {
+      throw new RangeError(`getRangeAt: index ${index} is out of range`);
+    }
+    const range = new Range(this._doc, anchor);
+    if (this.direction === "backward") {
+      range.setStart(focus, this.focusOffset);
+      range.setEnd(anchor, this.anchorOffset);
+    } else {
+      range.setStart(anchor, this.anchorOffset);
+      range.setEnd(focus, this.focusOffset);
+    }
+    return range;
+  }
+
+  /** Select `range`. Like browsers, ignored when a range is already selected. */
+  addRange(range: Range): void {
+    if (this.rangeCount > 0) return;
+    this.setBaseAndExtent(range.startContainer, range.startOffset, range.endContainer, range.endOffset);
+  }
+
+  removeRange(_range: Range): void {
+    this.removeAllRanges();
+  }
+
+  removeAllRanges(): void {
+    this._doc._native.clearSelection();
+  }
+
+  empty(): void {
+    this.removeAllRanges();
+  }
+
+  collapse(node: Node | null, offset = 0): void {
+    if (node === null) {
+      this.removeAllRanges();
+      return;
+    }
+    this.setBaseAndExtent(node, offset, node, offset);
+  }
+
+  /** Move the focus, keeping the anchor. */
+  extend(node: Node, offset = 0): void {
+    const anchor = this.anchorNode;
+    if (anchor === null) {
+      throw new Error("InvalidStateError: extend() needs an existing selection");
+    }
+    this.setBaseAndExtent(anchor, this.anchorOffset, node, offset);
+  }
+
+  setBaseAndExtent(anchorNode: Node, anchorOffset: number, focusNode: Node, focusOffset: number): void {
+    this._doc._native.setSelection(
+      pluckNode(anchorNode)._handle,
+      anchorOffset,
+      pluckNode(focusNode)._handle,
+      focusOffset,
+    );
+  }
+
+  selectAllChildren(node: Node): void {
+    const length = this._doc._native.nodeLength(pluckNode(node)._handle);
+    this.setBaseAndExtent(node, 0, node, length);
+  }
+
+  containsNode(node: Node, allowPartialContainment = false): boolean {
+    if (this.rangeCount === 0) return false;
+    const range = this.getRangeAt(0);
+    const parent = node.parentNode;
+    if (parent === null) return false;
+    const index = parent.childNodes.indexOf(node);
+    const before = range.comparePoint(parent, index);
+    const after = range.comparePoint(parent, index + 1);
+    return allowPartialContainment ? before !== 1 && after !== -1 : before === 0 && after === 0;
+  }
+
+  toString(): string {
+    return this.rangeCount === 0 ? "" : this.getRangeAt(0).toString();
+  }
+}
@@ -10,22 +10,97 @@
 
 use blitz::shell::{BlitzShellEvent, View};
 use napi::Error;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
 use winit::{
-    application::ApplicationHandler, event::WindowEvent, event_loop::ActiveEventLoop,
-    window::WindowId as WinitWindowId,
+    application::ApplicationHandler,
+    event::{
+        ButtonSource, DeviceEvent, DeviceId, ElementState, MouseButton, TouchPhase, WindowEvent,
+    },
+    event_loop::ActiveEventLoop,
+    window::{Theme, UserAttentionType, WindowId as WinitWindowId},
 };
 
 use crate::{
     app::{AppState, NativeWindow, PendingRequest, WindowEntry, shell_event::JsShellEventHandler},
-    global,
+    dom::{
+        doc::WindowDocument,
+        file_drop::{FileDragEvent, handle_file_drag},
+        gesture::{GestureDelta, GesturePhase, handle_gesture},
+        payload::{EventPayload, WindowEventData, now_ms},
+        scroll::tick_scrolls,
+    },
+    window::{
+        WindowState,
+        cursor::{realize_pending_cursor, update_cursor},
+        region::{AppRegion, app_region_at},
+        util::place_window,
+    },
 };
 
+/// Zoom range reachable by pinching a window with `setPinchZoom(true)`.
+const MIN_PINCH_ZOOM: f32 = 0.25;
+const MAX_PINCH_ZOOM: f32 = 5.0;
+
 pub struct AppHandler {
     pub state: Rc<RefCell<AppState>>,
 }
 
 impl AppHandler {
+    /// Dispatch the `pointermove`s a window queued since its last frame
+    /// (see `dom/pointer_moves.rs`), then settle the cursor for the new
+    /// hover state. Runs right before the window redraws.
+    fn flush_pointer_moves(&mut self, event_loop: &dyn ActiveEventLoop, window_id: WinitWindowId) {
+        let doc = {
+            let state = self.state.borrow();
+            let Some(entry) = state.windows.get(&window_id) else {
+                return;
+            };
+            Rc::clone(&entry.shared_doc)
+        };
+        // Listeners may close the window or dispose of the app.
+        if !WindowDocument::new(Rc::clone(&doc)).flush_pointer_moves() || !doc.in_window.get() {
+            return;
+        }
+        let state = self.state.borrow();
+        if let Some(entry) = state.windows.get(&window_id) {
+            let mut win_state = entry.state.borrow_mut();
+            let win_state = &mut *win_state;
+            if let Some(window) = &win_state.window {
+                update_cursor(
+                    event_loop,
+                    &**window,
+                    &mut win_state.cursor,
+                    &entry.shared_doc.base.borrow(),
+                );
+            }
+        }
+    }
+
+    /// Run a frame of scrolling for each window that has one pending:
+    /// smooth scrolls advance and `scroll` / `scrollend` fire (see
+    /// `dom/scroll.rs`).
+    fn tick_scrolls(&mut self) {
+        let docs: Vec<_> = {
+            let state = self.state.borrow();
+            state
+                .windows
+                .values()
+                .filter(|entry| entry.shared_doc.scrolls.borrow().is_active())
+                .map(|entry| Rc::clone(&entry.shared_doc))
+                .collect()
+        };
+        let now = now_ms();
+        for doc in docs {
+            // Listeners may close the window or dispose of the app.
+            if !doc.in_window.get() {
+                continue;
+            }
+            if let Err(e) = tick_scrolls(&doc, now, &doc.env) {
+                eprintln!("napi-blitz: scroll event dispatch failed: {e}");
+            }
+        }
+    }
+
     /// Promote pending `WindowConfig`s into live `View`s. winit only
     /// fires `can_create_surfaces` on initial resume, so we must run
     /// this from every hook that has an `ActiveEventLoop`.
@@ -55,11 +130,24 @@ impl AppHandler {
                 config,
                 state: win_state,
                 shared_doc,
+                placement,
+                owner,
+                modal,
                 deferred,
             } = req
             else {
                 unreachable!()
             };
+            // The owner may have closed while the open was queued.
+            if let Some(owner) = owner
+                && !self.state.borrow().windows.contains_key(&owner)
+            {
+                shared_doc.in_window.set(false);
+                let mut state = self.state.borrow_mut();
+                state.outstanding_windows = state.outstanding_windows.saturating_sub(1);
+                deferred.reject(Error::from_reason("owner window is closed"));
+                continue;
+            }
             let mut view = View::init(*config, event_loop, &proxy);
             view.resume();
             let window_id = view.window_id();
@@ -68,22 +156,17 @@ impl AppHandler {
             // hold an AppState borrow: the dispatch re-enters JS and the
             // listener may call back into `NativeApp`.
             let js_app_ref = Rc::clone(&self.state.borrow().js_app_ref);
-            let allowed = match global::env() {
-                Ok(env) => JsShellEventHandler::new(js_app_ref).open_sequence(&env).unwrap_or_else(|e| {
+            let allowed = JsShellEventHandler::new(js_app_ref)
+                .open_sequence(&shared_doc.env)
+                .unwrap_or_else(|e| {
                     eprintln!(
                         "napi-blitz: drain_pending_windows: open_sequence failed, treating open as confirmed: {e}"
                     );
                     true
-                }),
-                Err(e) => {
-                    eprintln!(
-                        "napi-blitz: drain_pending_windows: env not available, treating open as confirmed: {e}"
-                    );
-                    true
-                }
-            };
+                });
             if !allowed {
                 drop(view);
+                shared_doc.in_window.set(false);
                 // The open was cancelled: roll back the outstanding-window
                 // count so the synthetic-exit check can still fire once the
                 // other windows close.
@@ -95,18 +178,30 @@ impl AppHandler {
                 continue;
             }
 
+            if let Some(placement) = &placement {
+                place_window(&*view.window, placement);
+            }
+
             // Now that the OS window exists, the bare WindowState becomes
             // shared: wrap it and fill in the live OS window.
             let shared = Rc::new(RefCell::new(win_state));
             shared.borrow_mut().window = Some(view.window.clone());
+            let wake_proxy = proxy.clone();
+            *shared_doc.wake.borrow_mut() = Some(Box::new(move || {
+                wake_proxy.send_event(BlitzShellEvent::Poll { window_id });
+            }));
             let native = NativeWindow {
                 window_id,
                 state: shared.clone(),
+                app: Rc::downgrade(&self.state),
+                doc: Rc::clone(&shared_doc),
             };
             let entry = WindowEntry {
                 view: Rc::new(RefCell::new(view)),
                 state: shared.clone(),
                 shared_doc,
+                owner,
+                modal,
             };
             self.state.borrow_mut().windows.insert(window_id, entry);
 
@@ -182,6 +277,28 @@ impl ApplicationHandler for AppHandler {
         window_id: WinitWindowId,
         event: WindowEvent,
     ) {
+        // A window with an open modal child takes no input and cannot be
+        // closed; pressing it brings the modal to the front instead.
+        if is_input_event(&event) || matches!(event, WindowEvent::CloseRequested) {
+            let state = self.state.borrow();
+            if let Some(modal) = state.modal_child(window_id) {
+                if matches!(
+                    event,
+                    WindowEvent::CloseRequested
+                        | WindowEvent::PointerButton {
+                            state: ElementState::Pressed,
+                            ..
+                        }
+                ) {
+                    let view = modal.view.borrow();
+                    view.window.focus_window();
+                    view.window
+                        .request_user_attention(Some(UserAttentionType::Informational));
+                }
+                return;
+            }
+        }
+
         if matches!(event, WindowEvent::CloseRequested) {
             // Clone the shell-event dispatch pieces without holding an
             // AppState borrow: `close_sequence` re-enters JS (dispatchEvent
@@ -194,13 +311,7 @@ impl ApplicationHandler for AppHandler {
                 };
                 (Rc::clone(&entry.shared_doc), Rc::clone(&state.js_app_ref))
             };
-            let env = match global::env() {
-                Ok(e) => e,
-                Err(e) => {
-                    eprintln!("napi-blitz: window_event CloseRequested: env not available: {e}");
-                    return;
-                }
-            };
+            let env = shared_doc.env;
             let handler = JsShellEventHandler::new(app_ref);
 
             // Dispatch `close` (cancelable) -> `closed` (window) ->
@@ -231,10 +342,121 @@ impl ApplicationHandler for AppHandler {
                     event_loop.exit();
                 }
                 state.outstanding_windows = state.outstanding_windows.saturating_sub(1);
+                state.close_owned(window_id);
+            }
+            return;
+        }
+
+        // OS file drags are not handled by blitz; they become DOM drag
+        // events (see `dom/file_drop.rs`).
+        if matches!(
+            event,
+            WindowEvent::DragEntered { .. }
+                | WindowEvent::DragMoved { .. }
+                | WindowEvent::DragDropped { .. }
+                | WindowEvent::DragLeft { .. }
+        ) {
+            let (shared_doc, scale) = {
+                let state = self.state.borrow();
+                let Some(entry) = state.windows.get(&window_id) else {
+                    return;
+                };
+                let scale = entry.view.borrow().window.scale_factor();
+                (Rc::clone(&entry.shared_doc), scale)
+            };
+            let env = shared_doc.env;
+            if let Some(drag) = file_drag_event(event, scale)
+                && let Err(e) = handle_file_drag(&shared_doc, drag, &env)
+            {
+                eprintln!("napi-blitz: window_event file drag: dispatch failed: {e}");
+            }
+            return;
+        }
+
+        // Trackpad gestures are not handled by blitz; they become
+        // `gesture*` events (see `dom/gesture.rs`) and, when enabled and
+        // not canceled, pinches zoom the document.
+        if let Some((phase, delta)) = gesture_event(&event) {
+            let (shared_doc, view, pinch_zoom, scale) = {
+                let state = self.state.borrow();
+                let Some(entry) = state.windows.get(&window_id) else {
+                    return;
+                };
+                let scale = entry.view.borrow().window.scale_factor();
+                (
+                    Rc::clone(&entry.shared_doc),
+                    Rc::clone(&entry.view),
+                    entry.state.borrow().pinch_zoom,
+                    scale,
+                )
+            };
+            let delta = match delta {
+                GestureDelta::Pan(x, y) => GestureDelta::Pan(x / scale, y / scale),
+                delta => delta,
+            };
+            let zoom_by = match (&delta, pinch_zoom) {
+                (GestureDelta::Pinch(delta), true) => Some(1.0 + delta),
+                _ => None,
+            };
+            let env = shared_doc.env;
+            match handle_gesture(&shared_doc, phase, delta, &env) {
+                Ok(false) => {
+                    if let Some(factor) = zoom_by {
+                        view.borrow_mut().with_viewport(|v| {
+                            let zoom = v.zoom() * factor as f32;
+                            v.set_zoom(zoom.clamp(MIN_PINCH_ZOOM, MAX_PINCH_ZOOM));
+                        });
+                    }
+                }
+                Ok(true) => {}
+                Err(e) => eprintln!("napi-blitz: window_event gesture: dispatch failed: {e}"),
             }
             return;
         }
 
+        // Queued pointer moves are dispatched once per frame, before the
+        // view paints it. `capture` resolves at the frame's time.
+        if matches!(event, WindowEvent::RedrawRequested) {
+            self.flush_pointer_moves(event_loop, window_id);
+            let state = self.state.borrow();
+            if let Some(entry) = state.windows.get(&window_id) {
+                let time = entry.view.borrow_mut().current_animation_time();
+                entry.state.borrow_mut().frame_time = time;
+            }
+        }
+
+        // Primary presses on an `app-region` element move or resize the
+        // window instead of reaching the document (see `window/region.rs`).
+        if let WindowEvent::PointerButton {
+            state: ElementState::Pressed,
+            position,
+            button: ButtonSource::Mouse(MouseButton::Left),
+            ..
+        } = &event
+        {
+            let target = {
+                let state = self.state.borrow();
+                state.windows.get(&window_id).and_then(|entry| {
+                    let view = entry.view.borrow();
+                    let zoom = view.doc.inner().viewport().zoom();
+                    let scale = view.window.scale_factor() * f64::from(zoom);
+                    let base = entry.shared_doc.base.borrow();
+                    app_region_at(&base, position.x / scale, position.y / scale)
+                        .map(|region| (region, Arc::clone(&view.window)))
+                })
+            };
+            if let Some((region, window)) = target {
+                let started = match region {
+                    AppRegion::Drag => window.drag_window(),
+                    AppRegion::Resize(direction) => window.drag_resize_window(direction),
+                };
+                match started {
+                    Ok(()) => return,
+                    Err(e) => eprintln!("napi-blitz: window_event app region: {e}"),
+                }
+            }
+        }
+
         // Non-close events: forward to the View's event handler.
         //
         // `handle_winit_event` may re-enter JS (click -> spawn -> openWindow).
@@ -247,18 +469,112 @@ impl ApplicationHandler for AppHandler {
         // view. The view's own RefCell borrow is held across the JS callback,
         // but re-entrant JS never touches *this* view except through a fresh
         // `AppState` borrow (which no longer conflicts), so this is safe.
-        let view_rc = {
+        //
+        // Window lifecycle notifications are translated first (the view
+        // consumes the event) but dispatched after the view has handled
+        // it, so `resize` listeners already see the new layout.
+        let (view_rc, lifecycle) = {
             let state = self.state.borrow();
-            state.windows.get(&window_id).map(|e| Rc::clone(&e.view))
+            let Some(entry) = state.windows.get(&window_id) else {
+                return;
+            };
+            let payloads = lifecycle_events(&event, &mut entry.state.borrow_mut());
+            let lifecycle = (!payloads.is_empty()).then(|| {
+                (
+                    payloads,
+                    Rc::clone(&entry.shared_doc),
+                    Rc::clone(&state.js_app_ref),
+                )
+            });
+            (Rc::clone(&entry.view), lifecycle)
         };
-        if let Some(view_rc) = view_rc {
-            view_rc.borrow_mut().handle_winit_event(event);
+        let pointer_moved = matches!(event, WindowEvent::PointerMoved { .. });
+        view_rc.borrow_mut().handle_winit_event(event);
+
+        // Settle the cursor the view picked for the hovered element (see
+        // `window/cursor.rs`).
+        if pointer_moved {
+            let state = self.state.borrow();
+            if let Some(entry) = state.windows.get(&window_id) {
+                let mut win_state = entry.state.borrow_mut();
+                let win_state = &mut *win_state;
+                if let Some(window) = &win_state.window {
+                    update_cursor(
+                        event_loop,
+                        &**window,
+                        &mut win_state.cursor,
+                        &entry.shared_doc.base.borrow(),
+                    );
+                }
+            }
+        }
+
+        if let Some((payloads, shared_doc, app_ref)) = lifecycle {
+            let env = shared_doc.env;
+            let handler = JsShellEventHandler::new(app_ref);
+            for payload in payloads {
+                if let Err(e) = handler.notify(payload, &shared_doc, &env) {
+                    eprintln!("napi-blitz: window_event lifecycle: dispatch failed: {e}");
+                }
+            }
+        }
+    }
+
+    fn device_event(
+        &mut self,
+        _event_loop: &dyn ActiveEventLoop,
+        _device_id: Option<DeviceId>,
+        event: DeviceEvent,
+    ) {
+        // Raw motion only reaches windows whose cursor is locked, where the
+        // pointer itself no longer moves.
+        let DeviceEvent::PointerMotion { delta: (dx, dy) } = event else {
+            return;
+        };
+        let (docs, app_ref) = {
+            let state = self.state.borrow();
+            let docs: Vec<_> = state
+                .windows
+                .values()
+                .filter(|entry| entry.state.borrow().cursor.locked)
+                .map(|entry| Rc::clone(&entry.shared_doc))
+                .collect();
+            (docs, Rc::clone(&state.js_app_ref))
+        };
+        if docs.is_empty() {
+            return;
+        }
+        let handler = JsShellEventHandler::new(app_ref);
+        for doc in docs {
+            let payload = EventPayload {
+                window: Some(WindowEventData {
+                    movement_x: Some(dx),
+                    movement_y: Some(dy),
+                    ..Default::default()
+                }),
+                ..EventPayload::new("motion", false, false)
+            };
+            if let Err(e) = handler.notify(payload, &doc, &doc.env) {
+                eprintln!("napi-blitz: device_event motion: dispatch failed: {e}");
+            }
         }
     }
 
     fn about_to_wait(&mut self, event_loop: &dyn ActiveEventLoop) {
         self.drain_pending_windows(event_loop);
         self.drain_shell_events(event_loop);
+        self.tick_scrolls();
+
+        // `setCursorImage` cursors are created here rather than waiting
+        // for the next pointer move.
+        let state = self.state.borrow();
+        for entry in state.windows.values() {
+            let mut win_state = entry.state.borrow_mut();
+            let win_state = &mut *win_state;
+            if let Some(window) = &win_state.window {
+                realize_pending_cursor(event_loop, &**window, &mut win_state.cursor);
+            }
+        }
     }
 
     fn suspended(&mut self, _event_loop: &dyn ActiveEventLoop) {
@@ -275,3 +591,147 @@ impl ApplicationHandler for AppHandler {
         }
     }
 }
+
+/// Events that carry user input, which a modal child blocks.
+fn is_input_event(event: &WindowEvent) -> bool {
+    matches!(
+        event,
+        WindowEvent::PointerButton { .. }
+            | WindowEvent::PointerMoved { .. }
+            | WindowEvent::PointerEntered { .. }
+            | WindowEvent::PointerLeft { .. }
+            | WindowEvent::MouseWheel { .. }
+            | WindowEvent::KeyboardInput { .. }
+            | WindowEvent::ModifiersChanged(_)
+            | WindowEvent::Ime(_)
+            | WindowEvent::PinchGesture { .. }
+            | WindowEvent::PanGesture { .. }
+            | WindowEvent::DoubleTapGesture { .. }
+            | WindowEvent::RotationGesture { .. }
+            | WindowEvent::TouchpadPressure { .. }
+            | WindowEvent::DragEntered { .. }
+            | WindowEvent::DragMoved { .. }
+            | WindowEvent::DragDropped { .. }
+            | WindowEvent::DragLeft { .. }
+    )
+}
+
+/// Translate a winit trackpad gesture. Pan distances are still physical
+/// pixels.
+fn gesture_event(event: &WindowEvent) -> Option<(GesturePhase, GestureDelta)> {
+    let (phase, delta) = match event {
+        WindowEvent::PinchGesture { delta, phase, .. } => (phase, GestureDelta::Pinch(*delta)),
+        WindowEvent::RotationGesture { delta, phase, .. } => {
+            (phase, GestureDelta::Rotate(*delta as f64))
+        }
+        WindowEvent::PanGesture { delta, phase, .. } => {
+            (phase, GestureDelta::Pan(delta.x as f64, delta.y as f64))
+        }
+        _ => return None,
+    };
+    let phase = match phase {
+        TouchPhase::Started => GesturePhase::Start,
+        TouchPhase::Moved => GesturePhase::Change,
+        TouchPhase::Ended | TouchPhase::Cancelled => GesturePhase::End,
+    };
+    Some((phase, delta))
+}
+
+/// Translate a winit file drag event into a `FileDragEvent`, converting
+/// the position from physical to CSS pixels.
+fn file_drag_event(event: WindowEvent, scale: f64) -> Option<FileDragEvent> {
+    fn paths_of(paths: Vec<PathBuf>) -> Vec<String> {
+        paths
+            .into_iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect()
+    }
+    Some(match event {
+        WindowEvent::DragEntered { paths, position } => FileDragEvent::Enter {
+            paths: paths_of(paths),
+            x: position.x / scale,
+            y: position.y / scale,
+        },
+        WindowEvent::DragMoved { position } => FileDragEvent::Move {
+            x: position.x / scale,
+            y: position.y / scale,
+        },
+        WindowEvent::DragDropped { paths, position } => FileDragEvent::Drop {
+            paths: paths_of(paths),
+            x: position.x / scale,
+            y: position.y / scale,
+        },
+        WindowEvent::DragLeft { .. } => FileDragEvent::Leave,
+        _ => return None,
+    })
+}
+
+/// Window lifecycle events for a winit event, in dispatch order. winit
+/// has no minimize event, so the minimized state is re-checked whenever
+/// the window is resized, occluded or (un)focused.
+fn lifecycle_events(event: &WindowEvent, state: &mut WindowState) -> Vec<EventPayload> {
+    let notification = |event_type: &str, data: WindowEventData| EventPayload {
+        window: Some(data),
+        ..EventPayload::new(event_type, false, false)
+    };
+    let mut out = Vec::new();
+    match event {
+        WindowEvent::SurfaceResized(size) => out.push(notification(
+            "resize",
+            WindowEventData {
+                width: Some(size.width),
+                height: Some(size.height),
+                ..Default::default()
+            },
+        )),
+        WindowEvent::Moved(position) => out.push(notification(
+            "move",
+            WindowEventData {
+                x: Some(position.x),
+                y: Some(position.y),
+                ..Default::default()
+            },
+        )),
+        WindowEvent::Focused(focused) => out.push(notification(
+            if *focused { "focus" } else { "blur" },
+            WindowEventData::default(),
+        )),
+        WindowEvent::ScaleFactorChanged { scale_factor, .. } => out.push(notification(
+            "scalefactorchange",
+            WindowEventData {
+                scale_factor: Some(*scale_factor),
+                ..Default::default()
+            },
+        )),
+        WindowEvent::ThemeChanged(theme) => out.push(notification(
+            "themechange",
+            WindowEventData {
+                theme: Some(theme_name(*theme).to_string()),
+                ..Default::default()
+            },
+        )),
+        WindowEvent::Occluded(occluded) => out.push(notification(
+            if *occluded { "occluded" } else { "visible" },
+            WindowEventData::default(),
+        )),
+        _ => return out,
+    }
+
+    if let Some(minimized) = state.window.as_ref().and_then(|w| w.is_minimized())
+        && minimized != state.minimized
+    {
+        state.minimized = minimized;
+        out.push(notification(
+            if minimized { "minimize" } else { "restore" },
+            WindowEventData::default(),
+        ));
+    }
+    out
+}
+
+fn theme_name(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+    }
+}
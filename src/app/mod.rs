@@ -19,25 +19,42 @@
 //!   the current or next pump's `drain_pending_windows` creates the window and
 //!   resolves the promise. This is what makes opening a window from a click
 //!   handler safe — there is no nested event-loop recursion.
+//!
+//! # Integrated pumping
+//!
+//! Instead of pumping from a JS timer, JS may call `watchEvents(callback)`:
+//! a watcher thread then calls back whenever the loop has work and the
+//! callback pumps with a zero timeout (see `watch.rs`).
 
 mod handler;
 mod shell_event;
+mod watch;
 
 use crate::{
-    app::{handler::AppHandler, shell_event::JsShellEventHandler},
+    app::{
+        handler::AppHandler,
+        shell_event::JsShellEventHandler,
+        watch::{EventWatcher, WakeFn},
+    },
     dom::doc::{NativeDoc, SharedDoc},
-    global,
     helpers::JsWeakRef,
     renderer::CurrentRenderer,
     window::{
-        NativeWindow, WindowState, make_window_document,
+        NativeWindow, WindowState,
+        cursor::CursorState,
+        make_window_document,
         monitor::{MonitorInfo, monitor_to_info},
-        options::WindowOptions,
+        options::{Placement, WindowOptions},
         util::build_window_attributes,
     },
 };
 use std::{
-    cell::RefCell, collections::HashMap, rc::Rc, sync::Arc, sync::mpsc::Receiver, time::Duration,
+    cell::{RefCell, RefMut},
+    collections::HashMap,
+    rc::Rc,
+    sync::Arc,
+    sync::mpsc::Receiver,
+    time::Duration,
 };
 
 use blitz::{
@@ -68,8 +85,11 @@ pub struct PumpResult {
 
 #[napi]
 pub struct NativeApp {
-    pub(super) event_loop: RefCell<EventLoop>,
+    /// Dropped by `dispose`.
+    pub(super) event_loop: RefCell<Option<EventLoop>>,
     pub(crate) state: Rc<RefCell<AppState>>,
+    /// Set while `watchEvents` is active.
+    watcher: RefCell<Option<EventWatcher>>,
 }
 
 /// A live window: the blitz `View` plus the JS-side `Window` handle
@@ -88,14 +108,26 @@ pub(crate) struct WindowEntry {
     /// Shared doc, for dispatching shell events without downcasting
     /// `view.doc` (a `Box<dyn Document>`).
     pub(crate) shared_doc: Rc<SharedDoc>,
+    /// Window this one was opened for (`openWindow(doc, { owner })`). An
+    /// owned window closes with its owner.
+    pub(crate) owner: Option<WindowId>,
+    /// Owned modally: while open, the owner gets no input and cannot close.
+    pub(crate) modal: bool,
 }
 
 impl WindowEntry {
+    /// A close has been accepted; the teardown may still be queued.
+    fn is_closing(&self) -> bool {
+        self.state.borrow().closed
+    }
+
     fn close(&mut self) {
         let mut state = self.state.borrow_mut();
         state.window = None;
         state.closed = true;
         drop(state);
+        self.shared_doc.wake.borrow_mut().take();
+        self.shared_doc.in_window.set(false);
         self.view
             .borrow_mut()
             .doc
@@ -121,14 +153,21 @@ pub(crate) enum PendingRequest {
         /// Shared doc, so the promoted `WindowEntry` can dispatch shell
         /// events to the JS `Window` object.
         shared_doc: Rc<SharedDoc>,
+        /// `WindowOptions` placement to finish once the window exists
+        /// (see `place_window`).
+        placement: Option<Placement>,
+        /// See `WindowEntry::owner` / `WindowEntry::modal`.
+        owner: Option<WindowId>,
+        modal: bool,
         deferred: JsDeferred<NativeWindow, Box<dyn FnOnce(Env) -> Result<NativeWindow>>>,
     },
     /// Tear down a requested closure (deferred past in-flight winit dispatch
     /// so `window.close()` is safe from inside a click handler). Resolving
-    /// `deferred` fulfils the `Promise` `close_window` returned to JS.
+    /// `deferred` fulfils the `Promise` `close_window` returned to JS; it is
+    /// `None` for owned windows closing along with their owner.
     Close {
         window_id: WindowId,
-        deferred: JsDeferred<Undefined, Box<dyn FnOnce(Env) -> Result<Undefined>>>,
+        deferred: Option<JsDeferred<Undefined, Box<dyn FnOnce(Env) -> Result<Undefined>>>>,
     },
 }
 
@@ -159,6 +198,55 @@ pub(crate) struct AppState {
     pub(crate) has_opened_window: bool,
 }
 
+impl AppState {
+    /// The open modal window owned by `window_id`, if any. It blocks the
+    /// owner's input and close requests.
+    pub(crate) fn modal_child(&self, window_id: WindowId) -> Option<&WindowEntry> {
+        self.windows
+            .values()
+            .find(|entry| entry.modal && entry.owner == Some(window_id) && !entry.is_closing())
+    }
+
+    /// Queue the teardown of every window owned by `window_id`, and of
+    /// theirs in turn. Owned windows close along with their owner: they
+    /// get no cancelable `close`, only `closed` at the teardown.
+    pub(crate) fn close_owned(&mut self, window_id: WindowId) {
+        let owned: Vec<WindowId> = self
+            .windows
+            .iter()
+            .filter(|(_, entry)| entry.owner == Some(window_id) && !entry.is_closing())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in owned {
+            self.force_close(id);
+        }
+    }
+
+    /// Queue the teardown of `window_id` and its owned windows without a
+    /// cancelable `close`; they only get `closed` at the teardown. No-op
+    /// for a window that is closing already.
+    pub(crate) fn force_close(&mut self, window_id: WindowId) {
+        let Some(entry) = self.windows.get(&window_id) else {
+            return;
+        };
+        if entry.is_closing() {
+            return;
+        }
+        {
+            let mut state = entry.state.borrow_mut();
+            state.closed = true;
+            state.window = None;
+        }
+        self.pending_requests.push(PendingRequest::Close {
+            window_id,
+            deferred: None,
+        });
+        self.outstanding_windows = self.outstanding_windows.saturating_sub(1);
+        self.close_owned(window_id);
+        self.proxy.wake_up();
+    }
+}
+
 #[napi]
 impl NativeApp {
     /// Build the winit event loop.
@@ -167,7 +255,7 @@ impl NativeApp {
         let event_loop = create_default_event_loop();
         let (proxy, receiver) = BlitzShellProxy::new(event_loop.create_proxy());
         Self {
-            event_loop: RefCell::new(event_loop),
+            event_loop: RefCell::new(Some(event_loop)),
             state: Rc::new(RefCell::new(AppState {
                 windows: HashMap::new(),
                 pending_requests: Vec::new(),
@@ -177,6 +265,7 @@ impl NativeApp {
                 outstanding_windows: 0,
                 has_opened_window: false,
             })),
+            watcher: RefCell::new(None),
         }
     }
 
@@ -211,26 +300,55 @@ impl NativeApp {
     /// ensure a pump is (or will be) running before `await`-ing the result;
     /// otherwise the promise never resolves. Typical setup drives at least one
     /// `pump_app_events` before awaiting.
+    ///
+    /// `owner` makes the new window an owned window of a live one: it stays
+    /// above its owner (unless `options` names another parent) and closes
+    /// with it. With `modal`, the owner also gets no input and cannot close
+    /// while the new window is open.
     #[napi]
     pub fn open_window(
         &self,
         env: Env,
         doc: &mut NativeDoc,
         options: Option<&WindowOptions>,
+        owner: Option<&NativeWindow>,
+        modal: Option<bool>,
     ) -> Result<PromiseRaw<'_, NativeWindow>> {
+        let modal = modal.unwrap_or(false);
+        if modal && owner.is_none() {
+            return Err(Error::from_reason("a modal window needs an owner"));
+        }
+        let owner_handle = owner.map(NativeWindow::window_handle).transpose()?;
+        self.event_loop()?;
+        doc.doc.check_live()?;
         if !doc.mark_attached() {
             return Err(Error::from_reason(
                 "DocHandle has already been attached to a window".to_string(),
             ));
         }
         let shared_doc = doc.doc.clone();
+        shared_doc.in_window.set(true);
         let window_doc = make_window_document(doc);
-        let attributes = build_window_attributes(options)?;
-        let config = WindowConfig::with_attributes(window_doc, CurrentRenderer::new(), attributes);
+        let mut attributes = build_window_attributes(options)?;
+        if let Some(handle) = owner_handle
+            && options.is_none_or(|o| o.parent_window.is_none())
+        {
+            attributes = unsafe { attributes.with_parent_window(Some(handle.window)) };
+        }
+        let placement = options.and_then(|o| o.placement.clone());
+        let config = WindowConfig::with_attributes(
+            window_doc,
+            CurrentRenderer::new(Rc::downgrade(&shared_doc)),
+            attributes,
+        );
 
         let win_state = WindowState {
             window: None,
             closed: false,
+            minimized: false,
+            cursor: CursorState::default(),
+            pinch_zoom: false,
+            frame_time: 0.0,
         };
         let (deferred, promise_obj) =
             env.create_deferred::<NativeWindow, Box<dyn FnOnce(Env) -> Result<NativeWindow>>>()?;
@@ -242,10 +360,14 @@ impl NativeApp {
                 config: Box::new(config),
                 state: win_state,
                 shared_doc,
+                placement,
+                owner: owner.map(|o| o.window_id),
+                modal,
                 deferred,
             });
             app_state.has_opened_window = true;
             app_state.outstanding_windows += 1;
+            app_state.proxy.wake_up();
         }
 
         Ok(promise)
@@ -302,6 +424,15 @@ impl NativeApp {
             return PromiseRaw::resolve(&env, ());
         }
 
+        // An open modal child vetoes the close without asking listeners.
+        if self.state.borrow().modal_child(window_id).is_some() {
+            let (deferred, promise_obj) =
+                env.create_deferred::<Undefined, Box<dyn FnOnce(Env) -> Result<Undefined>>>()?;
+            let promise = PromiseRaw::new(env.raw(), JsValue::raw(&promise_obj));
+            deferred.reject(Error::from_reason("close prevented by a modal window"));
+            return Ok(promise);
+        }
+
         // Dispatch the cancelable `close` event to the window from Rust.
         // Clone the dispatch pieces first so no AppState borrow is held
         // across the re-entrant JS call.
@@ -335,9 +466,12 @@ impl NativeApp {
             let mut app_state = self.state.borrow_mut();
             app_state.pending_requests.push(PendingRequest::Close {
                 window_id,
-                deferred,
+                deferred: Some(deferred),
             });
             app_state.outstanding_windows = app_state.outstanding_windows.saturating_sub(1);
+            app_state.close_owned(window_id);
+            // Let an integrated loop run the teardown pump right away.
+            app_state.proxy.wake_up();
         }
 
         Ok(promise)
@@ -383,8 +517,94 @@ impl NativeApp {
 
     /// Pump pending winit events for at most `millis` milliseconds.
     #[napi]
-    pub fn pump_app_events(&self, millis: f64) -> PumpResult {
-        self.pump_app_events_inner(millis)
+    pub fn pump_app_events(&self, millis: f64) -> Result<PumpResult> {
+        let result = self.pump_app_events_inner(millis);
+        // A failed pump still lets the watcher wait again.
+        if let Some(watcher) = &*self.watcher.borrow() {
+            watcher.pumped();
+        }
+        result
+    }
+
+    /// Integrated mode: call `callback` on the JS thread whenever the event
+    /// loop has work (OS events, redraws, DOM mutations of a window's
+    /// document), at least every `maxWait` ms (default 500). The callback
+    /// should run `pumpAppEvents(0)`; no further callback comes until it
+    /// does, or until `maxWait` passed (e.g. the callback threw before it
+    /// pumped). Keeps the process alive until `unwatchEvents`.
+    ///
+    /// Only supported on Linux / BSD, where the watcher waits on the event
+    /// loop's descriptor. Elsewhere nothing can see the loop's events while
+    /// Node idles, and this throws.
+    #[napi(ts_args_type = "callback: () => void, maxWait?: number | undefined | null")]
+    pub fn watch_events(&self, callback: WakeFn, max_wait: Option<f64>) -> Result<()> {
+        if self.watcher.borrow().is_some() {
+            return Err(Error::from_reason("already watching events"));
+        }
+        let max_wait = match max_wait {
+            Some(ms) if !(ms.is_finite() && ms > 0.0) => {
+                return Err(Error::from_reason(format!(
+                    "maxWait must be a positive number of milliseconds, got {ms}"
+                )));
+            }
+            Some(ms) => Duration::from_secs_f64(ms / 1000.0),
+            None => Duration::from_millis(500),
+        };
+        let watcher = EventWatcher::start(&*self.event_loop()?, max_wait, callback)?;
+        *self.watcher.borrow_mut() = Some(watcher);
+        Ok(())
+    }
+
+    /// Close every window and drop the event loop now. Windows are torn
+    /// down without a cancelable `close` (each still gets `closed`),
+    /// pending `openWindow` promises reject, and any further use of the app
+    /// throws. Calling it again is a no-op.
+    #[napi]
+    pub fn dispose(&self) {
+        if self.event_loop.borrow().is_none() {
+            return;
+        }
+        self.watcher.borrow_mut().take();
+        let opens = {
+            let mut state = self.state.borrow_mut();
+            let ids: Vec<WindowId> = state.windows.keys().copied().collect();
+            for id in ids {
+                state.force_close(id);
+            }
+            let all = std::mem::take(&mut state.pending_requests);
+            let (opens, closes): (Vec<_>, Vec<_>) = all
+                .into_iter()
+                .partition(|req| matches!(req, PendingRequest::Open { .. }));
+            state.pending_requests = closes;
+            state.outstanding_windows = 0;
+            opens
+        };
+        for req in opens {
+            if let PendingRequest::Open {
+                shared_doc,
+                deferred,
+                ..
+            } = req
+            {
+                shared_doc.in_window.set(false);
+                deferred.reject(Error::from_reason("app is disposed"));
+            }
+        }
+        self.flush_closing_windows();
+        self.event_loop.borrow_mut().take();
+    }
+
+    /// Whether `dispose` has run.
+    #[napi(getter)]
+    pub fn disposed(&self) -> bool {
+        self.event_loop.borrow().is_none()
+    }
+
+    /// Stop the watcher started by `watchEvents`. A callback already queued
+    /// may still run once. No-op when not watching.
+    #[napi]
+    pub fn unwatch_events(&self) {
+        self.watcher.borrow_mut().take();
     }
 
     /// Set the document zoom level. `1.0` is unzoomed. Combined with the
@@ -454,6 +674,12 @@ impl NativeApp {
                 match state.windows.remove(&window_id) {
                     Some(mut entry) => {
                         entry.close();
+                        // Hand focus back to the owner of a closed modal.
+                        if entry.modal
+                            && let Some(owner) = entry.owner.and_then(|id| state.windows.get(&id))
+                        {
+                            owner.view.borrow().window.focus_window();
+                        }
                         let doc = Some(Rc::clone(&entry.shared_doc));
                         drop(entry);
                         (doc, Rc::clone(&state.js_app_ref))
@@ -466,13 +692,7 @@ impl NativeApp {
             //    up to the app. No outstanding AppState borrow, so JS
             //    re-entry into `open_window` / `close_window` is safe.
             if let Some(shared_doc) = shared_doc {
-                let env = match global::env() {
-                    Ok(e) => e,
-                    Err(e) => {
-                        eprintln!("napi-blitz: flush_closing_windows: env not available: {e}");
-                        return;
-                    }
-                };
+                let env = shared_doc.env;
                 let handler = JsShellEventHandler::new(app_ref);
                 // The cancelable close request (`window:close`) was already
                 // dispatched by `close_window`; here only the post-teardown
@@ -484,14 +704,16 @@ impl NativeApp {
 
             // 3. Fulfil the `close_window` promise after the notifications,
             //    so JS-side await sees the teardown fully complete.
-            deferred.resolve(Box::new(move |_env| Ok(())));
+            if let Some(deferred) = deferred {
+                deferred.resolve(Box::new(move |_env| Ok(())));
+            }
         }
     }
 
     /// Pump pending winit events for at most `millis` milliseconds. JS should
     /// call this in a loop (typically once per animation frame) to drive the
     /// renderer and event handling.
-    fn pump_app_events_inner(&self, millis: f64) -> PumpResult {
+    fn pump_app_events_inner(&self, millis: f64) -> Result<PumpResult> {
         // Give host-driven DOM mutations from the previous JS turn a chance to
         // flow through Blitz's normal `View::poll -> Document::poll ->
         // request_redraw` path before winit waits for more events.
@@ -518,11 +740,11 @@ impl NativeApp {
             (state.has_opened_window, state.outstanding_windows)
         };
         if has_opened && outstanding == 0 {
-            return PumpResult {
+            return Ok(PumpResult {
                 r#continue: false,
                 exit: true,
                 code: Some(0),
-            };
+            });
         }
 
         let timeout = Some(Duration::from_millis(millis.max(0.0).round() as u64));
@@ -530,14 +752,11 @@ impl NativeApp {
         let mut handler = AppHandler {
             state: Rc::clone(&self.state),
         };
-        let status = self
-            .event_loop
-            .borrow_mut()
-            .pump_app_events(timeout, &mut handler);
+        let status = self.event_loop()?.pump_app_events(timeout, &mut handler);
         self.flush_closing_windows();
         self.poll_live_views();
 
-        match status {
+        Ok(match status {
             PumpStatus::Continue => PumpResult {
                 r#continue: true,
                 exit: false,
@@ -548,6 +767,11 @@ impl NativeApp {
                 exit: true,
                 code: Some(code),
             },
-        }
+        })
+    }
+
+    fn event_loop(&self) -> Result<RefMut<'_, EventLoop>> {
+        RefMut::filter_map(self.event_loop.borrow_mut(), Option::as_mut)
+            .map_err(|_| Error::from_reason("app is disposed"))
     }
 }
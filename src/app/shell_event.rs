@@ -3,7 +3,11 @@
 //! Mirrors `JsEventHandler` (DOM events) but for shell-level events that
 //! originate from winit's `ApplicationHandler` and the async `openWindow`
 //! / `closeWindow` paths: `open`, `close`, `closed` (window-level) and
-//! `window:open`, `window:close`, `window:closed` (app-level echoes).
+//! `window:open`, `window:close`, `window:closed` (app-level echoes), plus
+//! the notifications translated from winit `WindowEvent`s (`resize`,
+//! `move`, `focus`, `blur`, `scalefactorchange`, `themechange`,
+//! `occluded`, `visible`, `minimize`, `restore`), raw pointer `motion`
+//! while the cursor is locked, and their `window:*` echoes.
 //!
 //! A close request is currently dispatched to the two receivers
 //! INDEPENDENTLY: the window receives `close` (its own event), and the app
@@ -12,8 +16,8 @@
 //! — a `preventDefault()` at either level vetoes the close.
 //!
 //! Both event systems share the same dispatch primitives:
-//! - `global::get_event_factory()` to build the JS `Event`
-//! - `global::get_dispatch_fn()` to call `dispatchEvent(target, event)`
+//! - `global::get_event_factory(env)` to build the JS `Event`
+//! - `global::get_dispatch_fn(env)` to call `dispatchEvent(target, event)`
 //! - `event.get_named_property::<bool>("defaultPrevented" | "cancelBubble")`
 //!   to read back dispatch flags
 //!
@@ -24,7 +28,7 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    dom::doc::SharedDoc,
+    dom::{doc::SharedDoc, payload::EventPayload},
     helpers::{
         JsWeakRef, build_event_object, dispatch_event, read_event_flag, reset_dispatch_state,
         resolve_window,
@@ -148,6 +152,34 @@ impl JsShellEventHandler {
     pub fn open_sequence(&self, env: &Env) -> Result<bool> {
         Ok(!self.dispatch_app_cancelable("window:open", env)?)
     }
+
+    /// Dispatch a non-cancelable window notification (`resize`, `move`,
+    /// `focus`, ...): the payload's event on the window, then its
+    /// `window:`-prefixed echo on the app. The echo carries the JS window
+    /// as `event.window`, since one app listener serves every window.
+    /// Receivers that are not registered (yet) are skipped.
+    pub fn notify(&self, payload: EventPayload, doc: &Rc<SharedDoc>, env: &Env) -> Result<()> {
+        let window = resolve_window(doc, env);
+        let echo = EventPayload {
+            event_type: format!("window:{}", payload.event_type),
+            window: payload.window.clone(),
+            ..EventPayload::new("", false, false)
+        };
+        if let Some(window) = &window {
+            let mut event_obj = build_event_object(payload, env)?;
+            dispatch_event(window, &event_obj, env)?;
+            reset_dispatch_state(&mut event_obj, env);
+        }
+        if let Some(app) = resolve_app(&self.app_ref, env) {
+            let mut event_obj = build_event_object(echo, env)?;
+            if let Some(window) = &window {
+                event_obj.set_named_property("window", *window)?;
+            }
+            dispatch_event(&app, &event_obj, env)?;
+            reset_dispatch_state(&mut event_obj, env);
+        }
+        Ok(())
+    }
 }
 
 // ── Dispatch primitives ────────────────────────────────────────────────
@@ -167,16 +199,6 @@ fn build_event<'a>(
     bubbles: bool,
     env: &'a Env,
 ) -> Result<Object<'a>> {
-    use crate::dom::payload::EventPayload;
-    let payload = EventPayload {
-        event_type: event_type.to_string(),
-        bubbles,
-        cancelable,
-        pointer: None,
-        wheel: None,
-        key: None,
-        input: None,
-        ime: None,
-    };
+    let payload = EventPayload::new(event_type, bubbles, cancelable);
     build_event_object(payload, env)
 }
@@ -0,0 +1,169 @@
+//! Integrated event-loop mode: wake JS when the winit loop has work,
+//! instead of polling it from a JS timer.
+//!
+//! `NativeApp.watchEvents(callback)` starts a watcher thread that waits
+//! for the event loop to become ready and then calls `callback` on the JS
+//! thread through a `ThreadsafeFunction`. The callback runs one
+//! non-blocking `pumpAppEvents(0)`; the watcher waits for that pump to
+//! finish before it waits again, so at most one wakeup is in flight and
+//! the JS thread is never blocked waiting for events. Every pump counts,
+//! failed ones included, and a callback that never pumps (it threw) is
+//! called again after `max_wait`.
+//!
+//! The watcher polls the event loop's file descriptor, which becomes
+//! readable for OS events, redraw requests and `BlitzShellProxy` wakeups
+//! alike. Host DOM mutations send such a wakeup (see
+//! `SharedDoc::mark_host_dirty`). The callback also fires at least every
+//! `max_wait`, so winit's own timers still run.
+//!
+//! Only Linux / BSD (X11, Wayland) event loops have such a descriptor.
+//! Elsewhere OS events and proxy wakeups arrive through the platform run
+//! loop on the main thread, which nothing runs while Node idles in libuv,
+//! so `watchEvents` is refused there rather than degrading to a timer.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::Duration,
+};
+
+use self::ready::{ReadyFd, ready_fd, wait_ready};
+use blitz::shell::EventLoop;
+use napi::{
+    Error, Result, Status,
+    threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+};
+
+/// JS `() => void` called on the JS thread when the loop has work.
+pub(crate) type WakeFn = ThreadsafeFunction<(), (), (), Status, false>;
+
+pub(crate) struct EventWatcher {
+    stop: Arc<AtomicBool>,
+    pumped: Sender<()>,
+}
+
+impl EventWatcher {
+    pub(crate) fn start(event_loop: &EventLoop, max_wait: Duration, wake: WakeFn) -> Result<Self> {
+        let fd = ready_fd(event_loop)?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let (pumped, pumped_rx) = mpsc::channel();
+        let thread_stop = Arc::clone(&stop);
+        thread::Builder::new()
+            .name("napi-blitz-watch".into())
+            .spawn(move || watch(fd, max_wait, wake, pumped_rx, thread_stop))
+            .map_err(|e| Error::from_reason(format!("failed to start the event watcher: {e}")))?;
+        Ok(Self { stop, pumped })
+    }
+
+    /// A pump ran: the watcher may wait for the loop again.
+    pub(crate) fn pumped(&self) {
+        let _ = self.pumped.send(());
+    }
+}
+
+impl Drop for EventWatcher {
+    /// The thread is not joined: it may be blocked in `poll` for up to
+    /// `max_wait`, and exits by itself once it sees the flag.
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        let _ = self.pumped.send(());
+    }
+}
+
+fn watch(
+    fd: ReadyFd,
+    max_wait: Duration,
+    wake: WakeFn,
+    pumped: Receiver<()>,
+    stop: Arc<AtomicBool>,
+) {
+    loop {
+        wait_ready(&fd, max_wait);
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+        // Forget pumps JS ran on its own while we waited: only the one
+        // this wakeup causes counts.
+        while pumped.try_recv().is_ok() {}
+        if wake.call((), ThreadsafeFunctionCallMode::NonBlocking) != Status::Ok {
+            break;
+        }
+        match pumped.recv_timeout(max_wait) {
+            Ok(()) | Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+    }
+}
+
+/// Waiting on the event loop's descriptor.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod ready {
+    use std::{
+        os::fd::{AsFd, AsRawFd, OwnedFd},
+        time::Duration,
+    };
+
+    use blitz::shell::EventLoop;
+    use napi::{Error, Result};
+
+    pub(super) type ReadyFd = OwnedFd;
+
+    pub(super) fn ready_fd(event_loop: &EventLoop) -> Result<ReadyFd> {
+        event_loop
+            .as_fd()
+            .try_clone_to_owned()
+            .map_err(|e| Error::from_reason(format!("failed to watch the event loop: {e}")))
+    }
+
+    pub(super) fn wait_ready(fd: &ReadyFd, timeout: Duration) {
+        let mut pollfd = libc::pollfd {
+            fd: fd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.as_millis().min(i32::MAX as u128) as i32;
+        // SAFETY: one valid `pollfd` for a descriptor this thread owns.
+        unsafe { libc::poll(&mut pollfd, 1, timeout) };
+    }
+}
+
+/// No descriptor to wait on: watching is unsupported.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod ready {
+    use std::time::Duration;
+
+    use blitz::shell::EventLoop;
+    use napi::{Error, Result, Status};
+
+    pub(super) enum ReadyFd {}
+
+    pub(super) fn ready_fd(_event_loop: &EventLoop) -> Result<ReadyFd> {
+        Err(Error::new(
+            Status::GenericFailure,
+            "watchEvents is not supported on this platform; pump with pumpAppEvents instead",
+        ))
+    }
+
+    pub(super) fn wait_ready(fd: &ReadyFd, _timeout: Duration) {
+        match *fd {}
+    }
+}
@@ -1,3 +1,4 @@
 pub mod renderer;
 
-pub use renderer::{BufferFrame, BufferRenderer, BufferRendererOptions};
+pub use crate::renderer::BufferFrame;
+pub use renderer::{BufferRenderer, BufferRendererOptions};
@@ -6,17 +6,13 @@
 //! then ask this renderer to resolve layout/paint into an RGBA frame that the
 //! host can display however it wants.
 
-use anyrender::{PaintScene as _, render_to_buffer};
-use anyrender_vello_cpu::VelloCpuImageRenderer;
-use blitz::{
-    dom::util::Color,
-    paint::paint_scene,
-    traits::shell::{ColorScheme, Viewport},
-};
-use napi::{Error, Result, bindgen_prelude::Uint8Array};
-use peniko::{Fill, kurbo::Rect};
+use blitz::traits::shell::{ColorScheme, Viewport};
+use napi::{Error, Result};
 
-use crate::doc::DocHandle;
+use crate::{
+    doc::DocHandle,
+    renderer::{BufferFrame, Overlays, render_to_rgba},
+};
 
 #[napi(object)]
 pub struct BufferRendererOptions {
@@ -28,18 +24,6 @@ pub struct BufferRendererOptions {
     pub scale: Option<f64>,
 }
 
-#[napi(object)]
-pub struct BufferFrame {
-    /// Frame width in physical pixels.
-    pub width: u32,
-    /// Frame height in physical pixels.
-    pub height: u32,
-    /// Device scale factor used to render the frame.
-    pub scale: f64,
-    /// RGBA8 pixels, row-major, 4 bytes per pixel.
-    pub data: Uint8Array,
-}
-
 #[napi]
 pub struct BufferRenderer {
     width: u32,
@@ -76,12 +60,12 @@ impl BufferRenderer {
     /// the whole viewport. Later we can layer dirty-region tracking and buffer
     /// reuse on top without changing the separation from the native window path.
     #[napi]
-    pub fn render(&mut self, doc: &mut DocHandle) -> BufferFrame {
+    pub fn render(&mut self, doc: &mut DocHandle) -> Result<BufferFrame> {
         let render_width = scaled_dimension(self.width, self.scale);
         let render_height = scaled_dimension(self.height, self.scale);
         let scale = self.scale as f32;
 
-        let mut base = doc.doc.base.borrow_mut();
+        let mut base = doc.doc.write()?;
         base.set_viewport(Viewport::new(
             render_width,
             render_height,
@@ -90,36 +74,21 @@ impl BufferRenderer {
         ));
         base.resolve(0.0);
 
-        let data = render_to_buffer::<VelloCpuImageRenderer, _>(
-            |scene| {
-                scene.fill(
-                    Fill::NonZero,
-                    Default::default(),
-                    Color::WHITE,
-                    Default::default(),
-                    &Rect::new(0.0, 0.0, render_width as f64, render_height as f64),
-                );
-
-                paint_scene(
-                    scene,
-                    &mut base,
-                    self.scale,
-                    render_width,
-                    render_height,
-                    0,
-                    0,
-                );
-            },
+        let overlays = Overlays::of(&doc.doc, &base);
+        let data = render_to_rgba(
+            &mut base,
+            &overlays,
             render_width,
             render_height,
+            self.scale,
         );
 
-        BufferFrame {
+        Ok(BufferFrame {
             width: render_width,
             height: render_height,
             scale: self.scale,
             data: data.into(),
-        }
+        })
     }
 }
 
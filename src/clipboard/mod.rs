@@ -0,0 +1,205 @@
+//! Clipboard access behind a swappable backend.
+//!
+//! Everything that touches the clipboard (the `clipboard*` napi functions
+//! and the default actions of `copy` / `cut` / `paste` in
+//! `dom/clipboard_event.rs`) goes through the thread's current
+//! `ClipboardBackend`. It defaults to the OS clipboard; tests switch to an
+//! in-memory one with `setClipboardBackend("memory")` so they neither need
+//! a display server nor clobber the user's clipboard.
+
+use std::{cell::RefCell, rc::Rc};
+
+use napi::{Error, Result, bindgen_prelude::Uint8Array};
+
+/// RGBA8 image, row-major, `width * height * 4` bytes.
+#[derive(Clone)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+/// A clipboard implementation. Reads return `None` when the clipboard
+/// holds nothing in the requested format.
+pub trait ClipboardBackend {
+    fn read_text(&self) -> Result<Option<String>>;
+    fn write_text(&self, text: &str) -> Result<()>;
+    fn read_image(&self) -> Result<Option<RgbaImage>>;
+    fn write_image(&self, image: &RgbaImage) -> Result<()>;
+}
+
+// ── OS clipboard ──────────────────────────────────────────────────────
+
+/// The OS clipboard via `arboard`. The connection is opened on first use
+/// and kept: on X11 / Wayland the owner has to stay alive to serve the
+/// data it wrote.
+#[derive(Default)]
+pub struct SystemClipboard {
+    inner: RefCell<Option<arboard::Clipboard>>,
+}
+
+impl SystemClipboard {
+    fn with<T>(&self, f: impl FnOnce(&mut arboard::Clipboard) -> T) -> Result<T> {
+        let mut inner = self.inner.borrow_mut();
+        if inner.is_none() {
+            *inner = Some(arboard::Clipboard::new().map_err(clipboard_error)?);
+        }
+        Ok(f(inner.as_mut().expect("clipboard opened above")))
+    }
+}
+
+fn clipboard_error(err: arboard::Error) -> Error {
+    Error::from_reason(format!("clipboard: {err}"))
+}
+
+/// `ContentNotAvailable` is an empty clipboard, not a failure.
+fn optional<T>(result: std::result::Result<T, arboard::Error>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(arboard::Error::ContentNotAvailable) => Ok(None),
+        Err(err) => Err(clipboard_error(err)),
+    }
+}
+
+impl ClipboardBackend for SystemClipboard {
+    fn read_text(&self) -> Result<Option<String>> {
+        self.with(|c| optional(c.get_text()))?
+    }
+
+    fn write_text(&self, text: &str) -> Result<()> {
+        self.with(|c| c.set_text(text).map_err(clipboard_error))?
+    }
+
+    fn read_image(&self) -> Result<Option<RgbaImage>> {
+        let image = self.with(|c| optional(c.get_image()))??;
+        Ok(image.map(|image| RgbaImage {
+            width: image.width as u32,
+            height: image.height as u32,
+            bytes: image.bytes.into_owned(),
+        }))
+    }
+
+    fn write_image(&self, image: &RgbaImage) -> Result<()> {
+        let data = arboard::ImageData {
+            width: image.width as usize,
+            height: image.height as usize,
+            bytes: image.bytes.as_slice().into(),
+        };
+        self.with(|c| c.set_image(data).map_err(clipboard_error))?
+    }
+}
+
+// ── In-memory clipboard ───────────────────────────────────────────────
+
+/// Process-local clipboard. Like a real one it holds a single entry, so
+/// writing text drops a previously written image and vice versa.
+#[derive(Default)]
+pub struct MemoryClipboard {
+    text: RefCell<Option<String>>,
+    image: RefCell<Option<RgbaImage>>,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn read_text(&self) -> Result<Option<String>> {
+        Ok(self.text.borrow().clone())
+    }
+
+    fn write_text(&self, text: &str) -> Result<()> {
+        *self.text.borrow_mut() = Some(text.to_string());
+        *self.image.borrow_mut() = None;
+        Ok(())
+    }
+
+    fn read_image(&self) -> Result<Option<RgbaImage>> {
+        Ok(self.image.borrow().clone())
+    }
+
+    fn write_image(&self, image: &RgbaImage) -> Result<()> {
+        *self.image.borrow_mut() = Some(image.clone());
+        *self.text.borrow_mut() = None;
+        Ok(())
+    }
+}
+
+// ── Current backend ───────────────────────────────────────────────────
+
+thread_local! {
+    static BACKEND: RefCell<Rc<dyn ClipboardBackend>> =
+        RefCell::new(Rc::new(SystemClipboard::default()));
+}
+
+/// The backend in use on this thread.
+pub fn backend() -> Rc<dyn ClipboardBackend> {
+    BACKEND.with(|b| b.borrow().clone())
+}
+
+pub fn set_backend(backend: Rc<dyn ClipboardBackend>) {
+    BACKEND.with(|b| *b.borrow_mut() = backend);
+}
+
+// ── JS API ────────────────────────────────────────────────────────────
+
+/// Image read from / written to the clipboard: RGBA8 pixels, row-major.
+#[napi(object)]
+pub struct ClipboardImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Uint8Array,
+}
+
+/// Switch the clipboard backend: `"system"` (the OS clipboard, default)
+/// or `"memory"` (a fresh, empty in-process clipboard).
+#[napi]
+pub fn set_clipboard_backend(kind: String) -> Result<()> {
+    let backend: Rc<dyn ClipboardBackend> = match kind.as_str() {
+        "system" => Rc::new(SystemClipboard::default()),
+        "memory" => Rc::new(MemoryClipboard::default()),
+        _ => {
+            return Err(Error::from_reason(format!(
+                "setClipboardBackend: unknown backend {kind:?}"
+            )));
+        }
+    };
+    set_backend(backend);
+    Ok(())
+}
+
+/// Clipboard text, or `null` when the clipboard holds no text.
+#[napi]
+pub fn clipboard_read_text() -> Result<Option<String>> {
+    backend().read_text()
+}
+
+#[napi]
+pub fn clipboard_write_text(text: String) -> Result<()> {
+    backend().write_text(&text)
+}
+
+/// Clipboard image, or `null` when the clipboard holds no image.
+#[napi]
+pub fn clipboard_read_image() -> Result<Option<ClipboardImage>> {
+    Ok(backend().read_image()?.map(|image| ClipboardImage {
+        width: image.width,
+        height: image.height,
+        data: image.bytes.into(),
+    }))
+}
+
+/// Write an image. `data` must hold `width * height * 4` bytes.
+#[napi]
+pub fn clipboard_write_image(image: ClipboardImage) -> Result<()> {
+    let expected = image.width as usize * image.height as usize * 4;
+    if image.data.len() != expected {
+        return Err(Error::from_reason(format!(
+            "clipboardWriteImage: expected {expected} bytes for {}x{} RGBA, got {}",
+            image.width,
+            image.height,
+            image.data.len()
+        )));
+    }
+    backend().write_image(&RgbaImage {
+        width: image.width,
+        height: image.height,
+        bytes: image.data.to_vec(),
+    })
+}
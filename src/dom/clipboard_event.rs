@@ -0,0 +1,199 @@
+//! `copy` / `cut` / `paste` DOM events.
+//!
+//! blitz's text inputs handle the editing shortcuts themselves through its
+//! shell provider, which has no clipboard in our build. `JsEventHandler`
+//! instead routes an uncanceled Ctrl/Cmd+C, X or V keydown to
+//! `run_clipboard_action`, which follows the browser sequence:
+//!
+//! 1. Build a `DataTransferItems` (pre-filled with the clipboard text for
+//!    `paste`) and dispatch the cancelable event at the focused element,
+//!    or at `<body>`.
+//! 2. Canceled `copy` / `cut`: write whatever the listeners put into
+//!    `clipboardData` to the clipboard; nothing is removed.
+//! 3. Otherwise run the default action: copy the text-control or document
+//!    selection, delete it for `cut`, insert the text for `paste`. Edits
+//!    fire an `input` event.
+
+use std::rc::Rc;
+
+use blitz::{
+    dom::{BaseDocument, NodeId, local_name},
+    traits::events::BlitzKeyEvent,
+};
+use keyboard_types::{Key, Modifiers, NamedKey};
+use napi::{Env, Result};
+
+use crate::{
+    clipboard,
+    dom::{
+        doc::SharedDoc,
+        event::dispatch_synthetic,
+        ops::body_or_root,
+        payload::{DataTransferItems, EventPayload, InputData},
+        selection::{compare_boundaries, range_text},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClipboardAction {
+    Copy,
+    Cut,
+    Paste,
+}
+
+impl ClipboardAction {
+    fn event_type(self) -> &'static str {
+        match self {
+            Self::Copy => "copy",
+            Self::Cut => "cut",
+            Self::Paste => "paste",
+        }
+    }
+}
+
+/// Map a keydown to the clipboard action it triggers: Ctrl (Cmd on macOS)
+/// with C / X / V, or the dedicated Copy / Cut / Paste keys.
+pub(crate) fn clipboard_shortcut(key: &BlitzKeyEvent) -> Option<ClipboardAction> {
+    let command = if cfg!(target_os = "macos") {
+        Modifiers::META
+    } else {
+        Modifiers::CONTROL
+    };
+    match &key.key {
+        Key::Named(NamedKey::Copy) => Some(ClipboardAction::Copy),
+        Key::Named(NamedKey::Cut) => Some(ClipboardAction::Cut),
+        Key::Named(NamedKey::Paste) => Some(ClipboardAction::Paste),
+        Key::Character(c)
+            if key.modifiers.contains(command) && !key.modifiers.contains(Modifiers::ALT) =>
+        {
+            match c.to_ascii_lowercase().as_str() {
+                "c" => Some(ClipboardAction::Copy),
+                "x" => Some(ClipboardAction::Cut),
+                "v" => Some(ClipboardAction::Paste),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Dispatch the clipboard event for `action` and run its default action.
+pub(crate) fn run_clipboard_action(
+    doc: &Rc<SharedDoc>,
+    action: ClipboardAction,
+    env: &Env,
+) -> Result<()> {
+    let (target, editor) = {
+        let base = doc.base.borrow();
+        let focused = base.get_focussed_node_id();
+        let editor = focused.filter(|&id| has_text_editor(&base, id));
+        let target = focused.unwrap_or_else(|| body_or_root(&base));
+        (target, editor)
+    };
+
+    let data = DataTransferItems::default();
+    if action == ClipboardAction::Paste
+        && let Some(text) = clipboard::backend().read_text()?
+    {
+        data.set_data("text/plain".to_string(), text);
+    }
+    let payload = EventPayload {
+        clipboard: Some(data.clone()),
+        ..EventPayload::new(action.event_type(), true, true)
+    };
+    let outcome = dispatch_synthetic(doc, target, payload, env)?;
+
+    if outcome.default_prevented {
+        if action != ClipboardAction::Paste
+            && let Some(text) = data.get("text/plain")
+        {
+            clipboard::backend().write_text(&text)?;
+        }
+        return Ok(());
+    }
+
+    match action {
+        ClipboardAction::Copy | ClipboardAction::Cut => {
+            let text = match editor {
+                Some(id) => editor_selected_text(&doc.base.borrow(), id),
+                None => document_selected_text(doc),
+            };
+            let Some(text) = text.filter(|t| !t.is_empty()) else {
+                return Ok(());
+            };
+            clipboard::backend().write_text(&text)?;
+            if action == ClipboardAction::Cut
+                && let Some(id) = editor.filter(|&id| is_editable(&doc.base.borrow(), id))
+            {
+                doc.base
+                    .borrow_mut()
+                    .with_text_input(id, |mut driver| driver.delete_selection());
+                fire_input(doc, id, env)?;
+            }
+        }
+        ClipboardAction::Paste => {
+            let (Some(id), Some(text)) = (
+                editor.filter(|&id| is_editable(&doc.base.borrow(), id)),
+                data.get("text/plain"),
+            ) else {
+                return Ok(());
+            };
+            doc.base
+                .borrow_mut()
+                .with_text_input(id, |mut driver| driver.insert_or_replace_selection(&text));
+            fire_input(doc, id, env)?;
+        }
+    }
+    Ok(())
+}
+
+fn has_text_editor(base: &BaseDocument, node_id: NodeId) -> bool {
+    base.get_node(node_id)
+        .and_then(|n| n.element_data())
+        .is_some_and(|el| el.text_input_data().is_some())
+}
+
+/// Cut and paste leave read-only and disabled controls alone.
+fn is_editable(base: &BaseDocument, node_id: NodeId) -> bool {
+    base.get_node(node_id).is_some_and(|n| {
+        n.attr(local_name!("readonly")).is_none() && n.attr(local_name!("disabled")).is_none()
+    })
+}
+
+/// Selected text of a text control. Password fields never expose it.
+fn editor_selected_text(base: &BaseDocument, node_id: NodeId) -> Option<String> {
+    let node = base.get_node(node_id)?;
+    if node.attr(local_name!("type")) == Some("password") {
+        return None;
+    }
+    let editor = &node.element_data()?.text_input_data()?.editor;
+    editor.selected_text().map(str::to_string)
+}
+
+fn document_selected_text(doc: &SharedDoc) -> Option<String> {
+    let selection = doc.selection.get()?;
+    let base = doc.base.borrow();
+    let (start, end) = match compare_boundaries(&base, selection.anchor, selection.focus) {
+        std::cmp::Ordering::Greater => (selection.focus, selection.anchor),
+        _ => (selection.anchor, selection.focus),
+    };
+    Some(range_text(&base, start, end))
+}
+
+fn fire_input(doc: &Rc<SharedDoc>, node_id: NodeId, env: &Env) -> Result<()> {
+    doc.mark_host_dirty();
+    let value = doc
+        .base
+        .borrow()
+        .get_node(node_id)
+        .and_then(|n| n.element_data())
+        .and_then(|el| el.text_input_data())
+        .map(|input| input.editor.text().to_string())
+        .unwrap_or_default();
+    let payload = EventPayload {
+        input: Some(InputData { value }),
+        ..EventPayload::new("input", true, false)
+    };
+    dispatch_synthetic(doc, node_id, payload, env)?;
+    Ok(())
+}
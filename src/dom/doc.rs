@@ -2,15 +2,21 @@
 //!
 //! Per-document state lives in `Rc<SharedDoc>` and is shared between
 //! `DocHandle` (JS side) and `WindowDocument` (blitz window side).
-//! JS constructor refs, event factory, and napi env are global
-//! (`GlobalRegistry` static), not per-document.
+//! JS constructor refs and the event factory are per N-API environment
+//! (see `global`), not per-document.
 //!
 //! The GC finalizer weak-references `SharedDoc`. When a JS Node is
 //! collected, the finalizer upgrades the weak ref, removes the NodeCache
 //! entry, and if the blitz node is detached, drops it from the doc tree.
+//!
+//! `NativeDoc.dispose` releases a document deterministically instead: it
+//! drops the `BaseDocument`, the font context and every cached wrapper
+//! while node handles may still point at the `SharedDoc`. The JS layer
+//! refuses to touch a disposed document, so the document tree is never
+//! borrowed afterwards.
 
 use std::{
-    cell::{Cell, RefCell},
+    cell::{Cell, Ref, RefCell, RefMut},
     rc::Rc,
     sync::Arc,
     task::Context as TaskContext,
@@ -18,10 +24,27 @@ use std::{
 
 use crate::{
     dom::{
-        event::JsEventHandler, input_data_handle::InputDataHandle, node_cache::NodeCache,
+        drag::{DragCandidate, DragPointer, DragReleaseHandler, DragSession, handle_drag_pointer},
+        event::JsEventHandler,
+        file_drop::FileInputs,
+        focus::sync_focus,
+        gesture::{GestureDelta, GesturePhase, Gestures, handle_gesture},
+        input_data_handle::InputDataHandle,
+        listeners::TypeCounts,
+        node_cache::{NodeCache, SWEEP_BUDGET},
         node_handle::NativeNode,
+        pointer_capture::{PointerCaptures, handle_capture_cancel},
+        pointer_info::PointerInfo,
+        pointer_moves::PointerMoves,
+        scroll::{
+            ScrollTarget, ScrollToOptions, Scrolls, finish_wheel, offset, scroll_with_options,
+            tick_scrolls, wheel_snapshot,
+        },
+        selection::{DocSelection, handle_selection_pointer},
+        text_selection::sync_focused_selection,
+        touch::{Touches, handle_touch_pointer},
     },
-    global::{get_element_constructor, get_node_constructor},
+    global::{get_element_constructor, get_node_constructor, track_document},
     helpers::JsWeakRef,
 };
 use blitz::{
@@ -30,8 +53,12 @@ use blitz::{
         DocumentConfig, EventDriver, FontContext, NodeData, NodeId,
     },
     html::{DocumentHtmlParser, HtmlProvider},
-    traits::events::UiEvent,
+    traits::events::{
+        BlitzPointerEvent, BlitzPointerId, MouseEventButton, MouseEventButtons, PointerCoords,
+        PointerDetails, UiEvent,
+    },
 };
+use keyboard_types::Modifiers;
 use napi::{
     Env, Error, Result, Status,
     bindgen_prelude::{FnArgs, FromNapiValue, Object, ObjectRef, ToNapiValue, Uint8Array},
@@ -89,13 +116,39 @@ pub struct RegisterFontOptions {
 
 // ── SharedDoc: per-document shared state ─────────────────────────────
 
+/// The document tree of a `SharedDoc`, until `dispose` drops it.
+pub struct DocCell(RefCell<Option<BaseDocument>>);
+
+impl DocCell {
+    /// Borrow the tree. Panics once the document is disposed: napi entry
+    /// points go through `SharedDoc::read` / `write` or `check_live` first.
+    pub fn borrow(&self) -> Ref<'_, BaseDocument> {
+        Ref::map(self.0.borrow(), |base| {
+            base.as_ref().expect("document is disposed")
+        })
+    }
+
+    /// Mutably borrow the tree. Panics once the document is disposed.
+    pub fn borrow_mut(&self) -> RefMut<'_, BaseDocument> {
+        RefMut::map(self.0.borrow_mut(), |base| {
+            base.as_mut().expect("document is disposed")
+        })
+    }
+}
+
 /// Per-document shared state. Held inside `Rc` so that `DocHandle`
 /// and `WindowDocument` can share it. The GC finalizer uses `Weak`.
 pub struct SharedDoc {
     /// The document tree.
-    pub base: RefCell<BaseDocument>,
+    pub base: DocCell,
+    /// N-API environment the document was created in, for callbacks that
+    /// receive no `Env` (blitz's event and UI-event hooks).
+    pub env: Env,
     /// Host-dirty flag: JS mutated the DOM, window needs redraw.
     host_dirty: Cell<bool>,
+    /// Wakes the event loop of the document's window when it becomes
+    /// host-dirty, so a loop waiting for events picks the mutation up.
+    pub wake: RefCell<Option<Box<dyn Fn()>>>,
     /// Switchable-reference cache: blitz_node_id -> SwitchableRef.
     /// In-document nodes are strong (prevent GC); detached nodes are weak.
     pub node_cache: RefCell<NodeCache>,
@@ -103,21 +156,137 @@ pub struct SharedDoc {
     pub js_document_ref: RefCell<Option<JsWeakRef>>,
     /// Weak ref to the JS Window object, for forwarding pointer events.
     pub js_window_ref: RefCell<Option<JsWeakRef>>,
+    /// Pending / active pointer capture targets, keyed by pointer id.
+    pub pointer_captures: RefCell<PointerCaptures>,
+    /// Focused node as last reported to JS through focus events. Diffed
+    /// against blitz's focus by `sync_focus`.
+    pub reported_focus: Cell<Option<NodeId>>,
+    /// Text-control selection as last reported to JS through
+    /// `selectionchange`: `(node, anchor byte, focus byte)`.
+    pub reported_selection: Cell<Option<(NodeId, usize, usize)>>,
+    /// Document (non-editable text) selection.
+    pub selection: Cell<Option<DocSelection>>,
+    /// A primary-button drag is extending `selection`.
+    pub selecting: Cell<bool>,
+    /// Drag-and-drop in progress over the document (element or OS files).
+    pub drag: RefCell<Option<DragSession>>,
+    /// Press on a draggable element that may still become a drag.
+    pub drag_candidate: Cell<Option<DragCandidate>>,
+    /// Files selected in `<input type=file>` elements.
+    pub file_inputs: RefCell<FileInputs>,
+    /// Attached to a window that has not been torn down yet.
+    pub in_window: Cell<bool>,
+    /// Event types the JS `Window` showing this document listens for.
+    pub window_listeners: RefCell<TypeCounts>,
+    /// `pointermove`s queued until the next frame.
+    pub pointer_moves: RefCell<PointerMoves>,
+    /// Movement, click count and hover transition of the UI event being
+    /// handled.
+    pub pointer_info: RefCell<PointerInfo>,
+    /// Fingers on the touchscreen, for touch events.
+    pub touches: RefCell<Touches>,
+    /// Trackpad gestures in progress.
+    pub gestures: RefCell<Gestures>,
+    /// Pending scroll events and smooth scrolls.
+    pub scrolls: RefCell<Scrolls>,
 }
 
 impl SharedDoc {
-    pub fn new(base: BaseDocument) -> Self {
+    pub fn new(base: BaseDocument, env: Env) -> Self {
         Self {
-            base: RefCell::new(base),
+            base: DocCell(RefCell::new(Some(base))),
+            env,
             host_dirty: Cell::new(false),
+            wake: RefCell::new(None),
             node_cache: RefCell::new(NodeCache::new()),
             js_document_ref: RefCell::new(None),
             js_window_ref: RefCell::new(None),
+            pointer_captures: RefCell::new(PointerCaptures::new()),
+            reported_focus: Cell::new(None),
+            reported_selection: Cell::new(None),
+            selection: Cell::new(None),
+            selecting: Cell::new(false),
+            drag: RefCell::new(None),
+            drag_candidate: Cell::new(None),
+            file_inputs: RefCell::new(FileInputs::new()),
+            in_window: Cell::new(false),
+            window_listeners: RefCell::new(TypeCounts::default()),
+            pointer_moves: RefCell::new(PointerMoves::new()),
+            pointer_info: RefCell::new(PointerInfo::default()),
+            touches: RefCell::new(Touches::default()),
+            gestures: RefCell::new(Gestures::default()),
+            scrolls: RefCell::new(Scrolls::default()),
         }
     }
 
+    pub fn is_disposed(&self) -> bool {
+        self.base.0.borrow().is_none()
+    }
+
+    /// Error for use of a disposed document.
+    pub fn check_live(&self) -> Result<()> {
+        if self.is_disposed() {
+            return Err(Error::from_reason("document is disposed"));
+        }
+        Ok(())
+    }
+
+    /// Borrow the tree, or the `check_live` error once it is disposed.
+    pub fn read(&self) -> Result<Ref<'_, BaseDocument>> {
+        self.check_live()?;
+        Ok(self.base.borrow())
+    }
+
+    /// Mutably borrow the tree, or the `check_live` error once it is
+    /// disposed.
+    pub fn write(&self) -> Result<RefMut<'_, BaseDocument>> {
+        self.check_live()?;
+        Ok(self.base.borrow_mut())
+    }
+
+    /// Drop the document tree, every cached JS wrapper and the per-document
+    /// interaction state. The `SharedDoc` itself lives on while node
+    /// handles reference it.
+    fn dispose(&self) {
+        self.node_cache.borrow_mut().clear();
+        self.js_document_ref.borrow_mut().take();
+        self.js_window_ref.borrow_mut().take();
+        *self.window_listeners.borrow_mut() = TypeCounts::default();
+        self.pointer_moves.borrow_mut().clear();
+        self.pointer_info.borrow_mut().clear();
+        self.touches.borrow_mut().clear();
+        self.gestures.borrow_mut().clear();
+        self.scrolls.borrow_mut().clear();
+        *self.pointer_captures.borrow_mut() = PointerCaptures::new();
+        *self.file_inputs.borrow_mut() = FileInputs::new();
+        self.drag.borrow_mut().take();
+        self.drag_candidate.set(None);
+        self.selection.set(None);
+        self.selecting.set(false);
+        self.reported_focus.set(None);
+        self.reported_selection.set(None);
+        self.base.0.borrow_mut().take();
+    }
+
+    /// Whether `node_id` has a JS listener for `event_type`.
+    pub fn listens(&self, node_id: NodeId, event_type: &str) -> bool {
+        self.node_cache
+            .borrow()
+            .listeners
+            .listens(node_id, event_type)
+    }
+
+    /// Whether the JS `Window` has a listener for `event_type`.
+    pub fn window_listens(&self, event_type: &str) -> bool {
+        self.window_listeners.borrow().has(event_type)
+    }
+
     pub fn mark_host_dirty(&self) {
-        self.host_dirty.set(true);
+        if !self.host_dirty.replace(true)
+            && let Some(wake) = &*self.wake.borrow()
+        {
+            wake();
+        }
     }
 
     pub fn take_host_dirty(&self) -> bool {
@@ -184,14 +353,54 @@ impl SharedDoc {
     /// If the node is already detached, no-op.
     ///
     /// **Must be called before `remove_node`**, while the node still has its
-    /// parent chain so `is_in_document` can be evaluated.
+    /// parent chain so `is_in_document` can be evaluated. Being the one
+    /// hook every removal path goes through, it also drops pointer captures
+    /// held by the subtree, and clears focus and the document selection if
+    /// they sit inside it (without `blur` / `selectionchange`, matching
+    /// browsers' focus fixup).
     pub fn make_in_document_subtree_weak(&self, node_id: NodeId, env: &Env) -> Result<()> {
         if self.is_in_document(node_id) {
+            self.pointer_captures
+                .borrow_mut()
+                .release_subtree(&self.base.borrow(), node_id);
+            self.clear_focus_within(node_id);
+            self.clear_selection_within(node_id);
             self.make_subtree_weak(node_id, env)?;
         }
         Ok(())
     }
 
+    /// Whether `node_id` is `root` or one of its descendants.
+    fn is_within(&self, node_id: NodeId, root: NodeId) -> bool {
+        let base = self.base.borrow();
+        let mut cursor = Some(node_id);
+        while let Some(id) = cursor {
+            if id == root {
+                return true;
+            }
+            cursor = base.get_node(id).and_then(|n| n.parent);
+        }
+        false
+    }
+
+    fn clear_focus_within(&self, root: NodeId) {
+        let focused = self.base.borrow().get_focussed_node_id();
+        if focused.is_some_and(|id| self.is_within(id, root)) {
+            self.base.borrow_mut().clear_focus();
+            self.reported_focus.set(None);
+        }
+    }
+
+    fn clear_selection_within(&self, root: NodeId) {
+        if let Some(selection) = self.selection.get()
+            && (self.is_within(selection.anchor.node, root)
+                || self.is_within(selection.focus.node, root))
+        {
+            self.selection.set(None);
+            self.selecting.set(false);
+        }
+    }
+
     /// Collect, weaken, and detach all children of `node_id`.
     ///
     /// After this call the node has no children and the caller can proceed
@@ -287,7 +496,7 @@ pub fn wrap_node<'a>(doc: &Rc<SharedDoc>, node_id: NodeId, env: &'a Env) -> Resu
     //    node_type constructor.
     let element_ctor = qual_name
         .as_ref()
-        .and_then(|qn| get_element_constructor(&qn.ns, &qn.local));
+        .and_then(|qn| get_element_constructor(env, &qn.ns, &qn.local));
 
     // 6. Build the optional extra argument for element constructors.
     let extra: Option<ObjectRef> = if element_ctor.is_some()
@@ -310,7 +519,7 @@ pub fn wrap_node<'a>(doc: &Rc<SharedDoc>, node_id: NodeId, env: &'a Env) -> Resu
         let result = ctor_fn.new_instance(FnArgs::from((handle, document_ref, extra)))?;
         Object::from_unknown(result)?
     } else {
-        let ctor = get_node_constructor(node_type).ok_or_else(|| {
+        let ctor = get_node_constructor(env, node_type).ok_or_else(|| {
             Error::new(
                 Status::GenericFailure,
                 format!(
@@ -345,6 +554,85 @@ impl WindowDocument {
     pub fn new(doc: Rc<SharedDoc>) -> Self {
         Self { doc }
     }
+
+    /// Dispatch the queued `pointermove`s (see `pointer_moves`), the latest
+    /// sample of each pointer with its batch as coalesced events. Returns
+    /// whether anything was dispatched.
+    pub fn flush_pointer_moves(&mut self) -> bool {
+        let batches = self.doc.pointer_moves.borrow_mut().take();
+        if batches.is_empty() {
+            return false;
+        }
+        for (latest, samples) in batches {
+            self.doc.pointer_moves.borrow_mut().current = Some(Arc::new(samples));
+            self.dispatch_ui_event(UiEvent::PointerMove(latest));
+            self.doc.pointer_moves.borrow_mut().current = None;
+        }
+        // Hover styles may have changed.
+        self.doc.mark_host_dirty();
+        true
+    }
+
+    /// Run one UI event through drag-and-drop, blitz's event driver and the
+    /// focus / selection sync, without queueing.
+    pub(crate) fn dispatch_ui_event(&mut self, event: UiEvent) {
+        #[cfg(debug_assertions)]
+        if should_log_ui_event(&event) {
+            eprintln!("napi-blitz[ui]: enter kind={}", debug_ui_event_kind(&event));
+        }
+        // Each input event sweeps one bounded batch of collected cache
+        // entries, however many DOM events it turns into.
+        self.doc
+            .node_cache
+            .borrow_mut()
+            .sweep(&self.doc.env, SWEEP_BUDGET);
+        self.doc
+            .pointer_info
+            .borrow_mut()
+            .track(&self.doc.base.borrow(), &event);
+        if let UiEvent::PointerUp(pointer) | UiEvent::PointerCancel(pointer) = &event {
+            self.doc.pointer_moves.borrow_mut().release(&pointer.id);
+        }
+        let env = self.doc.env;
+        if let Err(e) = handle_capture_cancel(&self.doc, &event, &env) {
+            eprintln!("napi-blitz: pointer capture event dispatch failed: {e}");
+        }
+
+        // An element drag in progress consumes the pointer stream.
+        match handle_drag_pointer(&self.doc, &event, &env) {
+            Ok(DragPointer::Pass) => {}
+            Ok(DragPointer::Consumed) => return,
+            Ok(DragPointer::Release) => {
+                EventDriver::new(&mut *self, DragReleaseHandler).handle_ui_event(event);
+                return;
+            }
+            Err(e) => eprintln!("napi-blitz: drag-and-drop event dispatch failed: {e}"),
+        }
+
+        // Wheel events scroll as their default action.
+        let scroll_before = wheel_snapshot(&self.doc.base.borrow(), &event);
+        let handler = JsEventHandler {
+            doc: Rc::downgrade(&self.doc),
+        };
+        EventDriver::new(&mut *self, handler).handle_ui_event(event.clone());
+        if let Some(before) = scroll_before {
+            finish_wheel(&self.doc, before);
+        }
+
+        // Clicks and keys may have moved focus, the text caret or the
+        // document selection while the event was handled.
+        if let Err(e) = sync_focus(&self.doc, &env)
+            .and_then(|()| sync_focused_selection(&self.doc, &env))
+            .and_then(|()| handle_selection_pointer(&self.doc, &event, &env))
+        {
+            eprintln!("napi-blitz: focus/selection event dispatch failed: {e}");
+        }
+
+        // Finger pointer events are followed by their touch event.
+        if let Err(e) = handle_touch_pointer(&self.doc, &event, &env) {
+            eprintln!("napi-blitz: touch event dispatch failed: {e}");
+        }
+    }
 }
 
 impl BlitzDocument for WindowDocument {
@@ -359,19 +647,26 @@ impl BlitzDocument for WindowDocument {
     }
 
     fn handle_ui_event(&mut self, event: UiEvent) {
-        #[cfg(debug_assertions)]
-        if should_log_ui_event(&event) {
-            eprintln!("napi-blitz[ui]: enter kind={}", debug_ui_event_kind(&event));
+        // Moves wait for the next frame, which the first one schedules;
+        // anything else first flushes them so the order of pointer events
+        // is kept.
+        if let UiEvent::PointerMove(pointer) = &event {
+            let mut moves = self.doc.pointer_moves.borrow_mut();
+            if moves.enabled {
+                moves.queue(pointer.clone());
+                drop(moves);
+                self.doc.mark_host_dirty();
+                return;
+            }
         }
-        let handler = JsEventHandler {
-            doc: Rc::downgrade(&self.doc),
-        };
-        let mut driver = EventDriver::new(self, handler);
-        driver.handle_ui_event(event);
+        self.flush_pointer_moves();
+        self.dispatch_ui_event(event);
     }
 
     fn poll(&mut self, _task_context: Option<TaskContext>) -> bool {
-        self.doc.take_host_dirty()
+        // Smooth scrolls and wheel scrolls waiting for `scrollend` need
+        // the next frames.
+        self.doc.take_host_dirty() || self.doc.scrolls.borrow().is_animating()
     }
 
     fn id(&self) -> usize {
@@ -389,7 +684,8 @@ fn should_log_ui_event(event: &UiEvent) -> bool {
 #[napi]
 pub struct NativeDoc {
     pub(crate) doc: Rc<SharedDoc>,
-    pub(crate) font_ctx: FontContext,
+    /// Dropped by `dispose`.
+    pub(crate) font_ctx: Option<FontContext>,
     #[cfg(feature = "native-window")]
     pub(crate) moved_into_window: bool,
 }
@@ -401,10 +697,66 @@ impl NativeDoc {
     }
 }
 
+impl NativeDoc {
+    /// Run a main-button mouse event at client coordinates through
+    /// `WindowDocument::dispatch_ui_event`, for the headless hooks.
+    fn dispatch_mouse(
+        &self,
+        client_x: f64,
+        client_y: f64,
+        buttons: MouseEventButtons,
+        kind: fn(BlitzPointerEvent) -> UiEvent,
+    ) -> Result<()> {
+        self.dispatch_input(kind(mouse_event(client_x, client_y, buttons)))
+    }
+
+    /// Dispatch a UI event from a headless hook, after the queued moves.
+    fn dispatch_input(&self, event: UiEvent) -> Result<()> {
+        self.doc.check_live()?;
+        let mut window_doc = WindowDocument::new(self.doc.clone());
+        window_doc.flush_pointer_moves();
+        window_doc.dispatch_ui_event(event);
+        Ok(())
+    }
+}
+
+/// The primary mouse at client coordinates, for the headless input hooks.
+fn mouse_event(client_x: f64, client_y: f64, buttons: MouseEventButtons) -> BlitzPointerEvent {
+    pointer_event(BlitzPointerId::Mouse, true, client_x, client_y, buttons)
+}
+
+/// A main-button pointer at client coordinates; page and screen
+/// coordinates equal the client ones.
+fn pointer_event(
+    id: BlitzPointerId,
+    is_primary: bool,
+    client_x: f64,
+    client_y: f64,
+    buttons: MouseEventButtons,
+) -> BlitzPointerEvent {
+    let (x, y) = (client_x as f32, client_y as f32);
+    BlitzPointerEvent {
+        id,
+        is_primary,
+        coords: PointerCoords {
+            page_x: x,
+            page_y: y,
+            screen_x: x,
+            screen_y: y,
+            client_x: x,
+            client_y: y,
+        },
+        button: MouseEventButton::Main,
+        buttons,
+        mods: Modifiers::empty(),
+        details: PointerDetails::default(),
+    }
+}
+
 #[napi]
 impl NativeDoc {
     #[napi(factory)]
-    pub fn create(_env: Env, config: DocHandleConfig) -> Result<Self> {
+    pub fn create(env: Env, config: DocHandleConfig) -> Result<Self> {
         let mut font_ctx = FontContext::new();
         font_ctx
             .collection
@@ -432,16 +784,43 @@ impl NativeDoc {
         }
         base.resolve(0.0);
 
-        let doc = Rc::new(SharedDoc::new(base));
+        let doc = Rc::new(SharedDoc::new(base, env));
+        track_document(&env, &doc)?;
 
         Ok(Self {
             doc,
-            font_ctx: shared_font_ctx,
+            font_ctx: Some(shared_font_ctx),
             #[cfg(feature = "native-window")]
             moved_into_window: false,
         })
     }
 
+    /// Release the document now instead of when the GC collects it: the
+    /// document tree, its font context and every cached node wrapper are
+    /// dropped, and any further use of the document or its nodes throws.
+    /// Fails while the document is shown in a window; close it first.
+    /// Calling it again is a no-op.
+    #[napi]
+    pub fn dispose(&mut self) -> Result<()> {
+        if self.doc.is_disposed() {
+            return Ok(());
+        }
+        if self.doc.in_window.get() {
+            return Err(Error::from_reason(
+                "cannot dispose a document attached to an open window; close the window first",
+            ));
+        }
+        self.doc.dispose();
+        self.font_ctx = None;
+        Ok(())
+    }
+
+    /// Whether `dispose` has run.
+    #[napi(getter)]
+    pub fn disposed(&self) -> bool {
+        self.doc.is_disposed()
+    }
+
     #[cfg(feature = "native-window")]
     pub(crate) fn mark_attached(&mut self) -> bool {
         if self.moved_into_window {
@@ -452,9 +831,182 @@ impl NativeDoc {
         }
     }
 
+    /// Resolve styles and layout for frame time `time_ms`. For a document
+    /// not shown in a window this is also its frame clock: moves queued by
+    /// `queuePointerMove` are dispatched first, then smooth scrolls advance
+    /// to `time_ms` and pending `scroll` / `scrollend` events fire.
+    #[napi]
+    pub fn resolve(&mut self, env: Env, time_ms: f64) -> Result<()> {
+        self.doc.check_live()?;
+        let in_window = self.doc.in_window.get();
+        if !in_window {
+            WindowDocument::new(self.doc.clone()).flush_pointer_moves();
+        }
+        self.doc.write()?.resolve(time_ms);
+        if in_window {
+            return Ok(());
+        }
+        tick_scrolls(&self.doc, time_ms, &env)
+    }
+
+    /// Horizontal scroll offset of the viewport. Mirrors `window.scrollX`.
+    #[napi(getter)]
+    pub fn scroll_x(&self) -> Result<f64> {
+        Ok(offset(&self.doc.read()?, ScrollTarget::Viewport).0)
+    }
+
+    /// Vertical scroll offset of the viewport. Mirrors `window.scrollY`.
+    #[napi(getter)]
+    pub fn scroll_y(&self) -> Result<f64> {
+        Ok(offset(&self.doc.read()?, ScrollTarget::Viewport).1)
+    }
+
+    /// Scroll the viewport, clamped to the document's scroll range.
+    /// Mirrors `window.scrollTo(options)`.
+    #[napi]
+    pub fn scroll_to(&self, options: ScrollToOptions) -> Result<()> {
+        self.doc.check_live()?;
+        scroll_with_options(&self.doc, ScrollTarget::Viewport, options, false)
+    }
+
+    /// Scroll the viewport by `left` / `top`. Mirrors
+    /// `window.scrollBy(options)`.
+    #[napi]
+    pub fn scroll_by(&self, options: ScrollToOptions) -> Result<()> {
+        self.doc.check_live()?;
+        scroll_with_options(&self.doc, ScrollTarget::Viewport, options, true)
+    }
+
+    /// Feed a mouse `pointermove` at client coordinates through the same
+    /// pipeline as window input: hit testing, hover state, drag-and-drop
+    /// and the JS dispatch. `buttons` is the `MouseEvent.buttons` mask held
+    /// during the move (none by default). Uses the last resolved layout;
+    /// page and screen coordinates equal the client ones. Never coalesced;
+    /// moves queued by `queuePointerMove` are dispatched first. For tests
+    /// and benchmarks of documents that are not shown in a window.
+    #[napi]
+    pub fn dispatch_pointer_move(
+        &self,
+        client_x: f64,
+        client_y: f64,
+        buttons: Option<u32>,
+    ) -> Result<()> {
+        let buttons = MouseEventButtons::from_bits_truncate(buttons.unwrap_or(0) as _);
+        self.dispatch_mouse(client_x, client_y, buttons, UiEvent::PointerMove)
+    }
+
+    /// Queue a mouse `pointermove` the way a window coalesces them: the
+    /// next `resolve` or `dispatchPointer*` call dispatches the latest
+    /// sample, with every queued one as `getCoalescedEvents()` and the
+    /// last step extrapolated as `getPredictedEvents()`.
+    #[napi]
+    pub fn queue_pointer_move(
+        &self,
+        client_x: f64,
+        client_y: f64,
+        buttons: Option<u32>,
+    ) -> Result<()> {
+        self.doc.check_live()?;
+        let buttons = MouseEventButtons::from_bits_truncate(buttons.unwrap_or(0) as _);
+        self.doc
+            .pointer_moves
+            .borrow_mut()
+            .queue(mouse_event(client_x, client_y, buttons));
+        Ok(())
+    }
+
+    /// Press the primary mouse button at client coordinates, through the
+    /// same pipeline as `dispatchPointerMove`.
     #[napi]
-    pub fn resolve(&mut self, time_ms: f64) {
-        self.doc.base.borrow_mut().resolve(time_ms);
+    pub fn dispatch_pointer_down(&self, client_x: f64, client_y: f64) -> Result<()> {
+        let buttons = MouseEventButtons::Primary;
+        self.dispatch_mouse(client_x, client_y, buttons, UiEvent::PointerDown)
+    }
+
+    /// Release the primary mouse button at client coordinates, through
+    /// the same pipeline as `dispatchPointerMove`.
+    #[napi]
+    pub fn dispatch_pointer_up(&self, client_x: f64, client_y: f64) -> Result<()> {
+        let buttons = MouseEventButtons::empty();
+        self.dispatch_mouse(client_x, client_y, buttons, UiEvent::PointerUp)
+    }
+
+    /// Feed a touchscreen contact through the same pipeline as
+    /// `dispatchPointerMove`: the finger's pointer event, then its touch
+    /// event. `phase` is "start" | "move" | "end" | "cancel";
+    /// `identifier` tells fingers apart, and the first one down is the
+    /// primary pointer.
+    #[napi]
+    pub fn dispatch_touch(
+        &self,
+        phase: String,
+        identifier: u32,
+        client_x: f64,
+        client_y: f64,
+    ) -> Result<()> {
+        self.doc.check_live()?;
+        let (kind, buttons): (fn(BlitzPointerEvent) -> UiEvent, _) = match phase.as_str() {
+            "start" => (UiEvent::PointerDown, MouseEventButtons::Primary),
+            "move" => (UiEvent::PointerMove, MouseEventButtons::Primary),
+            "end" => (UiEvent::PointerUp, MouseEventButtons::empty()),
+            "cancel" => (UiEvent::PointerCancel, MouseEventButtons::empty()),
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("dispatchTouch: invalid phase {phase:?}"),
+                ));
+            }
+        };
+        let id = u64::from(identifier);
+        let is_primary = self.doc.touches.borrow().is_primary(id);
+        let pointer = pointer_event(
+            BlitzPointerId::Finger(id),
+            is_primary,
+            client_x,
+            client_y,
+            buttons,
+        );
+        self.dispatch_input(kind(pointer))
+    }
+
+    /// Feed one trackpad gesture step through the same path as a
+    /// window's, without the pinch zoom. `kind` is "pinch" (`delta` is the
+    /// scale change, `0.1` is 10% larger), "rotate" (`delta` in degrees)
+    /// or "pan" (`delta` and `delta_y` in CSS pixels); `phase` is "start" |
+    /// "change" | "end". Returns whether the step was canceled.
+    #[napi]
+    pub fn dispatch_gesture(
+        &self,
+        env: Env,
+        kind: String,
+        phase: String,
+        delta: f64,
+        delta_y: Option<f64>,
+    ) -> Result<bool> {
+        self.doc.check_live()?;
+        let delta = match kind.as_str() {
+            "pinch" => GestureDelta::Pinch(delta),
+            "rotate" => GestureDelta::Rotate(delta),
+            "pan" => GestureDelta::Pan(delta, delta_y.unwrap_or(0.0)),
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("dispatchGesture: invalid kind {kind:?}"),
+                ));
+            }
+        };
+        let phase = match phase.as_str() {
+            "start" => GesturePhase::Start,
+            "change" => GesturePhase::Change,
+            "end" => GesturePhase::End,
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("dispatchGesture: invalid phase {phase:?}"),
+                ));
+            }
+        };
+        handle_gesture(&self.doc, phase, delta, &env)
     }
 
     #[napi]
@@ -502,23 +1054,28 @@ impl NativeDoc {
                 None
             };
 
-        let registered = self.font_ctx.collection.register_fonts(blob, info_override);
+        let font_ctx = self
+            .font_ctx
+            .as_mut()
+            .ok_or_else(|| Error::from_reason("document is disposed"))?;
+        let registered = font_ctx.collection.register_fonts(blob, info_override);
         let face_count: usize = registered.iter().map(|(_, fonts)| fonts.len()).sum();
         Ok(face_count as u32)
     }
 
     #[napi]
-    pub fn root_node_id(&self) -> u64 {
-        self.doc.base.borrow().root_node().id.as_u64()
+    pub fn root_node_id(&self) -> Result<u64> {
+        Ok(self.doc.read()?.root_node().id.as_u64())
     }
 
     #[napi]
-    pub fn root_element_id(&self) -> u64 {
-        self.doc.base.borrow().root_element().id.as_u64()
+    pub fn root_element_id(&self) -> Result<u64> {
+        Ok(self.doc.read()?.root_element().id.as_u64())
     }
 
     #[napi]
     pub fn set_document_ref(&self, env: Env, document: Object) -> Result<()> {
+        self.doc.check_live()?;
         *self.doc.js_document_ref.borrow_mut() = Some(JsWeakRef::new(&document, &env)?);
         Ok(())
     }
@@ -527,6 +1084,7 @@ impl NativeDoc {
     /// pointer events to it via the registered dispatch function.
     #[napi]
     pub fn set_window_ref(&self, env: Env, window: Object) -> Result<()> {
+        self.doc.check_live()?;
         *self.doc.js_window_ref.borrow_mut() = Some(JsWeakRef::new(&window, &env)?);
         Ok(())
     }
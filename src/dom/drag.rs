@@ -0,0 +1,430 @@
+//! Drag-and-drop sessions, shared by element drags and OS file drops.
+//!
+//! A `DragSession` lives in `SharedDoc` while something is dragged over
+//! the document. Both kinds of drag run the same HTML processing model
+//! against the element under the cursor:
+//!
+//! - When that element changes, `dragenter` fires at the new one, then
+//!   `dragleave` at the old one (`update_target`).
+//! - Every step then fires `dragover` at the current target, with
+//!   `dropEffect` reset to "copy". Canceling it (and leaving a
+//!   `dropEffect` other than "none") accepts the drop.
+//! - On release an accepted drag fires `drop` at the target, a rejected
+//!   one `dragleave` (`finish`).
+//!
+//! Element drags (`draggable="true"`) start from the pointer stream,
+//! intercepted in `WindowDocument::handle_ui_event` before blitz sees it
+//! (`handle_drag_pointer`):
+//!
+//! 1. A primary-button press on a draggable element records a candidate.
+//! 2. Once the pointer moves a few pixels with the button held,
+//!    `dragstart` fires at the source (canceling it aborts the drag) and a
+//!    snapshot of the element starts following the pointer.
+//! 3. Each further move fires `drag` at the source, then the target steps
+//!    above. These moves are consumed: JS sees no pointer events (and no
+//!    `click`) until the drag is over. The final release still reaches
+//!    blitz, so the press ends there too, but not JS.
+//! 4. Release drops; Escape or a canceled `drag` cancels. Either way
+//!    `dragend` fires at the source with the final `dropEffect` ("none"
+//!    unless dropped).
+//!
+//! OS file drags enter through `dom/file_drop.rs`.
+
+use std::{cell::RefCell, rc::Rc};
+
+use blitz::{
+    dom::{BaseDocument, Document as BlitzDocument, EventHandler, NodeId, local_name},
+    traits::events::{
+        BlitzPointerEvent, DomEvent, DomEventData, EventState, MouseEventButton, MouseEventButtons,
+        UiEvent,
+    },
+};
+use keyboard_types::{Key, NamedKey};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::dispatch_synthetic,
+    file_drop::{is_file_input, set_input_files},
+    node_handle::DomRect,
+    ops::{body_or_root, hit_element},
+    payload::{DataTransferItems, DragData, EventPayload},
+};
+
+/// Distance in CSS pixels the pointer must travel with the button held
+/// before a press on a draggable element turns into a drag.
+const DRAG_THRESHOLD: f64 = 4.0;
+
+/// A drag in progress over a document.
+pub struct DragSession {
+    /// Dragged element; `None` for files dragged in from the OS.
+    source: Option<NodeId>,
+    files: Vec<String>,
+    data: DataTransferItems,
+    drop_effect: Rc<RefCell<String>>,
+    /// Last pointer position, viewport CSS pixels.
+    x: f64,
+    y: f64,
+    /// Element the last `dragenter` was fired at.
+    target: Option<NodeId>,
+    /// The last `dragover` accepted the drop.
+    accepted: bool,
+    /// Drag image following the pointer (element drags).
+    image: Option<DragImage>,
+}
+
+impl DragSession {
+    /// A session for files dragged in from the OS.
+    pub(crate) fn files(files: Vec<String>, x: f64, y: f64) -> Self {
+        Self::new(None, files, x, y)
+    }
+
+    fn new(source: Option<NodeId>, files: Vec<String>, x: f64, y: f64) -> Self {
+        Self {
+            source,
+            files,
+            data: DataTransferItems::default(),
+            drop_effect: Rc::new(RefCell::new("none".to_string())),
+            x,
+            y,
+            target: None,
+            accepted: false,
+            image: None,
+        }
+    }
+
+    pub(crate) fn set_files(&mut self, files: Vec<String>) {
+        self.files = files;
+    }
+
+    fn payload(&self, event_type: &str, cancelable: bool) -> EventPayload {
+        EventPayload {
+            drag: Some(DragData {
+                files: self.files.clone(),
+                data: self.data.clone(),
+                drop_effect: Rc::clone(&self.drop_effect),
+                client_x: self.x,
+                client_y: self.y,
+            }),
+            ..EventPayload::new(event_type, true, cancelable)
+        }
+    }
+}
+
+/// A press on a draggable element that may still turn into a drag.
+#[derive(Clone, Copy)]
+pub struct DragCandidate {
+    source: NodeId,
+    x: f64,
+    y: f64,
+}
+
+/// Payload for `event_type` from the current session, or `None` once the
+/// session has ended. Listeners run between the events of one step, so
+/// this is read afresh for each event rather than borrowed across them.
+fn session_payload(doc: &SharedDoc, event_type: &str, cancelable: bool) -> Option<EventPayload> {
+    doc.drag
+        .borrow()
+        .as_ref()
+        .map(|session| session.payload(event_type, cancelable))
+}
+
+/// Move the drag to `(x, y)`: fire `dragenter` / `dragleave` if the element
+/// under the cursor changed, then `dragover`, and record whether the drop
+/// is accepted.
+pub(crate) fn update_target(doc: &Rc<SharedDoc>, x: f64, y: f64, env: &Env) -> Result<()> {
+    let (current, previous) = {
+        let mut drag = doc.drag.borrow_mut();
+        let Some(session) = drag.as_mut() else {
+            return Ok(());
+        };
+        session.x = x;
+        session.y = y;
+        let base = doc.base.borrow();
+        let current = hit_element(&base, x, y).unwrap_or_else(|| body_or_root(&base));
+        (current, session.target.replace(current))
+    };
+
+    if previous != Some(current) {
+        if let Some(payload) = session_payload(doc, "dragenter", true) {
+            dispatch_synthetic(doc, current, payload, env)?;
+        }
+        if let Some(old) = previous.filter(|&id| doc.is_in_document(id))
+            && let Some(payload) = session_payload(doc, "dragleave", false)
+        {
+            dispatch_synthetic(doc, old, payload, env)?;
+        }
+    }
+
+    let Some(payload) = ({
+        let drag = doc.drag.borrow();
+        drag.as_ref().map(|session| {
+            *session.drop_effect.borrow_mut() = "copy".to_string();
+            session.payload("dragover", true)
+        })
+    }) else {
+        return Ok(());
+    };
+    let outcome = dispatch_synthetic(doc, current, payload, env)?;
+    if let Some(session) = doc.drag.borrow_mut().as_mut() {
+        // A file input takes dropped files without any listener.
+        let default_accept =
+            !session.files.is_empty() && is_file_input(&doc.base.borrow(), current);
+        let effect_ok = *session.drop_effect.borrow() != "none";
+        session.accepted = (outcome.default_prevented && effect_ok) || default_accept;
+    }
+    Ok(())
+}
+
+/// End the session: `drop` at the target if the drag was accepted (and
+/// `dropped` is set), `dragleave` otherwise, then `dragend` at the source
+/// of an element drag.
+pub(crate) fn finish(doc: &Rc<SharedDoc>, dropped: bool, env: &Env) -> Result<()> {
+    let Some(mut session) = doc.drag.borrow_mut().take() else {
+        return Ok(());
+    };
+    if session.image.take().is_some() {
+        doc.mark_host_dirty();
+    }
+    let accepted = dropped && session.accepted;
+    if !accepted {
+        *session.drop_effect.borrow_mut() = "none".to_string();
+    }
+
+    if let Some(target) = session.target.filter(|&id| doc.is_in_document(id)) {
+        if accepted {
+            let outcome = dispatch_synthetic(doc, target, session.payload("drop", true), env)?;
+            if !outcome.default_prevented
+                && !session.files.is_empty()
+                && is_file_input(&doc.base.borrow(), target)
+            {
+                set_input_files(doc, target, session.files.clone(), env)?;
+            }
+        } else {
+            dispatch_synthetic(doc, target, session.payload("dragleave", false), env)?;
+        }
+    }
+
+    if let Some(source) = session.source.filter(|&id| doc.is_in_document(id)) {
+        dispatch_synthetic(doc, source, session.payload("dragend", false), env)?;
+    }
+    Ok(())
+}
+
+// ── Element drags ─────────────────────────────────────────────────────
+
+/// Nearest inclusive ancestor with `draggable="true"`. An explicit
+/// `draggable="false"` stops the search.
+fn draggable_ancestor(base: &BaseDocument, node_id: NodeId) -> Option<NodeId> {
+    let mut cursor = Some(node_id);
+    while let Some(id) = cursor {
+        let node = base.get_node(id)?;
+        match node.attr(local_name!("draggable")) {
+            Some(v) if v.eq_ignore_ascii_case("true") => return Some(id),
+            Some(v) if v.eq_ignore_ascii_case("false") => return None,
+            _ => {}
+        }
+        cursor = node.parent;
+    }
+    None
+}
+
+fn client_pos(pointer: &BlitzPointerEvent) -> (f64, f64) {
+    (
+        pointer.coords.client_x as f64,
+        pointer.coords.client_y as f64,
+    )
+}
+
+/// What `handle_drag_pointer` did with a UI event.
+pub(crate) enum DragPointer {
+    /// Not part of an element drag: handle it as usual.
+    Pass,
+    /// Consumed by the drag: neither blitz nor JS sees it.
+    Consumed,
+    /// The release (or cancel) that ended the drag: blitz still needs it
+    /// to end the press, through `DragReleaseHandler`.
+    Release,
+}
+
+/// Event handler for the release that ends an element drag: JS sees
+/// none of its events, and the `click` it may produce does nothing.
+pub(crate) struct DragReleaseHandler;
+
+impl EventHandler for DragReleaseHandler {
+    fn handle_event(
+        &mut self,
+        _chain: &[NodeId],
+        event: &mut DomEvent,
+        _doc: &mut dyn BlitzDocument,
+        event_state: &mut EventState,
+    ) {
+        if matches!(event.data, DomEventData::Click(_)) {
+            event_state.prevent_default();
+        }
+    }
+}
+
+/// Run element drag-and-drop on a UI event before it reaches blitz.
+pub(crate) fn handle_drag_pointer(
+    doc: &Rc<SharedDoc>,
+    event: &UiEvent,
+    env: &Env,
+) -> Result<DragPointer> {
+    let element_drag = doc
+        .drag
+        .borrow()
+        .as_ref()
+        .is_some_and(|s| s.source.is_some());
+    match event {
+        UiEvent::PointerDown(pointer) if matches!(pointer.button, MouseEventButton::Main) => {
+            let (x, y) = client_pos(pointer);
+            let source = {
+                let base = doc.base.borrow();
+                hit_element(&base, x, y).and_then(|id| draggable_ancestor(&base, id))
+            };
+            doc.drag_candidate
+                .set(source.map(|source| DragCandidate { source, x, y }));
+            Ok(DragPointer::Pass)
+        }
+        UiEvent::PointerMove(pointer) if element_drag => {
+            let (x, y) = client_pos(pointer);
+            drag_step(doc, x, y, env)?;
+            Ok(DragPointer::Consumed)
+        }
+        UiEvent::PointerMove(pointer) => {
+            let Some(candidate) = doc.drag_candidate.get() else {
+                return Ok(DragPointer::Pass);
+            };
+            if !pointer.buttons.contains(MouseEventButtons::Primary) {
+                doc.drag_candidate.set(None);
+                return Ok(DragPointer::Pass);
+            }
+            let (x, y) = client_pos(pointer);
+            if (x - candidate.x).hypot(y - candidate.y) < DRAG_THRESHOLD {
+                return Ok(DragPointer::Pass);
+            }
+            doc.drag_candidate.set(None);
+            Ok(if start_drag(doc, candidate, x, y, env)? {
+                DragPointer::Consumed
+            } else {
+                DragPointer::Pass
+            })
+        }
+        UiEvent::PointerUp(_) if element_drag => {
+            finish(doc, true, env)?;
+            Ok(DragPointer::Release)
+        }
+        UiEvent::PointerCancel(_) if element_drag => {
+            finish(doc, false, env)?;
+            Ok(DragPointer::Release)
+        }
+        UiEvent::PointerUp(_) | UiEvent::PointerCancel(_) => {
+            doc.drag_candidate.set(None);
+            Ok(DragPointer::Pass)
+        }
+        UiEvent::KeyDown(key) if element_drag && key.key == Key::Named(NamedKey::Escape) => {
+            finish(doc, false, env)?;
+            Ok(DragPointer::Consumed)
+        }
+        _ => Ok(DragPointer::Pass),
+    }
+}
+
+/// Fire `dragstart`; unless canceled, begin the session at `(x, y)`.
+/// Returns whether a drag started (and the move was consumed).
+fn start_drag(
+    doc: &Rc<SharedDoc>,
+    candidate: DragCandidate,
+    x: f64,
+    y: f64,
+    env: &Env,
+) -> Result<bool> {
+    let source = candidate.source;
+    if !doc.is_in_document(source) {
+        return Ok(false);
+    }
+    // `dragstart` reports where the press happened, as in browsers.
+    let session = DragSession::new(Some(source), Vec::new(), candidate.x, candidate.y);
+    let outcome = dispatch_synthetic(doc, source, session.payload("dragstart", true), env)?;
+    if outcome.default_prevented || !doc.is_in_document(source) {
+        return Ok(false);
+    }
+
+    // The press no longer extends a text selection.
+    doc.selecting.set(false);
+    let image = DragImage::new(&doc.base.borrow(), source, candidate.x, candidate.y);
+    *doc.drag.borrow_mut() = Some(DragSession { image, ..session });
+    update_target(doc, x, y, env)?;
+    doc.mark_host_dirty();
+    Ok(true)
+}
+
+/// One pointer move of an element drag: `drag` at the source, then the
+/// target steps. Canceling `drag` cancels the whole operation.
+fn drag_step(doc: &Rc<SharedDoc>, x: f64, y: f64, env: &Env) -> Result<()> {
+    let Some((source, payload)) = doc.drag.borrow_mut().as_mut().and_then(|session| {
+        session.x = x;
+        session.y = y;
+        Some((session.source?, session.payload("drag", true)))
+    }) else {
+        return Ok(());
+    };
+    let outcome = dispatch_synthetic(doc, source, payload, env)?;
+    if outcome.default_prevented {
+        return finish(doc, false, env);
+    }
+    update_target(doc, x, y, env)?;
+    // The drag image follows the pointer.
+    doc.mark_host_dirty();
+    Ok(())
+}
+
+// ── Drag image ────────────────────────────────────────────────────────
+
+/// The drag image: the dragged element's part of the painted document,
+/// drawn translucent over each frame so that the pointer keeps its spot
+/// on the element (see `renderer`). It is no node, so neither script nor
+/// hit testing ever sees it.
+struct DragImage {
+    /// Pointer position relative to the element's top-left corner.
+    offset_x: f64,
+    offset_y: f64,
+}
+
+impl DragImage {
+    /// Pick up `source` with the pointer at `(x, y)`.
+    fn new(base: &BaseDocument, source: NodeId, x: f64, y: f64) -> Option<Self> {
+        let pos = base.get_node(source)?.absolute_position(0.0, 0.0);
+        let scroll = base.viewport_scroll();
+        Some(Self {
+            offset_x: x + scroll.x - pos.x as f64,
+            offset_y: y + scroll.y - pos.y as f64,
+        })
+    }
+}
+
+/// Where the renderer paints the drag image: the part of the painted
+/// document inside `source` (viewport CSS pixels), moved by `(dx, dy)`.
+pub struct DragImageSpot {
+    pub source: DomRect,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+/// The drag image of the element drag in progress, if any.
+pub(crate) fn drag_image_spot(doc: &SharedDoc, base: &BaseDocument) -> Option<DragImageSpot> {
+    let drag = doc.drag.borrow();
+    let session = drag.as_ref()?;
+    let image = session.image.as_ref()?;
+    let node = base.get_node(session.source?)?;
+    let pos = node.absolute_position(0.0, 0.0);
+    let size = node.final_layout().size;
+    let scroll = base.viewport_scroll();
+    let (left, top) = (pos.x as f64 - scroll.x, pos.y as f64 - scroll.y);
+    Some(DragImageSpot {
+        source: DomRect::new(left, top, size.width as f64, size.height as f64),
+        dx: session.x - image.offset_x - left,
+        dy: session.y - image.offset_y - top,
+    })
+}
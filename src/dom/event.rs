@@ -12,31 +12,43 @@
 //!    and invokes `node.dispatchEvent(event)`.
 //! 4. Reads back `event.defaultPrevented` / `event.cancelBubble` and
 //!    writes them to blitz's `EventState`.
+//!
+//! Pointer events honour pointer capture (`dom/pointer_capture.rs`): while
+//! a node holds the capture of a pointer, its moves and ups are retargeted
+//! to that node. Events synthesized on the Rust side reuse the same walk
+//! through `dispatch_synthetic`.
 
 use crate::{
     dom::{
+        clipboard_event::{clipboard_shortcut, run_clipboard_action},
         doc::{SharedDoc, wrap_node},
-        payload::{EventPayload, ImeData, InputData, KeyData, PointerData, WheelData},
+        focus::focus_sequential,
+        payload::{EventPayload, ImeData, InputData, KeyData, PointerData, WheelData, now_ms},
+        pointer_capture::process_pending_capture,
+        pointer_info::decorate,
+        pointer_moves::PointerSamples,
     },
-    global,
     helpers::{
         build_event_object, dispatch_event, read_event_flag, reset_dispatch_state, resolve_window,
     },
 };
 use blitz::{
-    dom::{Document as BlitzDocument, EventHandler, NodeData, NodeId},
+    dom::{BaseDocument, Document as BlitzDocument, EventHandler, NodeData, NodeId},
     traits::events::{
-        BlitzImeEvent, BlitzPointerId, BlitzWheelDelta, DomEvent, DomEventData, EventState,
-        KeyState,
+        BlitzImeEvent, BlitzWheelDelta, DomEvent, DomEventData, EventState, KeyState,
     },
 };
+use keyboard_types::{Key, Modifiers, NamedKey};
 use napi::{
     Env, JsValue, Result,
     bindgen_prelude::{
         FromNapiValue, JsObjectValue, Object, Property, PropertyAttributes, This, Unknown,
     },
 };
-use std::rc::{Rc, Weak};
+use std::{
+    rc::{Rc, Weak},
+    sync::Arc,
+};
 
 const CAPTURING_PHASE: u32 = 1;
 const AT_TARGET: u32 = 2;
@@ -52,9 +64,15 @@ pub struct JsEventHandler {
 /// Normalize the event target: if it's an `AnonymousBlock` (blitz internal
 /// layout node), walk up to the first non-anonymous ancestor.
 fn normalize_event_target(doc: &dyn BlitzDocument, target: NodeId) -> NodeId {
-    let inner = doc.inner();
+    normalize_hit_target(&doc.inner(), target)
+}
+
+/// `BaseDocument` flavour of `normalize_event_target`, shared with the
+/// hit-testing APIs on `NativeDoc` (`elementFromPoint` etc.) so both agree
+/// on which node a coordinate resolves to.
+pub(crate) fn normalize_hit_target(base: &BaseDocument, target: NodeId) -> NodeId {
     let mut node_id = target;
-    while let Some(node) = inner.get_node(node_id) {
+    while let Some(node) = base.get_node(node_id) {
         match node.data {
             NodeData::AnonymousBlock(_) => {
                 let Some(parent_id) = node.parent else {
@@ -85,15 +103,11 @@ impl EventHandler for JsEventHandler {
         doc: &mut dyn BlitzDocument,
         event_state: &mut EventState,
     ) {
-        let env = match global::env() {
-            Ok(e) => e,
-            Err(_) => return,
-        };
-
         // Upgrade weak ref to get the SharedDoc.
         let Some(shared_doc) = self.doc.upgrade() else {
             return;
         };
+        let env = shared_doc.env;
 
         // The dispatch pipeline is best-effort: a napi boundary failure is
         // logged and dropped, the event is simply not delivered further.
@@ -104,10 +118,11 @@ impl EventHandler for JsEventHandler {
 }
 
 impl JsEventHandler {
-    /// Run the full event dispatch pipeline: build the JS `Event`, walk the
-    /// chain in capture → target → bubble order, reset transient dispatch
-    /// state, forward pointer events to the window, and write the resulting
-    /// flags back to blitz's `EventState`.
+    /// Run the full event dispatch pipeline: settle pointer capture, build
+    /// the JS `Event`, walk the chain in capture → target → bubble order,
+    /// reset transient dispatch state, forward pointer events to the
+    /// window, write the resulting flags back to blitz's `EventState`, and
+    /// run the Tab and clipboard default actions.
     fn dispatch(
         &mut self,
         chain: &[NodeId],
@@ -117,107 +132,289 @@ impl JsEventHandler {
         shared_doc: &Rc<SharedDoc>,
         env: &Env,
     ) -> Result<()> {
-        // 1. Build the JS Event object via the registered factory.
-        let payload = serialize_event(event);
-        let mut event_obj = build_event_object(payload, env)?;
-
-        // 2. Normalize target (skip AnonymousBlock).
-        let target_nid = normalize_event_target(doc, event.target);
-
-        // 3. Set lazy target getter on the event.
-        set_lazy_target(&mut event_obj, target_nid, shared_doc, env)?;
-
-        // 4. Filter anonymous nodes from the chain.
-        let clean_chain: Vec<NodeId> = chain
-            .iter()
-            .copied()
-            .filter(|&nid| !is_anonymous(doc, nid))
-            .collect();
+        // 1. Serialize the blitz event once; the payload feeds the JS
+        //    event factory. A `pointermove` carries the samples it was
+        //    coalesced from, or just itself when dispatched unqueued.
+        let mut payload = serialize_event(event);
+        if let DomEventData::PointerMove(p) = &event.data
+            && let Some(pointer) = &mut payload.pointer
+        {
+            let current = shared_doc.pointer_moves.borrow().current.clone();
+            pointer.samples = Some(current.unwrap_or_else(|| {
+                Arc::new(PointerSamples {
+                    coalesced: vec![p.clone()],
+                    predicted: Vec::new(),
+                })
+            }));
+        }
 
-        let mut propagation_stopped = false;
+        // 2. Pointer capture: apply capture changes requested since the
+        //    last pointer event (fires got/lostpointercapture), then look
+        //    up the node this event should be redirected to.
+        let pointer = payload.pointer.clone();
+        let captured = match &pointer {
+            Some(pointer) => {
+                process_pending_capture(shared_doc, pointer, env)?;
+                is_capture_redirected(event)
+                    .then(|| {
+                        shared_doc
+                            .pointer_captures
+                            .borrow()
+                            .target(pointer.pointer_id as u64)
+                    })
+                    .flatten()
+            }
+            None => None,
+        };
 
-        // 5. Capture phase (root → target's parent).
-        for &nid in clean_chain.iter().skip(1).rev() {
-            if propagation_stopped {
-                break;
+        // 3. Resolve the target and the chain. A captured pointer ignores
+        //    the hit-tested chain and walks the capturing node's ancestors
+        //    instead; otherwise the target is normalized (skip
+        //    AnonymousBlock) and anonymous nodes are filtered out.
+        let (target_nid, clean_chain) = match captured {
+            Some(capture_nid) => (capture_nid, node_chain(&doc.inner(), capture_nid)),
+            None => {
+                let clean_chain: Vec<NodeId> = chain
+                    .iter()
+                    .copied()
+                    .filter(|&nid| !is_anonymous(doc, nid))
+                    .collect();
+                (normalize_event_target(doc, event.target), clean_chain)
             }
-            propagation_stopped =
-                self.dispatch_to_node(nid, &mut event_obj, CAPTURING_PHASE, shared_doc, env);
-        }
+        };
 
-        // 6. Target phase.
-        if !propagation_stopped {
-            propagation_stopped =
-                self.dispatch_to_node(target_nid, &mut event_obj, AT_TARGET, shared_doc, env);
+        //    Fill in the pointer fields that depend on the target and on
+        //    the UI event (movement, click count, hover transition).
+        decorate(
+            &shared_doc.pointer_info.borrow(),
+            &doc.inner(),
+            event,
+            target_nid,
+            &mut payload,
+        );
+
+        // 4. Three-phase walk, then forward pointer events to the window.
+        let outcome = dispatch_payload(
+            payload,
+            target_nid,
+            &clean_chain,
+            is_pointer_event(event),
+            shared_doc,
+            env,
+        )?;
+
+        // 5. Write the flags back to blitz EventState.
+        if outcome.default_prevented {
+            event_state.prevent_default();
         }
-
-        // 7. Bubble phase (target's parent → root).
-        if event.bubbles && !propagation_stopped {
-            for &nid in clean_chain.iter().skip(1) {
-                if propagation_stopped {
-                    break;
-                }
-                propagation_stopped =
-                    self.dispatch_to_node(nid, &mut event_obj, BUBBLING_PHASE, shared_doc, env);
-            }
+        if outcome.propagation_stopped {
+            event_state.stop_propagation();
         }
 
-        // 8. Reset transient dispatch state: currentTarget → null,
-        //    eventPhase → NONE (0). Per DOM spec, after dispatch ends
-        //    these values are cleared so async callbacks see null.
-        reset_dispatch_state(&mut event_obj, env);
-
-        // 9. Dispatch pointer events to the window-level EventTarget.
-        //    JS code may register `pointermove`/`pointerup` listeners on
-        //    the Window object (e.g. for drag tracking). The DOM chain
-        //    walk above only reaches DOM nodes, so we explicitly forward
-        //    here.
-        if is_pointer_event(event) {
-            let _ = dispatch_to_window(&event_obj, shared_doc, env);
+        // 6. Sequential focus navigation is the default action of an
+        //    uncanceled Tab keydown. It replaces blitz's own handling (e.g.
+        //    inserting a tab into a textarea); the focus events follow from
+        //    `sync_focus` once the UI event has been fully processed.
+        if let DomEventData::KeyDown(key) = &event.data
+            && !outcome.default_prevented
+            && key.key == Key::Named(NamedKey::Tab)
+        {
+            let forward = !key.modifiers.contains(Modifiers::SHIFT);
+            focus_sequential(&mut doc.inner_mut(), forward);
+            event_state.prevent_default();
         }
 
-        // 10. Read back flags and write to blitz EventState.
-        let default_prevented: bool = event_obj
-            .get_named_property::<bool>("defaultPrevented")
-            .unwrap_or(false);
-        if default_prevented {
+        //    Likewise, an uncanceled copy / cut / paste shortcut fires the
+        //    clipboard event and runs its default action in place of
+        //    blitz's (which has no clipboard to talk to).
+        if let DomEventData::KeyDown(key) = &event.data
+            && !outcome.default_prevented
+            && let Some(action) = clipboard_shortcut(key)
+        {
+            run_clipboard_action(shared_doc, action, env)?;
             event_state.prevent_default();
         }
-        if propagation_stopped {
-            event_state.stop_propagation();
-        }
 
-        // 11. Sweep stale cache entries periodically.
-        shared_doc.node_cache.borrow_mut().sweep(env);
+        // 7. Implicit capture release: `pointerup` ends any capture of its
+        //    pointer, and `lostpointercapture` follows right away.
+        if let Some(pointer) = &pointer
+            && matches!(event.data, DomEventData::PointerUp(_))
+        {
+            shared_doc
+                .pointer_captures
+                .borrow_mut()
+                .release_pointer(pointer.pointer_id as u64);
+            process_pending_capture(shared_doc, pointer, env)?;
+        }
 
         Ok(())
     }
+}
 
-    /// Dispatch the event to a single node. Returns `true` if propagation
-    /// was stopped (stopPropagation / stopImmediatePropagation).
-    fn dispatch_to_node(
-        &self,
-        node_id: NodeId,
-        event: &mut Object,
-        phase: u32,
-        doc: &Rc<SharedDoc>,
-        env: &Env,
-    ) -> bool {
-        // 1. Wrap the node (NodeCache lookup or create).
-        let node = match wrap_node(doc, node_id, env) {
-            Ok(n) => n,
-            Err(e) => {
-                eprintln!("napi-blitz: wrap_node failed for node {node_id}: {e}");
-                return false;
+/// Flags read back from a JS `Event` after a dispatch.
+pub(crate) struct DispatchOutcome {
+    pub(crate) default_prevented: bool,
+    pub(crate) propagation_stopped: bool,
+}
+
+/// Dispatch one payload along `chain` (target first, root last):
+///
+/// 0. Returns right away when neither a node on the path nor (if
+///    forwarding) the window listens for the event type; see `listeners`.
+/// 1. Builds the JS `Event` object via the registered factory.
+/// 2. Sets a lazy `target` getter for `target_nid` (and `relatedTarget`
+///    when the payload names one).
+/// 3. Walks capture → target → bubble (bubble only if the payload bubbles),
+///    visiting only nodes that listen for the type.
+/// 4. Resets `currentTarget` / `eventPhase`.
+/// 5. Optionally forwards the event to the JS Window object.
+///
+/// Shared by blitz-driven events (`JsEventHandler`) and events synthesized
+/// on the Rust side (`dispatch_synthetic`).
+pub(crate) fn dispatch_payload(
+    payload: EventPayload,
+    target_nid: NodeId,
+    chain: &[NodeId],
+    forward_to_window: bool,
+    shared_doc: &Rc<SharedDoc>,
+    env: &Env,
+) -> Result<DispatchOutcome> {
+    let event_type = payload.event_type.clone();
+    let listens = |nid: NodeId| shared_doc.listens(nid, &event_type);
+    if !listens(target_nid)
+        && !chain.iter().any(|&nid| listens(nid))
+        && !(forward_to_window && shared_doc.window_listens(&event_type))
+    {
+        return Ok(DispatchOutcome {
+            default_prevented: false,
+            propagation_stopped: false,
+        });
+    }
+
+    let bubbles = payload.bubbles;
+    let related_target = payload.related_target;
+    let mut event_obj = build_event_object(payload, env)?;
+    set_lazy_node(&mut event_obj, "target", target_nid, shared_doc)?;
+    if let Some(related) = related_target {
+        set_lazy_node(&mut event_obj, "relatedTarget", related, shared_doc)?;
+    }
+
+    let mut propagation_stopped = false;
+
+    // Capture phase (root → target's parent). Listeners are checked as the
+    // walk reaches each node: ones added by earlier listeners still run.
+    for &nid in chain.iter().skip(1).rev() {
+        if propagation_stopped {
+            break;
+        }
+        if !listens(nid) {
+            continue;
+        }
+        propagation_stopped =
+            dispatch_to_node(nid, &mut event_obj, CAPTURING_PHASE, shared_doc, env);
+    }
+
+    // Target phase.
+    if !propagation_stopped && listens(target_nid) {
+        propagation_stopped =
+            dispatch_to_node(target_nid, &mut event_obj, AT_TARGET, shared_doc, env);
+    }
+
+    // Bubble phase (target's parent → root).
+    if bubbles && !propagation_stopped {
+        for &nid in chain.iter().skip(1) {
+            if propagation_stopped {
+                break;
             }
-        };
+            if !listens(nid) {
+                continue;
+            }
+            propagation_stopped =
+                dispatch_to_node(nid, &mut event_obj, BUBBLING_PHASE, shared_doc, env);
+        }
+    }
 
-        // 2. Set lazy currentTarget + eventPhase.
-        let _ = set_lazy_current_target(event, node_id, phase, doc, env);
+    // Reset transient dispatch state: currentTarget → null, eventPhase →
+    // NONE (0). Per DOM spec, after dispatch ends these values are cleared
+    // so async callbacks see null.
+    reset_dispatch_state(&mut event_obj, env);
 
-        // 3. Call node.dispatchEvent(event) and read back cancelBubble.
-        call_dispatch_event(&node, event, env).unwrap_or(false)
+    // JS code may register `pointermove`/`pointerup` listeners on the
+    // Window object (e.g. for drag tracking). The chain walk above only
+    // reaches DOM nodes, so we explicitly forward here.
+    if forward_to_window && shared_doc.window_listens(&event_type) {
+        let _ = dispatch_to_window(&event_obj, shared_doc, env);
     }
+
+    Ok(DispatchOutcome {
+        default_prevented: read_event_flag(&event_obj, "defaultPrevented"),
+        propagation_stopped,
+    })
+}
+
+/// Dispatch an event synthesized on the Rust side (no blitz `DomEvent`
+/// behind it) at `target`. The chain is the target's ancestor path.
+pub(crate) fn dispatch_synthetic(
+    doc: &Rc<SharedDoc>,
+    target: NodeId,
+    payload: EventPayload,
+    env: &Env,
+) -> Result<DispatchOutcome> {
+    let chain = node_chain(&doc.base.borrow(), target);
+    dispatch_payload(payload, target, &chain, false, doc, env)
+}
+
+/// Ancestor path of `node_id`, target first and root last (the same order
+/// blitz hands to `EventHandler::handle_event`), skipping anonymous blocks.
+pub(crate) fn node_chain(base: &BaseDocument, node_id: NodeId) -> Vec<NodeId> {
+    let mut chain = Vec::new();
+    let mut cursor = Some(node_id);
+    while let Some(id) = cursor {
+        let Some(node) = base.get_node(id) else {
+            break;
+        };
+        if !matches!(node.data, NodeData::AnonymousBlock(_)) {
+            chain.push(id);
+        }
+        cursor = node.parent;
+    }
+    chain
+}
+
+/// Dispatch the event to a single node. Returns `true` if propagation
+/// was stopped (stopPropagation / stopImmediatePropagation).
+fn dispatch_to_node(
+    node_id: NodeId,
+    event: &mut Object,
+    phase: u32,
+    doc: &Rc<SharedDoc>,
+    env: &Env,
+) -> bool {
+    // 1. Wrap the node (NodeCache lookup or create).
+    let node = match wrap_node(doc, node_id, env) {
+        Ok(n) => n,
+        Err(e) => {
+            eprintln!("napi-blitz: wrap_node failed for node {node_id}: {e}");
+            return false;
+        }
+    };
+
+    // 2. Set lazy currentTarget + eventPhase.
+    let _ = set_lazy_current_target(event, node_id, phase, doc, env);
+
+    // 3. Call node.dispatchEvent(event) and read back cancelBubble.
+    call_dispatch_event(&node, event, env).unwrap_or(false)
+}
+
+/// Events that follow an active pointer capture instead of the hit test.
+fn is_capture_redirected(event: &DomEvent) -> bool {
+    matches!(
+        event.data,
+        DomEventData::PointerMove(_)
+            | DomEventData::PointerUp(_)
+            | DomEventData::MouseMove(_)
+            | DomEventData::MouseUp(_)
+    )
 }
 
 /// Check if the event is a pointer-type event that should also be
@@ -278,21 +475,21 @@ fn wrap_node_unknown<'env>(
     unsafe { Unknown::from_napi_value(env_raw, raw) }
 }
 
-/// Set `event.target` to a getter that wraps the node only when JS reads
-/// it. Equivalent to
-/// `Object.defineProperty(event, "target", { get, configurable: true })`.
-fn set_lazy_target(
+/// Set `event[name]` (`target`, `relatedTarget`) to a getter that wraps
+/// the node only when JS reads it. Equivalent to
+/// `Object.defineProperty(event, name, { get, configurable: true })`.
+fn set_lazy_node(
     event: &mut Object,
+    name: &str,
     node_id: NodeId,
     doc: &Rc<SharedDoc>,
-    _env: &Env,
 ) -> Result<()> {
     let doc_clone = doc.clone();
     let getter = move |ctx: Env, _this: This| -> Result<Unknown> {
         wrap_node_unknown(&ctx, &doc_clone, node_id)
     };
     let prop = Property::new()
-        .with_utf8_name("target")?
+        .with_utf8_name(name)?
         .with_getter_closure(getter)
         .with_property_attributes(PropertyAttributes::Configurable);
     event.define_properties(&[prop])?;
@@ -339,6 +536,13 @@ fn serialize_event(event: &DomEvent) -> EventPayload {
         key: key_from(&event.data),
         input: input_from(&event.data),
         ime: ime_from(&event.data),
+        clipboard: None,
+        drag: None,
+        window: None,
+        touch: None,
+        gesture: None,
+        related_target: None,
+        time_stamp: now_ms(),
     }
 }
 
@@ -363,16 +567,7 @@ fn pointer_from(data: &DomEventData) -> Option<PointerData> {
         | DomEventData::DoubleClick(p) => p,
         _ => return None,
     };
-    let (kind, pointer_id) = match p.id {
-        BlitzPointerId::Mouse => ("mouse", 1.0),
-        BlitzPointerId::Pen => ("pen", 1.0),
-        BlitzPointerId::Finger(id) => ("finger", id as f64),
-    };
-    Some(PointerData {
-        inner: std::sync::Arc::new(p.clone()),
-        kind: kind.to_string(),
-        pointer_id,
-    })
+    Some(PointerData::new(p.clone()))
 }
 
 fn wheel_from(data: &DomEventData) -> Option<WheelData> {
@@ -384,7 +579,7 @@ fn wheel_from(data: &DomEventData) -> Option<WheelData> {
         BlitzWheelDelta::Pixels(x, y) => ("pixels", x, y),
     };
     Some(WheelData {
-        inner: std::sync::Arc::new(w.clone()),
+        inner: Arc::new(w.clone()),
         mode: mode.to_string(),
         delta_x,
         delta_y,
@@ -401,7 +596,7 @@ fn key_from(data: &DomEventData) -> Option<KeyData> {
         KeyState::Released => "released",
     };
     Some(KeyData {
-        inner: std::sync::Arc::new(k.clone()),
+        inner: Arc::new(k.clone()),
         state: state.to_string(),
     })
 }
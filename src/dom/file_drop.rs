@@ -0,0 +1,121 @@
+//! OS file drag-and-drop and the files of `<input type=file>`.
+//!
+//! winit reports a drag of files from another application as enter /
+//! move / drop / leave on the window. `AppHandler` converts the position
+//! to CSS pixels and hands each step to `handle_file_drag`, which drives
+//! a `DragSession` (`dom/drag.rs`) carrying the paths. On top of the
+//! shared drag model, `<input type=file>` accepts dropped files without a
+//! listener, and an uncanceled `drop` on one stores the paths as its
+//! files and fires `input` and `change`.
+
+use std::{collections::HashMap, rc::Rc};
+
+use blitz::dom::{BaseDocument, NodeId, local_name};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc,
+    drag::{DragSession, finish, update_target},
+    event::dispatch_synthetic,
+    payload::EventPayload,
+};
+
+/// One step of an OS file drag, positions in viewport CSS pixels.
+pub(crate) enum FileDragEvent {
+    Enter { paths: Vec<String>, x: f64, y: f64 },
+    Move { x: f64, y: f64 },
+    Drop { paths: Vec<String>, x: f64, y: f64 },
+    Leave,
+}
+
+/// Files selected in `<input type=file>` elements, keyed by node.
+#[derive(Default)]
+pub struct FileInputs {
+    files: HashMap<NodeId, Vec<String>>,
+}
+
+impl FileInputs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, node_id: NodeId) -> Vec<String> {
+        self.files.get(&node_id).cloned().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, node_id: NodeId, paths: Vec<String>) {
+        if paths.is_empty() {
+            self.files.remove(&node_id);
+        } else {
+            self.files.insert(node_id, paths);
+        }
+    }
+
+    /// Forget the files of `node_id` and its descendants before they are
+    /// dropped from the tree, whose ids blitz hands out again.
+    pub fn forget_subtree(&mut self, base: &BaseDocument, node_id: NodeId) {
+        self.files.retain(|&id, _| {
+            let mut node = base.get_node(id);
+            while let Some(n) = node {
+                if n.id == node_id {
+                    return false;
+                }
+                node = n.parent.and_then(|parent| base.get_node(parent));
+            }
+            true
+        });
+    }
+}
+
+/// Run one step of an OS file drag over `doc`.
+pub(crate) fn handle_file_drag(doc: &Rc<SharedDoc>, event: FileDragEvent, env: &Env) -> Result<()> {
+    match event {
+        FileDragEvent::Enter { paths, x, y } => {
+            *doc.drag.borrow_mut() = Some(DragSession::files(paths, x, y));
+            update_target(doc, x, y, env)
+        }
+        FileDragEvent::Move { x, y } => update_target(doc, x, y, env),
+        FileDragEvent::Drop { paths, x, y } => {
+            if let Some(session) = doc.drag.borrow_mut().as_mut() {
+                session.set_files(paths);
+            }
+            update_target(doc, x, y, env)?;
+            finish(doc, true, env)
+        }
+        FileDragEvent::Leave => finish(doc, false, env),
+    }
+}
+
+/// An enabled `<input type=file>`.
+pub(crate) fn is_file_input(base: &BaseDocument, node_id: NodeId) -> bool {
+    base.get_node(node_id).is_some_and(|node| {
+        node.element_data()
+            .is_some_and(|el| el.name.local == local_name!("input"))
+            && node
+                .attr(local_name!("type"))
+                .is_some_and(|t| t.eq_ignore_ascii_case("file"))
+            && node.attr(local_name!("disabled")).is_none()
+    })
+}
+
+/// Select `paths` in a file input, firing `input` then `change`. A
+/// single-file input (no `multiple`) keeps only the first path.
+pub(crate) fn set_input_files(
+    doc: &Rc<SharedDoc>,
+    node_id: NodeId,
+    mut paths: Vec<String>,
+    env: &Env,
+) -> Result<()> {
+    let multiple = doc
+        .base
+        .borrow()
+        .get_node(node_id)
+        .is_some_and(|n| n.attr(local_name!("multiple")).is_some());
+    if !multiple {
+        paths.truncate(1);
+    }
+    doc.file_inputs.borrow_mut().set(node_id, paths);
+    dispatch_synthetic(doc, node_id, EventPayload::new("input", true, false), env)?;
+    dispatch_synthetic(doc, node_id, EventPayload::new("change", true, false), env)?;
+    Ok(())
+}
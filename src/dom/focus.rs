@@ -0,0 +1,138 @@
+//! Focus events and sequential (Tab) focus navigation.
+//!
+//! blitz keeps the focused node in `BaseDocument` and changes it from
+//! several places: its own default actions for pointer clicks, our Tab
+//! handling in `JsEventHandler`, and `NativeNode::focus` / `blur`. Rather
+//! than hooking each of them, `SharedDoc` remembers the focus last
+//! reported to JS and `sync_focus` diffs against it after each of those
+//! entry points, firing `blur` → `focusout` → `focus` → `focusin` with
+//! `relatedTarget` set to the other side of the transition.
+
+use std::rc::Rc;
+
+use blitz::dom::{BaseDocument, Node, NodeId, local_name};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc, event::dispatch_synthetic, ops::dfs_collect, payload::EventPayload,
+};
+
+/// Dispatch focus events for any change of the focused node since the
+/// last call. Cheap when nothing changed.
+pub(crate) fn sync_focus(doc: &Rc<SharedDoc>, env: &Env) -> Result<()> {
+    let current = doc.base.borrow().get_focussed_node_id();
+    let previous = doc.reported_focus.replace(current);
+    if previous == current {
+        return Ok(());
+    }
+    // A node removed while focused gets no blur, and is not exposed as
+    // `relatedTarget` either.
+    let previous = previous.filter(|&id| doc.is_in_document(id));
+
+    if let Some(old) = previous {
+        dispatch_synthetic(doc, old, focus_payload("blur", false, current), env)?;
+        dispatch_synthetic(doc, old, focus_payload("focusout", true, current), env)?;
+    }
+    if let Some(new) = current {
+        // A blur/focusout listener may have moved focus elsewhere; that
+        // nested `sync_focus` already reported the newer state.
+        if doc.reported_focus.get() != Some(new) {
+            return Ok(());
+        }
+        dispatch_synthetic(doc, new, focus_payload("focus", false, previous), env)?;
+        dispatch_synthetic(doc, new, focus_payload("focusin", true, previous), env)?;
+    }
+    Ok(())
+}
+
+fn focus_payload(event_type: &str, bubbles: bool, related: Option<NodeId>) -> EventPayload {
+    EventPayload {
+        related_target: related,
+        ..EventPayload::new(event_type, bubbles, false)
+    }
+}
+
+// ── Focusability ──────────────────────────────────────────────────────
+
+/// Parsed `tabindex` attribute. Invalid values count as absent, as in
+/// browsers.
+fn tab_index(node: &Node) -> Option<i32> {
+    node.attr(local_name!("tabindex"))
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Whether the element is focusable without a `tabindex`: links with an
+/// `href`, and form controls that are not disabled.
+fn is_natively_focusable(node: &Node) -> bool {
+    let Some(el) = node.element_data() else {
+        return false;
+    };
+    let disabled = node.attr(local_name!("disabled")).is_some();
+    match el.name.local.as_ref() {
+        "a" | "area" => node.attr(local_name!("href")).is_some(),
+        "input" => !disabled && node.attr(local_name!("type")) != Some("hidden"),
+        "button" | "select" | "textarea" => !disabled,
+        _ => false,
+    }
+}
+
+/// Whether `node` can receive focus at all (`focus()`, clicks). Elements
+/// with a negative `tabindex` qualify here but are skipped by Tab.
+pub(crate) fn is_focusable(node: &Node) -> bool {
+    node.is_element() && (tab_index(node).is_some() || is_natively_focusable(node))
+}
+
+/// Position in the sequential navigation order, or `None` when Tab skips
+/// the node. Positive indices come first in ascending order, then
+/// everything at 0 in tree order.
+fn sequential_index(node: &Node) -> Option<i32> {
+    if !node.flags.is_in_document() || !is_rendered(node) {
+        return None;
+    }
+    match tab_index(node) {
+        Some(i) if i < 0 => None,
+        Some(i) => Some(i),
+        None => is_natively_focusable(node).then_some(0),
+    }
+}
+
+/// `display: none` subtrees are never styled, so a missing primary style
+/// covers hidden ancestors as well.
+fn is_rendered(node: &Node) -> bool {
+    node.primary_styles()
+        .is_some_and(|styles| !styles.get_box().clone_display().is_none())
+}
+
+/// Move focus to the next (or previous) element in the sequential
+/// navigation order, wrapping around at either end. Returns the newly
+/// focused node, or `None` when the document has nothing tabbable.
+pub(crate) fn focus_sequential(base: &mut BaseDocument, forward: bool) -> Option<NodeId> {
+    let mut order: Vec<(i32, NodeId)> =
+        dfs_collect(base, base.root_node().id, |n| sequential_index(n).is_some())
+            .into_iter()
+            .filter_map(|id| {
+                base.get_node(id)
+                    .and_then(sequential_index)
+                    .map(|i| (i, id))
+            })
+            .collect();
+    // Stable sort keeps tree order within equal indices; 0 sorts last.
+    order.sort_by_key(|&(i, _)| if i == 0 { i32::MAX } else { i });
+    if order.is_empty() {
+        return None;
+    }
+
+    let current = base
+        .get_focussed_node_id()
+        .and_then(|id| order.iter().position(|&(_, n)| n == id));
+    let len = order.len();
+    let next = match (current, forward) {
+        (Some(pos), true) => (pos + 1) % len,
+        (Some(pos), false) => (pos + len - 1) % len,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+    let target = order[next].1;
+    base.set_focus_to(target);
+    Some(target)
+}
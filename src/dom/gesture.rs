@@ -0,0 +1,141 @@
+//! Trackpad gestures: winit's pinch, rotation and pan gestures as
+//! `gesturestart` / `gesturechange` / `gestureend` events.
+//!
+//! The events target the element under the pointer when the gesture
+//! starts and carry the values accumulated since then (`scale`,
+//! `rotation`, `deltaX` / `deltaY`), with `kind` telling the gestures
+//! apart. Only `gesturechange` is cancelable; canceling a pinch stops it
+//! from zooming a window that has `setPinchZoom(true)`.
+
+use std::rc::Rc;
+
+use blitz::dom::{BaseDocument, NodeId};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::dispatch_synthetic,
+    ops::{body_or_root, hovered_element},
+    payload::{EventPayload, GestureData},
+};
+
+/// One step of a gesture, translated from winit by `AppHandler`.
+pub(crate) enum GestureDelta {
+    /// Change of the pinch scale; `0.1` is 10% larger.
+    Pinch(f64),
+    /// Rotation in degrees, counterclockwise positive.
+    Rotate(f64),
+    /// Pan distance in CSS pixels.
+    Pan(f64, f64),
+}
+
+impl GestureDelta {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Pinch(_) => "pinch",
+            Self::Rotate(_) => "rotate",
+            Self::Pan(..) => "pan",
+        }
+    }
+}
+
+pub(crate) enum GesturePhase {
+    Start,
+    Change,
+    End,
+}
+
+struct ActiveGesture {
+    kind: &'static str,
+    target: NodeId,
+    scale: f64,
+    rotation: f64,
+    delta_x: f64,
+    delta_y: f64,
+}
+
+impl ActiveGesture {
+    fn new(kind: &'static str, base: &BaseDocument) -> Self {
+        Self {
+            kind,
+            target: hovered_element(base).unwrap_or_else(|| body_or_root(base)),
+            scale: 1.0,
+            rotation: 0.0,
+            delta_x: 0.0,
+            delta_y: 0.0,
+        }
+    }
+
+    fn data(&self) -> GestureData {
+        GestureData {
+            kind: self.kind.to_string(),
+            scale: self.scale,
+            rotation: self.rotation,
+            delta_x: self.delta_x,
+            delta_y: self.delta_y,
+        }
+    }
+}
+
+/// Gestures in progress, at most one per kind.
+#[derive(Default)]
+pub struct Gestures(Vec<ActiveGesture>);
+
+impl Gestures {
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Fire the gesture event for one winit gesture step. Returns whether a
+/// listener canceled it.
+pub(crate) fn handle_gesture(
+    doc: &Rc<SharedDoc>,
+    phase: GesturePhase,
+    delta: GestureDelta,
+    env: &Env,
+) -> Result<bool> {
+    let kind = delta.kind();
+    let (event_type, target, data) = {
+        let mut gestures = doc.gestures.borrow_mut();
+        let active = &mut gestures.0;
+        let index = active.iter().position(|g| g.kind == kind);
+        // A step without a start (e.g. the start went to another window)
+        // starts the gesture.
+        let (event_type, index) = match (phase, index) {
+            (GesturePhase::Start, Some(index)) => {
+                active[index] = ActiveGesture::new(kind, &doc.base.borrow());
+                ("gesturestart", index)
+            }
+            (GesturePhase::Start | GesturePhase::Change, None) => {
+                active.push(ActiveGesture::new(kind, &doc.base.borrow()));
+                ("gesturestart", active.len() - 1)
+            }
+            (GesturePhase::Change, Some(index)) => ("gesturechange", index),
+            (GesturePhase::End, Some(index)) => ("gestureend", index),
+            (GesturePhase::End, None) => return Ok(false),
+        };
+        let gesture = &mut active[index];
+        match delta {
+            GestureDelta::Pinch(delta) => gesture.scale *= 1.0 + delta,
+            GestureDelta::Rotate(delta) => gesture.rotation += delta,
+            GestureDelta::Pan(x, y) => {
+                gesture.delta_x += x;
+                gesture.delta_y += y;
+            }
+        }
+        let data = gesture.data();
+        let target = gesture.target;
+        if event_type == "gestureend" {
+            active.remove(index);
+        }
+        (event_type, target, data)
+    };
+
+    let payload = EventPayload {
+        gesture: Some(data),
+        ..EventPayload::new(event_type, true, event_type == "gesturechange")
+    };
+    let outcome = dispatch_synthetic(doc, target, payload, env)?;
+    Ok(outcome.default_prevented)
+}
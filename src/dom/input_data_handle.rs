@@ -4,7 +4,9 @@
 //! Created in `wrap_node` when the element tag is "input" or "textarea" and
 //! passed as the third constructor argument. The JS subclass stores this
 //! reference and forwards only the properties that need native-side access
-//! (value syncs with the parley editor; checked syncs with special_data).
+//! (value syncs with the parley editor; checked syncs with special_data;
+//! files are kept in `SharedDoc::file_inputs`; the selection APIs drive
+//! the parley editor's selection).
 //!
 //! Pure attribute-backed properties (type, disabled, placeholder, readOnly,
 //! required, name, rows, cols) are handled in JS via the inherited
@@ -13,9 +15,13 @@
 use std::rc::Rc;
 
 use blitz::dom::{LocalName, NodeId};
+use napi::{Env, Error, Result, Status};
 
 use crate::dom::doc::SharedDoc;
 use crate::dom::ops::{make_qual_name, remove_detached_attribute, set_detached_attribute};
+use crate::dom::text_selection::{
+    byte_to_utf16, editor_text, selection_bytes, sync_selection, utf16_to_byte,
+};
 
 #[napi]
 pub struct InputDataHandle {
@@ -38,26 +44,27 @@ impl InputDataHandle {
     /// If a live `TextInputData` exists (created during layout), returns
     /// the editor's text. Otherwise falls back to the `value` attribute.
     #[napi(getter)]
-    pub fn value(&self) -> String {
-        let base = self.doc.base.borrow();
+    pub fn value(&self) -> Result<String> {
+        let base = self.doc.read()?;
         let Some(node) = base.get_node(self.node_id) else {
-            return String::new();
+            return Ok(String::new());
         };
         if let Some(el) = node.element_data()
             && let Some(ti) = el.text_input_data()
         {
-            return ti.editor.text().to_string();
+            return Ok(ti.editor.text().to_string());
         }
-        node.attr(LocalName::from("value"))
+        Ok(node
+            .attr(LocalName::from("value"))
             .map(|s| s.to_string())
-            .unwrap_or_default()
+            .unwrap_or_default())
     }
 
     #[napi(setter)]
-    pub fn set_value(&mut self, value: String) {
+    pub fn set_value(&mut self, value: String) -> Result<()> {
         // 1. Update the value attribute.
         let qual = make_qual_name("value", None);
-        let mut base = self.doc.base.borrow_mut();
+        let mut base = self.doc.write()?;
         if set_detached_attribute(&mut base, self.node_id, qual, &value) {
             drop(base);
         } else {
@@ -74,6 +81,7 @@ impl InputDataHandle {
             driver.editor.set_text(&value);
             driver.refresh_layout();
         });
+        Ok(())
     }
 
     // ---- checked --------------------------------------------------------
@@ -83,25 +91,25 @@ impl InputDataHandle {
     /// Reads from `CheckboxInput` special data if present, otherwise
     /// falls back to the `checked` attribute.
     #[napi(getter)]
-    pub fn checked(&self) -> bool {
-        let base = self.doc.base.borrow();
+    pub fn checked(&self) -> Result<bool> {
+        let base = self.doc.read()?;
         let Some(node) = base.get_node(self.node_id) else {
-            return false;
+            return Ok(false);
         };
         let Some(el) = node.element_data() else {
-            return false;
+            return Ok(false);
         };
-        match &el.special_data {
+        Ok(match &el.special_data {
             blitz::dom::node::SpecialElementData::CheckboxInput(c) => *c,
             _ => node.attr(LocalName::from("checked")).is_some(),
-        }
+        })
     }
 
     #[napi(setter)]
-    pub fn set_checked(&mut self, checked: bool) {
+    pub fn set_checked(&mut self, checked: bool) -> Result<()> {
         // 1. Update special_data if it's a checkbox.
         {
-            let mut base = self.doc.base.borrow_mut();
+            let mut base = self.doc.write()?;
             if let Some(node) = base.get_node_mut(self.node_id)
                 && let Some(el) = node.element_data_mut()
                 && matches!(
@@ -126,14 +134,203 @@ impl InputDataHandle {
         }
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
+    }
+
+    // ---- files ----------------------------------------------------------
+
+    /// Paths selected in an `<input type=file>`, e.g. by dropping files
+    /// onto it. Empty for other inputs.
+    #[napi(getter)]
+    pub fn files(&self) -> Result<Vec<String>> {
+        self.doc.check_live()?;
+        Ok(self.doc.file_inputs.borrow().get(self.node_id))
+    }
+
+    /// Replace the selection. Only clearing (`[]`) is allowed from script,
+    /// as in browsers.
+    #[napi(setter)]
+    pub fn set_files(&mut self, files: Vec<String>) -> Result<()> {
+        self.doc.check_live()?;
+        if !files.is_empty() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "files can only be cleared from script".to_string(),
+            ));
+        }
+        self.doc.file_inputs.borrow_mut().set(self.node_id, files);
+        Ok(())
     }
 
     // ---- focus ----------------------------------------------------------
 
     /// Whether this input currently has focus.
     #[napi(getter)]
-    pub fn focused(&self) -> bool {
-        let base = self.doc.base.borrow();
-        base.get_focussed_node_id() == Some(self.node_id)
+    pub fn focused(&self) -> Result<bool> {
+        let base = self.doc.read()?;
+        Ok(base.get_focussed_node_id() == Some(self.node_id))
+    }
+
+    // ---- selection ------------------------------------------------------
+    //
+    // Offsets are UTF-16 code units, as in the DOM. All of these need the
+    // live parley editor, which blitz creates during layout: before the
+    // first `resolve()` (or for non-text inputs) the getters return null
+    // and the setters are no-ops.
+
+    /// Start of the selection (or the caret position).
+    #[napi(getter)]
+    pub fn selection_start(&self) -> Result<Option<u32>> {
+        Ok(self.selection_utf16()?.map(|(start, _, _)| start))
+    }
+
+    /// End of the selection (equal to `selectionStart` for a caret).
+    #[napi(getter)]
+    pub fn selection_end(&self) -> Result<Option<u32>> {
+        Ok(self.selection_utf16()?.map(|(_, end, _)| end))
+    }
+
+    /// "forward" | "backward"
+    #[napi(getter)]
+    pub fn selection_direction(&self) -> Result<Option<String>> {
+        Ok(self
+            .selection_utf16()?
+            .map(|(_, _, backward)| if backward { "backward" } else { "forward" }.to_string()))
+    }
+
+    /// Mirrors `HTMLInputElement.setSelectionRange`. Offsets are clamped
+    /// to the text length and `start` to `end`. Fires `selectionchange`
+    /// (and `select` for a non-empty range) if the selection changed.
+    #[napi]
+    pub fn set_selection_range(
+        &mut self,
+        env: Env,
+        start: u32,
+        end: u32,
+        direction: Option<String>,
+    ) -> Result<()> {
+        {
+            let mut base = self.doc.write()?;
+            let Some(text) = editor_text(&base, self.node_id) else {
+                return Ok(());
+            };
+            let end = utf16_to_byte(&text, end);
+            let start = utf16_to_byte(&text, start).min(end);
+            let (anchor, focus) = match direction.as_deref() {
+                Some("backward") => (end, start),
+                _ => (start, end),
+            };
+            base.with_text_input(self.node_id, |mut driver| {
+                driver.select_byte_range(anchor, focus);
+            });
+        }
+        self.doc.mark_host_dirty();
+        sync_selection(&self.doc, self.node_id, &env)
+    }
+
+    /// Mirrors `HTMLInputElement.select`: select the whole text.
+    #[napi]
+    pub fn select(&mut self, env: Env) -> Result<()> {
+        self.doc
+            .write()?
+            .with_text_input(self.node_id, |mut driver| driver.select_all());
+        self.doc.mark_host_dirty();
+        sync_selection(&self.doc, self.node_id, &env)
+    }
+
+    /// Mirrors `HTMLInputElement.setRangeText`. Replaces `start..end`
+    /// (default: the current selection) with `replacement`, then places
+    /// the selection according to `select_mode`: "select" | "start" |
+    /// "end" | "preserve" (default).
+    #[napi]
+    pub fn set_range_text(
+        &mut self,
+        env: Env,
+        replacement: String,
+        start: Option<u32>,
+        end: Option<u32>,
+        select_mode: Option<String>,
+    ) -> Result<()> {
+        let mode = select_mode.as_deref().unwrap_or("preserve");
+        if !matches!(mode, "select" | "start" | "end" | "preserve") {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("setRangeText: invalid selectMode {mode:?}"),
+            ));
+        }
+        {
+            let mut base = self.doc.write()?;
+            let (Some(text), Some((anchor, focus))) = (
+                editor_text(&base, self.node_id),
+                selection_bytes(&base, self.node_id),
+            ) else {
+                return Ok(());
+            };
+            let (start, end) = match (start, end) {
+                (None, None) => (anchor.min(focus), anchor.max(focus)),
+                (Some(start), Some(end)) if start <= end => {
+                    (utf16_to_byte(&text, start), utf16_to_byte(&text, end))
+                }
+                (Some(_), Some(_)) => {
+                    return Err(Error::new(
+                        Status::InvalidArg,
+                        "setRangeText: start is greater than end",
+                    ));
+                }
+                _ => {
+                    return Err(Error::new(
+                        Status::InvalidArg,
+                        "setRangeText: start and end must be given together",
+                    ));
+                }
+            };
+
+            // New selection, per the HTML spec's "set up the new selection"
+            // steps. `preserve` shifts offsets behind the replaced range and
+            // snaps offsets inside it to its edges.
+            let new_end = start + replacement.len();
+            let shift = |offset: usize, inside: usize| {
+                if offset > end {
+                    offset - end + new_end
+                } else if offset > start {
+                    inside
+                } else {
+                    offset
+                }
+            };
+            let (new_anchor, new_focus) = match mode {
+                "select" => (start, new_end),
+                "start" => (start, start),
+                "end" => (new_end, new_end),
+                _ if anchor <= focus => (shift(anchor, start), shift(focus, new_end)),
+                _ => (shift(anchor, new_end), shift(focus, start)),
+            };
+
+            base.with_text_input(self.node_id, |mut driver| {
+                driver.select_byte_range(start, end);
+                driver.insert_or_replace_selection(&replacement);
+                driver.select_byte_range(new_anchor, new_focus);
+            });
+        }
+        self.doc.mark_host_dirty();
+        sync_selection(&self.doc, self.node_id, &env)
+    }
+}
+
+impl InputDataHandle {
+    /// `(start, end, backward)` in UTF-16 code units.
+    fn selection_utf16(&self) -> Result<Option<(u32, u32, bool)>> {
+        let base = self.doc.read()?;
+        let (Some(text), Some((anchor, focus))) = (
+            editor_text(&base, self.node_id),
+            selection_bytes(&base, self.node_id),
+        ) else {
+            return Ok(None);
+        };
+        Ok(Some((
+            byte_to_utf16(&text, anchor.min(focus)),
+            byte_to_utf16(&text, anchor.max(focus)),
+            focus < anchor,
+        )))
     }
 }
@@ -0,0 +1,168 @@
+//! Which event types have JS listeners, so dispatch can skip the rest.
+//!
+//! JS `addEventListener` / `removeEventListener` on a `Node` report each
+//! distinct registration here (`NativeNode.addListener`, or
+//! `NativeDoc.addListener` for the document itself); the `Window` reports
+//! to `NativeDoc.addWindowListener`. `dispatch_payload` then builds no JS
+//! event at all when nothing on the path listens for the type, and wraps
+//! only the nodes that do.
+//!
+//! Listeners live on the JS wrapper, so the counts of a node are dropped
+//! with its wrapper: when `NodeCache` sweeps a collected entry or replaces
+//! it with a new wrapper. Counts may overstate (a `once` listener that
+//! fired, an aborted `signal`), which only costs an unneeded dispatch.
+
+use std::collections::HashMap;
+
+use blitz::dom::NodeId;
+use napi::Result;
+
+use crate::dom::{doc::NativeDoc, node_handle::NativeNode};
+
+/// Registration counts by event type.
+#[derive(Default)]
+pub struct TypeCounts(HashMap<String, u32>);
+
+impl TypeCounts {
+    pub fn add(&mut self, event_type: &str) {
+        *self.0.entry(event_type.to_string()).or_default() += 1;
+    }
+
+    pub fn remove(&mut self, event_type: &str) {
+        self.remove_n(event_type, 1);
+    }
+
+    fn remove_n(&mut self, event_type: &str, n: u32) {
+        if let Some(count) = self.0.get_mut(event_type) {
+            *count = count.saturating_sub(n);
+            if *count == 0 {
+                self.0.remove(event_type);
+            }
+        }
+    }
+
+    pub fn has(&self, event_type: &str) -> bool {
+        self.0.contains_key(event_type)
+    }
+}
+
+/// Listener counts of the nodes of one document.
+#[derive(Default)]
+pub struct EventListeners {
+    by_node: HashMap<NodeId, TypeCounts>,
+    /// Sum over all nodes, for the common "nobody listens" check.
+    total: TypeCounts,
+}
+
+impl EventListeners {
+    pub fn add(&mut self, node_id: NodeId, event_type: &str) {
+        self.by_node.entry(node_id).or_default().add(event_type);
+        self.total.add(event_type);
+    }
+
+    pub fn remove(&mut self, node_id: NodeId, event_type: &str) {
+        let Some(counts) = self.by_node.get_mut(&node_id) else {
+            return;
+        };
+        if !counts.has(event_type) {
+            return;
+        }
+        counts.remove(event_type);
+        self.total.remove(event_type);
+        if counts.0.is_empty() {
+            self.by_node.remove(&node_id);
+        }
+    }
+
+    /// Drop the counts of a node whose wrapper is gone.
+    pub fn forget(&mut self, node_id: NodeId) {
+        let Some(counts) = self.by_node.remove(&node_id) else {
+            return;
+        };
+        for (event_type, count) in counts.0 {
+            self.total.remove_n(&event_type, count);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.by_node.clear();
+        self.total = TypeCounts::default();
+    }
+
+    /// Whether `node_id` has a listener for `event_type`.
+    pub fn listens(&self, node_id: NodeId, event_type: &str) -> bool {
+        self.total.has(event_type)
+            && self
+                .by_node
+                .get(&node_id)
+                .is_some_and(|counts| counts.has(event_type))
+    }
+}
+
+#[napi]
+impl NativeNode {
+    /// Record a new listener registration of `eventType` on this node.
+    #[napi]
+    pub fn add_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        let mut cache = self.doc.node_cache.borrow_mut();
+        cache.listeners.add(self.node_id, &event_type);
+        Ok(())
+    }
+
+    /// Record the removal of a registration reported by `addListener`.
+    #[napi]
+    pub fn remove_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        let mut cache = self.doc.node_cache.borrow_mut();
+        cache.listeners.remove(self.node_id, &event_type);
+        Ok(())
+    }
+}
+
+#[napi]
+impl NativeDoc {
+    /// `NativeNode.addListener` for the document node.
+    #[napi]
+    pub fn add_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        let root = self.doc.base.borrow().root_node().id;
+        self.doc
+            .node_cache
+            .borrow_mut()
+            .listeners
+            .add(root, &event_type);
+        Ok(())
+    }
+
+    /// `NativeNode.removeListener` for the document node.
+    #[napi]
+    pub fn remove_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        let root = self.doc.base.borrow().root_node().id;
+        self.doc
+            .node_cache
+            .borrow_mut()
+            .listeners
+            .remove(root, &event_type);
+        Ok(())
+    }
+
+    /// Record a listener registration of `eventType` on the JS `Window`
+    /// showing this document, which receives forwarded pointer events.
+    #[napi]
+    pub fn add_window_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        self.doc.window_listeners.borrow_mut().add(&event_type);
+        Ok(())
+    }
+
+    /// Record the removal of a registration reported by
+    /// `addWindowListener`.
+    #[napi]
+    pub fn remove_window_listener(&self, event_type: String) -> Result<()> {
+        self.doc.check_live()?;
+        self.doc.window_listeners.borrow_mut().remove(&event_type);
+        Ok(())
+    }
+}
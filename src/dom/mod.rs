@@ -1,7 +1,21 @@
+pub(crate) mod clipboard_event;
 pub(crate) mod doc;
+pub(crate) mod drag;
 pub(crate) mod event;
+pub(crate) mod file_drop;
+pub(crate) mod focus;
+pub(crate) mod gesture;
 pub(crate) mod input_data_handle;
+pub(crate) mod listeners;
 pub(crate) mod node_cache;
 pub(crate) mod node_handle;
 pub(crate) mod ops;
 pub(crate) mod payload;
+pub(crate) mod pointer_capture;
+pub(crate) mod pointer_info;
+pub(crate) mod pointer_moves;
+pub(crate) mod scroll;
+pub(crate) mod selection;
+pub(crate) mod stats;
+pub(crate) mod text_selection;
+pub(crate) mod touch;
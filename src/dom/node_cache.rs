@@ -12,29 +12,51 @@
 //!
 //! A finalizer is attached to each cached JS object. When V8 collects it
 //! (only possible while in weak mode), the finalizer fires and:
-//!   1. Removes the entry from the NodeCache (by `node_id`).
+//!   1. Queues the `node_id` for removal from the NodeCache.
 //!   2. Calls `remove_and_drop_node` on the blitz document to reclaim the
-//!      Rust-side node storage.
-
-use std::{collections::HashMap, rc::Weak};
+//!      Rust-side node storage, forgetting the files of dropped file inputs
+//!      first since blitz reuses the ids.
+//!
+//! ## Sweeping
+//!
+//! Queued entries are removed by `sweep`, a few at a time: at the start of
+//! each input event and before each insert, so the work is proportional to
+//! the number of collected wrappers rather than to the cache size. `sweep`
+//! re-checks each entry, because the node may have been wrapped again
+//! between the collection and the sweep; such an entry is kept.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    rc::Weak,
+};
 
 use blitz::dom::{BaseDocument, NodeId};
 use napi::{Env, Result, bindgen_prelude::Object};
 
 use crate::{
-    dom::doc::SharedDoc,
+    dom::{doc::SharedDoc, file_drop::FileInputs, listeners::EventListeners},
     helpers::{Finalize, SwitchableRef},
 };
 
+/// Most queued entries a single `sweep` removes.
+pub const SWEEP_BUDGET: usize = 64;
+
 /// Switchable-reference cache: `blitz_node_id -> SwitchableRef`.
 pub struct NodeCache {
     entries: HashMap<NodeId, SwitchableRef>,
+    /// Node ids whose JS object was collected, oldest first.
+    collected: VecDeque<NodeId>,
+    /// Event types the cached wrappers (and the document) listen for.
+    /// Dropped per node together with its entry.
+    pub listeners: EventListeners,
 }
 
 impl NodeCache {
     pub fn new() -> Self {
         Self {
             entries: HashMap::new(),
+            collected: VecDeque::new(),
+            listeners: EventListeners::default(),
         }
     }
 
@@ -59,15 +81,22 @@ impl NodeCache {
         strong: bool,
         doc_weak: Weak<SharedDoc>,
     ) -> Result<()> {
+        self.sweep(env, SWEEP_BUDGET);
         let sref = SwitchableRef::new(obj, env, strong)?;
         sref.add_finalizer(env, NodeFinalizer { node_id, doc_weak })?;
-        self.entries.insert(node_id, sref);
+        // A replaced entry belonged to a collected wrapper, and its
+        // listeners went with it.
+        if self.entries.insert(node_id, sref).is_some() {
+            self.listeners.forget(node_id);
+        }
         Ok(())
     }
 
-    /// Explicitly remove a cache entry and delete the underlying `napi_ref`.
-    pub fn remove(&mut self, node_id: NodeId) {
-        self.entries.remove(&node_id);
+    /// Drop every entry, releasing all cached JS objects to the GC.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.collected.clear();
+        self.listeners.clear();
     }
 
     /// Switch a cache entry to strong (refcount 1). No-op if already strong
@@ -88,28 +117,42 @@ impl NodeCache {
         Ok(())
     }
 
-    /// Remove all entries whose reference is dead (JS object collected).
-    pub fn sweep(&mut self, env: &Env) {
-        let stale: Vec<NodeId> = self
-            .entries
-            .iter()
-            .filter_map(|(&id, sref)| (!sref.is_alive(env)).then_some(id))
-            .collect();
-        for id in stale {
-            self.entries.remove(&id);
+    /// Queue the entry of a collected JS object for `sweep`.
+    fn mark_collected(&mut self, node_id: NodeId) {
+        self.collected.push_back(node_id);
+    }
+
+    /// Remove up to `budget` queued entries, oldest first. Entries that are
+    /// alive again (re-wrapped since) stay.
+    pub fn sweep(&mut self, env: &Env, budget: usize) {
+        let count = self.collected.len().min(budget);
+        for id in self.collected.drain(..count) {
+            if self
+                .entries
+                .get(&id)
+                .is_some_and(|sref| !sref.is_alive(env))
+            {
+                self.entries.remove(&id);
+                self.listeners.forget(id);
+            }
         }
     }
 
     /// Number of entries currently in the cache.
-    #[allow(unused)]
     pub fn len(&self) -> usize {
         self.entries.len()
     }
 
-    /// Whether the cache is empty.
-    #[allow(unused)]
-    pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+    /// Number of queued entries `sweep` has not reached yet.
+    pub fn collected_len(&self) -> usize {
+        self.collected.len()
+    }
+
+    /// Every cached node id with whether its reference is strong.
+    pub fn entries(&self) -> impl Iterator<Item = (NodeId, bool)> + '_ {
+        self.entries
+            .iter()
+            .map(|(&id, sref)| (id, sref.is_strong()))
     }
 
     fn is_alive(&self, node_id: NodeId, env: &Env) -> bool {
@@ -150,8 +193,12 @@ impl Finalize for NodeFinalizer {
         };
 
         let doc = &*doc_rc;
+        // `dispose` already cleared the cache and dropped the tree.
+        if doc.is_disposed() {
+            return;
+        }
 
-        doc.node_cache.borrow_mut().remove(self.node_id);
+        doc.node_cache.borrow_mut().mark_collected(self.node_id);
 
         let mut doc_mut = doc.base.borrow_mut();
 
@@ -185,21 +232,31 @@ impl Finalize for NodeFinalizer {
                     );
                     print!("{}", node_tree);
                 }
+                doc.file_inputs
+                    .borrow_mut()
+                    .forget_subtree(&doc_mut, self.node_id);
                 doc_mut.mutate().remove_and_drop_node(self.node_id);
                 return;
             }
         }
 
-        cleanup_detached_subtree(&mut doc_mut, &doc.node_cache.borrow(), self.node_id, &env);
+        cleanup_detached_subtree(
+            &mut doc_mut,
+            &doc.node_cache.borrow(),
+            &mut doc.file_inputs.borrow_mut(),
+            self.node_id,
+            &env,
+        );
     }
 }
 
 /// From a detached node, walk up to find the topmost ancestor that still
 /// exists in the slab, then check if that subtree has no live JS wrapper.
-/// If so, drop the entire subtree.
+/// If so, drop the entire subtree (and the files of its file inputs).
 pub fn cleanup_detached_subtree(
     doc: &mut BaseDocument,
     cache: &NodeCache,
+    files: &mut FileInputs,
     node_id: NodeId,
     env: &Env,
 ) {
@@ -232,6 +289,7 @@ pub fn cleanup_detached_subtree(
             doc_id, node_id, top
         );
     }
+    files.forget_subtree(doc, top);
     doc.mutate().remove_and_drop_node(top);
 }
 
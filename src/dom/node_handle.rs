@@ -1,13 +1,18 @@
 use blitz::dom::{LocalName, NodeData, NodeId};
-use napi::{Env, Error, Result, bindgen_prelude::Object};
+use napi::{Env, Error, Result, Status, bindgen_prelude::Object};
 use style::properties::PropertyId;
 
 use crate::dom::{
     doc::{SharedDoc, wrap_node},
+    focus::{is_focusable, sync_focus},
     ops::{
         AttrInit, make_qual_name, mark_inline_style_mutated, remove_detached_attribute,
         set_detached_attribute,
     },
+    scroll::{
+        ScrollIntoViewOptions, ScrollTarget, ScrollToOptions, max_offset, offset, scroll_into_view,
+        scroll_to, scroll_with_options,
+    },
 };
 use std::rc::Rc;
 
@@ -32,99 +37,95 @@ impl NativeNode {
 #[napi]
 impl NativeNode {
     #[napi]
-    pub fn node_type(&self) -> u32 {
-        let base = self.doc.base.borrow();
+    pub fn node_type(&self) -> Result<u32> {
+        let base = self.doc.read()?;
         let Some(node) = base.get_node(self.node_id) else {
-            return NODE_TYPE_OTHER;
+            return Ok(NODE_TYPE_OTHER);
         };
-        match &node.data {
+        Ok(match &node.data {
             NodeData::Document(_) => NODE_TYPE_DOCUMENT,
             NodeData::Element(_) => NODE_TYPE_ELEMENT,
             NodeData::Text(_) => NODE_TYPE_TEXT,
             NodeData::Comment { .. } => NODE_TYPE_COMMENT,
             _ => NODE_TYPE_OTHER,
-        }
+        })
     }
 
     #[napi]
-    pub fn parent_node<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+    pub fn parent_node<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
         let parent_id = self
             .doc
-            .base
-            .borrow()
+            .read()?
             .get_node(self.node_id)
-            .and_then(|n| n.parent)?;
-        wrap_node(&self.doc, parent_id, env).ok()
+            .and_then(|n| n.parent);
+        Ok(parent_id.and_then(|id| wrap_node(&self.doc, id, env).ok()))
     }
 
     #[napi]
-    pub fn first_child<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+    pub fn first_child<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
         let child_id = self
             .doc
-            .base
-            .borrow()
+            .read()?
             .get_node(self.node_id)
-            .and_then(|n| n.children.first().copied())?;
-        wrap_node(&self.doc, child_id, env).ok()
+            .and_then(|n| n.children.first().copied());
+        Ok(child_id.and_then(|id| wrap_node(&self.doc, id, env).ok()))
     }
 
     #[napi]
-    pub fn last_child<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+    pub fn last_child<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
         let child_id = self
             .doc
-            .base
-            .borrow()
+            .read()?
             .get_node(self.node_id)
-            .and_then(|n| n.children.last().copied())?;
-        wrap_node(&self.doc, child_id, env).ok()
+            .and_then(|n| n.children.last().copied());
+        Ok(child_id.and_then(|id| wrap_node(&self.doc, id, env).ok()))
     }
 
     #[napi]
-    pub fn next_sibling<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+    pub fn next_sibling<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
         let sibling_id = {
-            let base = self.doc.base.borrow();
+            let base = self.doc.read()?;
             base.get_node(self.node_id)
                 .and_then(|n| n.forward(1))
                 .map(|n| n.id)
-        }?;
-        wrap_node(&self.doc, sibling_id, env).ok()
+        };
+        Ok(sibling_id.and_then(|id| wrap_node(&self.doc, id, env).ok()))
     }
 
     #[napi]
-    pub fn previous_sibling<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+    pub fn previous_sibling<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
         let sibling_id = {
-            let base = self.doc.base.borrow();
+            let base = self.doc.read()?;
             base.get_node(self.node_id)
                 .and_then(|n| n.backward(1))
                 .map(|n| n.id)
-        }?;
-        wrap_node(&self.doc, sibling_id, env).ok()
+        };
+        Ok(sibling_id.and_then(|id| wrap_node(&self.doc, id, env).ok()))
     }
 
     #[napi]
-    pub fn child_nodes<'a>(&self, env: &'a Env) -> Vec<Object<'a>> {
+    pub fn child_nodes<'a>(&self, env: &'a Env) -> Result<Vec<Object<'a>>> {
         let children: Vec<NodeId> = self
             .doc
-            .base
-            .borrow()
+            .read()?
             .get_node(self.node_id)
             .map(|n| n.children.iter().copied().collect())
             .unwrap_or_default();
-        children
+        Ok(children
             .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     #[napi]
-    pub fn text_content(&self) -> Option<String> {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id).map(|n| n.text_content())
+    pub fn text_content(&self) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        Ok(base.get_node(self.node_id).map(|n| n.text_content()))
     }
 
     #[napi]
-    pub fn set_text_content(&mut self, text: String, env: &Env) {
-        let mut base = self.doc.base.borrow_mut();
+    pub fn set_text_content(&mut self, text: String, env: &Env) -> Result<()> {
+        let mut base = self.doc.write()?;
         let is_text = base
             .get_node(self.node_id)
             .map(|n| n.is_text_node())
@@ -135,7 +136,7 @@ impl NativeNode {
             drop(mutator);
             drop(base);
             self.doc.mark_host_dirty();
-            return;
+            return Ok(());
         }
 
         drop(base);
@@ -147,144 +148,156 @@ impl NativeNode {
         drop(mutator);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn tag_name(&self) -> Option<String> {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
+    pub fn tag_name(&self) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        Ok(base
+            .get_node(self.node_id)
             .and_then(|n| n.element_data())
-            .map(|el| el.name.local.to_string())
+            .map(|el| el.name.local.to_string()))
     }
 
     #[napi]
-    pub fn get_attribute(&self, name: String) -> Option<String> {
-        let base = self.doc.base.borrow();
-        let node = base.get_node(self.node_id)?;
-        node.attr(LocalName::from(name.as_str()))
-            .map(|s| s.to_string())
+    pub fn get_attribute(&self, name: String) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        Ok(base
+            .get_node(self.node_id)
+            .and_then(|node| node.attr(LocalName::from(name.as_str())))
+            .map(|s| s.to_string()))
     }
 
     #[napi]
-    pub fn get_attributes(&self) -> Vec<AttrInit> {
-        let base = self.doc.base.borrow();
-        let Some(node) = base.get_node(self.node_id) else {
-            return Vec::new();
-        };
-        let Some(attrs) = node.attrs() else {
-            return Vec::new();
+    pub fn get_attributes(&self) -> Result<Vec<AttrInit>> {
+        let base = self.doc.read()?;
+        let Some(attrs) = base.get_node(self.node_id).and_then(|node| node.attrs()) else {
+            return Ok(Vec::new());
         };
-        attrs
+        Ok(attrs
             .iter()
             .map(|attr| AttrInit {
                 name: attr.name.local.to_string(),
                 value: attr.value.clone(),
                 namespace: Some(attr.name.ns.to_string()),
             })
-            .collect()
+            .collect())
     }
 
     #[napi]
-    pub fn set_attribute(&mut self, name: String, value: String, namespace: Option<String>) {
-        let mut base = self.doc.base.borrow_mut();
+    pub fn set_attribute(
+        &mut self,
+        name: String,
+        value: String,
+        namespace: Option<String>,
+    ) -> Result<()> {
+        let mut base = self.doc.write()?;
         let name = make_qual_name(&name, namespace.as_deref());
-        if set_detached_attribute(&mut base, self.node_id, name.clone(), &value) {
-            drop(base);
-            self.doc.mark_host_dirty();
-            return;
+        if !set_detached_attribute(&mut base, self.node_id, name.clone(), &value) {
+            let mut mutator = base.mutate();
+            mutator.set_attribute(self.node_id, name, &value);
         }
-        let mut mutator = base.mutate();
-        mutator.set_attribute(self.node_id, name, &value);
-        drop(mutator);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn remove_attribute(&mut self, name: String, namespace: Option<String>) {
-        let mut base = self.doc.base.borrow_mut();
+    pub fn remove_attribute(&mut self, name: String, namespace: Option<String>) -> Result<()> {
+        let mut base = self.doc.write()?;
         let name = make_qual_name(&name, namespace.as_deref());
-        if remove_detached_attribute(&mut base, self.node_id, &name) {
-            drop(base);
-            self.doc.mark_host_dirty();
-            return;
+        if !remove_detached_attribute(&mut base, self.node_id, &name) {
+            let mut mutator = base.mutate();
+            mutator.clear_attribute(self.node_id, name);
         }
-        let mut mutator = base.mutate();
-        mutator.clear_attribute(self.node_id, name);
-        drop(mutator);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn get_style_property(&self, name: String) -> Option<String> {
-        let base = self.doc.base.borrow();
-        let element_data = base.get_node(self.node_id)?.element_data()?;
-        let block = element_data.style_attribute.as_ref()?;
-        let property_id = PropertyId::parse_enabled_for_all_content(&name).ok()?;
+    pub fn get_style_property(&self, name: String) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        let Some(block) = base
+            .get_node(self.node_id)
+            .and_then(|n| n.element_data())
+            .and_then(|el| el.style_attribute.as_ref())
+        else {
+            return Ok(None);
+        };
+        let Ok(property_id) = PropertyId::parse_enabled_for_all_content(&name) else {
+            return Ok(None);
+        };
 
         let guard = base.guard().read();
         let block = block.read_with(&guard);
         let mut buf = String::new();
-        block.property_value_to_css(&property_id, &mut buf).ok()?;
-        if buf.is_empty() { None } else { Some(buf) }
+        if block.property_value_to_css(&property_id, &mut buf).is_err() || buf.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(buf))
     }
 
     #[napi]
-    pub fn set_style_property(&mut self, name: String, value: String) {
-        let mut base = self.doc.base.borrow_mut();
+    pub fn set_style_property(&mut self, name: String, value: String) -> Result<()> {
+        let mut base = self.doc.write()?;
         mark_inline_style_mutated(&mut base, self.node_id);
         base.set_style_property(self.node_id, &name, &value);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn remove_style_property(&mut self, name: String) {
-        let mut base = self.doc.base.borrow_mut();
+    pub fn remove_style_property(&mut self, name: String) -> Result<()> {
+        let mut base = self.doc.write()?;
         mark_inline_style_mutated(&mut base, self.node_id);
         base.remove_style_property(self.node_id, &name);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn get_style_property_names(&self) -> Vec<String> {
-        let base = self.doc.base.borrow();
-        let Some(element_data) = base.get_node(self.node_id).and_then(|n| n.element_data()) else {
-            return Vec::new();
-        };
-        let Some(block) = element_data.style_attribute.as_ref() else {
-            return Vec::new();
+    pub fn get_style_property_names(&self) -> Result<Vec<String>> {
+        let base = self.doc.read()?;
+        let Some(block) = base
+            .get_node(self.node_id)
+            .and_then(|n| n.element_data())
+            .and_then(|el| el.style_attribute.as_ref())
+        else {
+            return Ok(Vec::new());
         };
         let guard = base.guard().read();
         let block = block.read_with(&guard);
-        block
+        Ok(block
             .declarations()
             .iter()
             .map(|declaration| declaration.id().name().into_owned())
-            .collect()
+            .collect())
     }
 
     #[napi]
-    pub fn get_style_attribute(&self) -> String {
-        let base = self.doc.base.borrow();
-        let Some(element_data) = base.get_node(self.node_id).and_then(|n| n.element_data()) else {
-            return String::new();
-        };
-        let Some(block) = element_data.style_attribute.as_ref() else {
-            return String::new();
+    pub fn get_style_attribute(&self) -> Result<String> {
+        let base = self.doc.read()?;
+        let Some(block) = base
+            .get_node(self.node_id)
+            .and_then(|n| n.element_data())
+            .and_then(|el| el.style_attribute.as_ref())
+        else {
+            return Ok(String::new());
         };
         let guard = base.guard().read();
         let block = block.read_with(&guard);
         let mut buf = String::new();
         let _ = block.to_css(&mut buf);
-        buf
+        Ok(buf)
     }
 
     #[napi]
     pub fn append_child<'a>(&mut self, child: &NativeNode, env: &'a Env) -> Result<Object<'a>> {
-        let mut base = self.doc.base.borrow_mut();
+        let mut base = self.doc.write()?;
         let mut mutator = base.mutate();
         mutator.append_children(self.node_id, &[child.node_id]);
         drop(mutator);
@@ -302,7 +315,7 @@ impl NativeNode {
         anchor: Option<&NativeNode>,
         env: &'a Env,
     ) -> Result<Object<'a>> {
-        let mut base = self.doc.base.borrow_mut();
+        let mut base = self.doc.write()?;
         let mut mutator = base.mutate();
         match anchor {
             Some(anchor) => {
@@ -321,7 +334,8 @@ impl NativeNode {
     }
 
     #[napi]
-    pub fn remove(&mut self, env: &Env) {
+    pub fn remove(&mut self, env: &Env) -> Result<()> {
+        self.doc.check_live()?;
         // Switch to weak before removing, while parent chain is intact.
         if let Err(e) = self.doc.make_in_document_subtree_weak(self.node_id, env) {
             eprintln!("napi-blitz: make_in_document_subtree_weak failed: {e}");
@@ -332,10 +346,12 @@ impl NativeNode {
         drop(mutator);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
     pub fn replace_with<'a>(&mut self, node: &NativeNode, env: &'a Env) -> Result<Object<'a>> {
+        self.doc.check_live()?;
         let removed_id = self.node_id;
         // Switch the removed node to weak before detaching, while parent chain is intact.
         if let Err(e) = self.doc.make_in_document_subtree_weak(removed_id, env) {
@@ -355,6 +371,7 @@ impl NativeNode {
 
     #[napi]
     pub fn clone_node<'a>(&self, deep: bool, env: &'a Env) -> Result<Object<'a>> {
+        self.doc.check_live()?;
         let new_id = if deep {
             let mut base = self.doc.base.borrow_mut();
             let mut mutator = base.mutate();
@@ -376,7 +393,8 @@ impl NativeNode {
     }
 
     #[napi]
-    pub fn set_inner_html(&mut self, html: String, env: &Env) {
+    pub fn set_inner_html(&mut self, html: String, env: &Env) -> Result<()> {
+        self.doc.check_live()?;
         self.doc.detach_children(self.node_id, env).ok();
         let mut base = self.doc.base.borrow_mut();
         let mut mutator = base.mutate();
@@ -384,31 +402,34 @@ impl NativeNode {
         drop(mutator);
         drop(base);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     #[napi]
-    pub fn inner_html(&self) -> Option<String> {
-        let base = self.doc.base.borrow();
-        let node = base.get_node(self.node_id)?;
+    pub fn inner_html(&self) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        let Some(node) = base.get_node(self.node_id) else {
+            return Ok(None);
+        };
         let mut out = String::new();
         for &child_id in &node.children {
             if let Some(child) = base.get_node(child_id) {
                 child.write_outer_html(&mut out);
             }
         }
-        Some(out)
+        Ok(Some(out))
     }
 
     #[napi]
-    pub fn outer_html(&self) -> Option<String> {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id).map(|node| node.outer_html())
+    pub fn outer_html(&self) -> Result<Option<String>> {
+        let base = self.doc.read()?;
+        Ok(base.get_node(self.node_id).map(|node| node.outer_html()))
     }
 
     #[napi]
     pub fn query_selector<'a>(&self, selector: String, env: &'a Env) -> Result<Option<Object<'a>>> {
         let result_id = {
-            let base = self.doc.base.borrow();
+            let base = self.doc.read()?;
             let selector_list = base
                 .try_parse_selector_list(&selector)
                 .map_err(|err| Error::from_reason(format!("query_selector: {err:?}")))?;
@@ -438,7 +459,7 @@ impl NativeNode {
         env: &'a Env,
     ) -> Result<Vec<Object<'a>>> {
         let ids: Vec<NodeId> = {
-            let base = self.doc.base.borrow();
+            let base = self.doc.read()?;
             let selector_list = base
                 .try_parse_selector_list(&selector)
                 .map_err(|err| Error::from_reason(format!("query_selector_all: {err:?}")))?;
@@ -463,116 +484,197 @@ impl NativeNode {
     }
 
     #[napi]
-    pub fn get_bounding_client_rect(&self) -> Option<DomRect> {
-        let base = self.doc.base.borrow();
-        let node = base.get_node(self.node_id)?;
-        let pos = node.absolute_position(0.0, 0.0);
-        let layout = node.final_layout();
-        Some(DomRect {
-            x: pos.x as f64,
-            y: pos.y as f64,
-            width: layout.size.width as f64,
-            height: layout.size.height as f64,
-            top: pos.y as f64,
-            left: pos.x as f64,
-            bottom: (pos.y + layout.size.height) as f64,
-            right: (pos.x + layout.size.width) as f64,
-        })
+    pub fn get_bounding_client_rect(&self) -> Result<Option<DomRect>> {
+        let base = self.doc.read()?;
+        Ok(base.get_node(self.node_id).map(|node| {
+            let pos = node.absolute_position(0.0, 0.0);
+            let layout = node.final_layout();
+            DomRect::new(
+                pos.x as f64,
+                pos.y as f64,
+                layout.size.width as f64,
+                layout.size.height as f64,
+            )
+        }))
     }
 
     #[napi(getter)]
-    pub fn scroll_top(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
-            .map(|n| n.scroll_offset().y)
-            .unwrap_or(0.0)
+    pub fn scroll_top(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        Ok(offset(&base, ScrollTarget::of(&base, self.node_id)).1)
     }
 
+    /// Scroll vertically, clamped to the scroll range. `scroll` fires on
+    /// the next frame.
     #[napi(setter)]
-    pub fn set_scroll_top(&mut self, value: f64) {
-        let mut base = self.doc.base.borrow_mut();
-        if let Some(node) = base.get_node_mut(self.node_id) {
-            let offset = node.scroll_offset_mut();
-            offset.y = value;
-        }
-        drop(base);
-        self.doc.mark_host_dirty();
+    pub fn set_scroll_top(&mut self, value: f64) -> Result<()> {
+        let target = ScrollTarget::of(&self.doc.read()?, self.node_id);
+        scroll_to(&self.doc, target, None, Some(value), false);
+        Ok(())
     }
 
     #[napi(getter)]
-    pub fn scroll_left(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
-            .map(|n| n.scroll_offset().x)
-            .unwrap_or(0.0)
+    pub fn scroll_left(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        Ok(offset(&base, ScrollTarget::of(&base, self.node_id)).0)
     }
 
+    /// Scroll horizontally, clamped to the scroll range. `scroll` fires on
+    /// the next frame.
     #[napi(setter)]
-    pub fn set_scroll_left(&mut self, value: f64) {
-        let mut base = self.doc.base.borrow_mut();
-        if let Some(node) = base.get_node_mut(self.node_id) {
-            let offset = node.scroll_offset_mut();
-            offset.x = value;
-        }
-        drop(base);
-        self.doc.mark_host_dirty();
+    pub fn set_scroll_left(&mut self, value: f64) -> Result<()> {
+        let target = ScrollTarget::of(&self.doc.read()?, self.node_id);
+        scroll_to(&self.doc, target, Some(value), None, false);
+        Ok(())
     }
 
+    /// Client height plus the vertical scroll range.
     #[napi(getter)]
-    pub fn scroll_height(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
-            .map(|n| {
-                let layout = n.final_layout();
-                layout.content_box_height() as f64
-            })
-            .unwrap_or(0.0)
+    pub fn scroll_height(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        let range = max_offset(&base, ScrollTarget::Node(self.node_id)).1;
+        Ok(base
+            .get_node(self.node_id)
+            .map(|n| n.final_layout().content_box_height() as f64 + range)
+            .unwrap_or(0.0))
     }
 
+    /// Client width plus the horizontal scroll range.
     #[napi(getter)]
-    pub fn scroll_width(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
-            .map(|n| {
-                let layout = n.final_layout();
-                layout.content_box_width() as f64
-            })
-            .unwrap_or(0.0)
+    pub fn scroll_width(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        let range = max_offset(&base, ScrollTarget::Node(self.node_id)).0;
+        Ok(base
+            .get_node(self.node_id)
+            .map(|n| n.final_layout().content_box_width() as f64 + range)
+            .unwrap_or(0.0))
+    }
+
+    /// Scroll to `left` / `top`, clamped. Mirrors `Element.scrollTo`; the
+    /// root element scrolls the viewport.
+    #[napi]
+    pub fn scroll_to(&self, options: ScrollToOptions) -> Result<()> {
+        let target = ScrollTarget::of(&self.doc.read()?, self.node_id);
+        scroll_with_options(&self.doc, target, options, false)
+    }
+
+    /// Scroll by `left` / `top`, clamped. Mirrors `Element.scrollBy`.
+    #[napi]
+    pub fn scroll_by(&self, options: ScrollToOptions) -> Result<()> {
+        let target = ScrollTarget::of(&self.doc.read()?, self.node_id);
+        scroll_with_options(&self.doc, target, options, true)
+    }
+
+    /// Scroll the enclosing scroll containers and the viewport to show
+    /// this node. Mirrors `Element.scrollIntoView(options)`; uses the last
+    /// resolved layout.
+    #[napi]
+    pub fn scroll_into_view(&self, options: Option<ScrollIntoViewOptions>) -> Result<()> {
+        self.doc.check_live()?;
+        let options = options.unwrap_or(ScrollIntoViewOptions {
+            block: None,
+            inline: None,
+            behavior: None,
+        });
+        scroll_into_view(&self.doc, self.node_id, options)
     }
 
     #[napi(getter)]
-    pub fn client_height(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
+    pub fn client_height(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        Ok(base
+            .get_node(self.node_id)
             .map(|n| n.final_layout().content_box_height() as f64)
-            .unwrap_or(0.0)
+            .unwrap_or(0.0))
     }
 
     #[napi(getter)]
-    pub fn client_width(&self) -> f64 {
-        let base = self.doc.base.borrow();
-        base.get_node(self.node_id)
+    pub fn client_width(&self) -> Result<f64> {
+        let base = self.doc.read()?;
+        Ok(base
+            .get_node(self.node_id)
             .map(|n| n.final_layout().content_box_width() as f64)
-            .unwrap_or(0.0)
+            .unwrap_or(0.0))
     }
 
     // ---- Focus / blur ----------------------------------------------------
 
-    /// Move focus to this node. Mirrors `HTMLElement.focus()`.
+    /// Move focus to this node. Mirrors `HTMLElement.focus()`: a no-op for
+    /// nodes that are not focusable or not in the document. Fires
+    /// `blur`/`focusout` and `focus`/`focusin` synchronously.
     /// Returns true if focus actually changed.
     #[napi]
-    pub fn focus(&mut self) -> bool {
-        let mut base = self.doc.base.borrow_mut();
-        base.set_focus_to(self.node_id)
+    pub fn focus(&mut self, env: Env) -> Result<bool> {
+        let changed = {
+            let mut base = self.doc.write()?;
+            let focusable = base
+                .get_node(self.node_id)
+                .is_some_and(|n| n.flags.is_in_document() && is_focusable(n));
+            focusable && base.set_focus_to(self.node_id)
+        };
+        if changed {
+            self.doc.mark_host_dirty();
+            sync_focus(&self.doc, &env)?;
+        }
+        Ok(changed)
     }
 
     /// Remove focus from this node (if focused). Mirrors `HTMLElement.blur()`.
     #[napi]
-    pub fn blur(&mut self) {
-        let mut base = self.doc.base.borrow_mut();
-        base.clear_focus();
-        drop(base);
+    pub fn blur(&mut self, env: Env) -> Result<()> {
+        {
+            let mut base = self.doc.write()?;
+            if base.get_focussed_node_id() != Some(self.node_id) {
+                return Ok(());
+            }
+            base.clear_focus();
+        }
         self.doc.mark_host_dirty();
+        sync_focus(&self.doc, &env)
+    }
+
+    // ---- Pointer capture -------------------------------------------------
+
+    /// Redirect subsequent events of `pointer_id` to this node. Mirrors
+    /// `Element.setPointerCapture`. Takes effect (and fires
+    /// `gotpointercapture`) at the next pointer event.
+    #[napi]
+    pub fn set_pointer_capture(&self, pointer_id: u32) -> Result<()> {
+        self.doc.check_live()?;
+        if !self.doc.is_in_document(self.node_id) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "setPointerCapture: element is not connected to the document",
+            ));
+        }
+        self.doc
+            .pointer_captures
+            .borrow_mut()
+            .set_pending(pointer_id as u64, self.node_id);
+        Ok(())
+    }
+
+    /// Mirrors `Element.releasePointerCapture`. No-op if this node does not
+    /// hold the capture.
+    #[napi]
+    pub fn release_pointer_capture(&self, pointer_id: u32) -> Result<()> {
+        self.doc.check_live()?;
+        self.doc
+            .pointer_captures
+            .borrow_mut()
+            .release_pending(pointer_id as u64, self.node_id);
+        Ok(())
+    }
+
+    /// Mirrors `Element.hasPointerCapture`.
+    #[napi]
+    pub fn has_pointer_capture(&self, pointer_id: u32) -> Result<bool> {
+        self.doc.check_live()?;
+        Ok(self
+            .doc
+            .pointer_captures
+            .borrow()
+            .has_capture(pointer_id as u64, self.node_id))
     }
 }
 
@@ -587,3 +689,18 @@ pub struct DomRect {
     pub bottom: f64,
     pub right: f64,
 }
+
+impl DomRect {
+    pub(crate) fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            top: y,
+            left: x,
+            bottom: y + height,
+            right: x + width,
+        }
+    }
+}
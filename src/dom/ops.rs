@@ -17,7 +17,13 @@ use style::{Atom, invalidation::element::restyle_hints::RestyleHint, properties:
 
 use crate::dom::{
     doc::{NativeDoc, wrap_node},
-    node_handle::NativeNode,
+    event::normalize_hit_target,
+    focus::{focus_sequential, sync_focus},
+    node_handle::{DomRect, NativeNode},
+    selection::{
+        Boundary, DocSelection, compare_boundaries, node_length, range_rects, range_text,
+        set_selection,
+    },
 };
 
 /// Plain attribute pair used by the create/insert APIs.
@@ -133,8 +139,8 @@ impl NativeDoc {
     /// Replace document content from an HTML string. Useful for tests and
     /// initial bootstrapping when `base_html` was not enough.
     #[napi]
-    pub fn load_html(&mut self, html: String) {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn load_html(&mut self, html: String) -> Result<()> {
+        let mut state = self.doc.write()?;
         {
             let mut mutator = state.mutate();
             DocumentHtmlParser::parse_into_mutator(&mut mutator, &html);
@@ -142,16 +148,20 @@ impl NativeDoc {
         state.resolve(0.0);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Find a single node by CSS selector. Returns a wrapped JS Node or null.
     #[napi]
     pub fn query_selector<'a>(&self, selector: String, env: &'a Env) -> Result<Option<Object<'a>>> {
-        let state = self.doc.base.borrow();
-        match state.query_selector(&selector) {
-            Ok(Some(id)) => Ok(Some(wrap_node(&self.doc, id, env)?)),
-            Ok(None) => Ok(None),
-            Err(err) => Err(Error::from_reason(format!("query_selector: {err:?}"))),
+        let result_id = self
+            .doc
+            .read()?
+            .query_selector(&selector)
+            .map_err(|err| Error::from_reason(format!("query_selector: {err:?}")))?;
+        match result_id {
+            Some(id) => Ok(Some(wrap_node(&self.doc, id, env)?)),
+            None => Ok(None),
         }
     }
 
@@ -162,17 +172,14 @@ impl NativeDoc {
         selector: String,
         env: &'a Env,
     ) -> Result<Vec<Object<'a>>> {
-        let state = self.doc.base.borrow();
-        match state.query_selector_all(&selector) {
-            Ok(ids) => {
-                let mut result = Vec::new();
-                for id in ids {
-                    result.push(wrap_node(&self.doc, id, env)?);
-                }
-                Ok(result)
-            }
-            Err(err) => Err(Error::from_reason(format!("query_selector_all: {err:?}"))),
-        }
+        let ids = self
+            .doc
+            .read()?
+            .query_selector_all(&selector)
+            .map_err(|err| Error::from_reason(format!("query_selector_all: {err:?}")))?;
+        ids.into_iter()
+            .map(|id| wrap_node(&self.doc, id, env))
+            .collect()
     }
 
     /// Element-scoped `querySelector`: first match in the subtree rooted at
@@ -184,7 +191,7 @@ impl NativeDoc {
     /// to `self.root_node()`.
     #[napi]
     pub fn query_selector_in(&self, root_id: BigInt, selector: String) -> Result<Option<u64>> {
-        let state = self.doc.base.borrow();
+        let state = self.doc.read()?;
         let selector_list = state
             .try_parse_selector_list(&selector)
             .map_err(|err| Error::from_reason(format!("query_selector_in: {err:?}")))?;
@@ -208,7 +215,7 @@ impl NativeDoc {
     /// at `root_id` (exclusive). Same approach as `query_selector_in`.
     #[napi]
     pub fn query_selector_all_in(&self, root_id: BigInt, selector: String) -> Result<Vec<u64>> {
-        let state = self.doc.base.borrow();
+        let state = self.doc.read()?;
         let selector_list = state
             .try_parse_selector_list(&selector)
             .map_err(|err| Error::from_reason(format!("query_selector_all_in: {err:?}")))?;
@@ -230,9 +237,11 @@ impl NativeDoc {
 
     /// Lookup by `id=` attribute, like `document.getElementById`.
     #[napi]
-    pub fn get_element_by_id<'a>(&self, id: String, env: &'a Env) -> Option<Object<'a>> {
-        let node_id = self.doc.base.borrow().get_element_by_id(&id)?;
-        wrap_node(&self.doc, node_id, env).ok()
+    pub fn get_element_by_id<'a>(&self, id: String, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let Some(node_id) = self.doc.read()?.get_element_by_id(&id) else {
+            return Ok(None);
+        };
+        Ok(wrap_node(&self.doc, node_id, env).ok())
     }
 
     /// Find the document's `<title>` element id, or None if no title
@@ -241,26 +250,26 @@ impl NativeDoc {
     /// `querySelector("title")` which dispatches through the CSS
     /// selector engine.
     #[napi]
-    pub fn find_title_node<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
-        let id = self.find_first_static(local_name!("title"))?;
-        wrap_node(&self.doc, id, env).ok()
+    pub fn find_title_node<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let Some(id) = self.find_first_static(local_name!("title"))? else {
+            return Ok(None);
+        };
+        Ok(wrap_node(&self.doc, id, env).ok())
     }
 
     /// True iff the given node id currently exists in the document.
     #[napi]
-    pub fn has_node(&self, id: BigInt) -> bool {
-        self.doc
-            .base
-            .borrow()
-            .get_node(js_to_node_id(&id))
-            .is_some()
+    pub fn has_node(&self, id: BigInt) -> Result<bool> {
+        Ok(self.doc.read()?.get_node(js_to_node_id(&id)).is_some())
     }
 
     #[napi]
-    pub fn node_handle(&self, id: BigInt) -> Option<NativeNode> {
+    pub fn node_handle(&self, id: BigInt) -> Result<Option<NativeNode>> {
         let node_id = js_to_node_id(&id);
-        self.doc.base.borrow().get_node(node_id)?;
-        Some(NativeNode::new(node_id, self.doc.clone()))
+        if self.doc.read()?.get_node(node_id).is_none() {
+            return Ok(None);
+        }
+        Ok(Some(NativeNode::new(node_id, self.doc.clone())))
     }
 }
 
@@ -276,7 +285,7 @@ impl NativeDoc {
         attrs: Option<Vec<AttrInit>>,
         env: &'a Env,
     ) -> Result<Object<'a>> {
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         let qn = make_qual_name(&local_name, namespace.as_deref());
         let attr_vec: Vec<BlitzAttribute> = attrs
@@ -297,7 +306,7 @@ impl NativeDoc {
     /// Create a text node with the given content. Returns a wrapped JS Node.
     #[napi]
     pub fn create_text_node<'a>(&mut self, text: String, env: &'a Env) -> Result<Object<'a>> {
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         let node_id = mutator.create_text_node(&text);
         drop(mutator);
@@ -309,7 +318,7 @@ impl NativeDoc {
     /// Create a comment node with the given content. Returns a wrapped JS Node.
     #[napi]
     pub fn create_comment_node<'a>(&mut self, text: String, env: &'a Env) -> Result<Object<'a>> {
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         let node_id = mutator.create_comment_node(&text);
         drop(mutator);
@@ -320,10 +329,10 @@ impl NativeDoc {
 
     /// Deep-clone an existing node and return the new node's id.
     #[napi]
-    pub fn deep_clone_node(&mut self, node_id: BigInt) -> u64 {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn deep_clone_node(&mut self, node_id: BigInt) -> Result<u64> {
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
-        mutator.deep_clone_node(js_to_node_id(&node_id)).as_u64()
+        Ok(mutator.deep_clone_node(js_to_node_id(&node_id)).as_u64())
     }
 
     /// Shallow-clone a node: same data (tag name, attributes, text
@@ -335,16 +344,16 @@ impl NativeDoc {
     /// alternative (returning `Option<u64>`) noisily complicates the
     /// JS-side cloneNode wrapper for a case JS code can never trigger.
     #[napi]
-    pub fn shallow_clone_node(&mut self, node_id: BigInt) -> u64 {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn shallow_clone_node(&mut self, node_id: BigInt) -> Result<u64> {
+        let mut state = self.doc.write()?;
         let Some(source) = state.get_node(js_to_node_id(&node_id)) else {
-            return 0;
+            return Ok(0);
         };
         // Cloning `NodeData` deep-copies attributes, text, and the
         // (Arc-shared) parsed `style` declaration block. We never
         // touch `children` / `parent` so the clone starts detached.
         let data = source.data.clone();
-        state.create_node(data).as_u64()
+        Ok(state.create_node(data).as_u64())
     }
 }
 
@@ -352,68 +361,68 @@ impl NativeDoc {
 impl NativeDoc {
     /// Parent node id, if any.
     #[napi]
-    pub fn parent_id(&self, node_id: BigInt) -> Option<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn parent_id(&self, node_id: BigInt) -> Result<Option<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.parent)
-            .map(|id| id.as_u64())
+            .map(|id| id.as_u64()))
     }
 
     /// First child id, if any.
     #[napi]
-    pub fn first_child_id(&self, node_id: BigInt) -> Option<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn first_child_id(&self, node_id: BigInt) -> Result<Option<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.children.first().copied())
-            .map(|id| id.as_u64())
+            .map(|id| id.as_u64()))
     }
 
     /// Last child id, if any.
     #[napi]
-    pub fn last_child_id(&self, node_id: BigInt) -> Option<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn last_child_id(&self, node_id: BigInt) -> Result<Option<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.children.last().copied())
-            .map(|id| id.as_u64())
+            .map(|id| id.as_u64()))
     }
 
     /// All children, in document order.
     #[napi]
-    pub fn child_ids(&self, node_id: BigInt) -> Vec<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn child_ids(&self, node_id: BigInt) -> Result<Vec<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .map(|n| n.children.iter().map(|id| id.as_u64()).collect())
-            .unwrap_or_default()
+            .unwrap_or_default())
     }
 
     /// Next sibling id, if any.
     #[napi]
-    pub fn next_sibling_id(&self, node_id: BigInt) -> Option<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn next_sibling_id(&self, node_id: BigInt) -> Result<Option<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.forward(1))
-            .map(|n| n.id.as_u64())
+            .map(|n| n.id.as_u64()))
     }
 
     /// Previous sibling id, if any.
     #[napi]
-    pub fn previous_sibling_id(&self, node_id: BigInt) -> Option<u64> {
-        self.doc
-            .base
-            .borrow()
+    pub fn previous_sibling_id(&self, node_id: BigInt) -> Result<Option<u64>> {
+        Ok(self
+            .doc
+            .read()?
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.backward(1))
-            .map(|n| n.id.as_u64())
+            .map(|n| n.id.as_u64()))
     }
 }
 
@@ -428,70 +437,72 @@ const NODE_TYPE_OTHER: u32 = 0;
 impl NativeDoc {
     /// DOM-style `nodeType` (1=Element, 3=Text, 8=Comment, 9=Document).
     #[napi]
-    pub fn node_type(&self, node_id: BigInt) -> u32 {
-        let state = self.doc.base.borrow();
+    pub fn node_type(&self, node_id: BigInt) -> Result<u32> {
+        let state = self.doc.read()?;
         let Some(node) = state.get_node(js_to_node_id(&node_id)) else {
-            return NODE_TYPE_OTHER;
+            return Ok(NODE_TYPE_OTHER);
         };
         use blitz::dom::NodeData;
-        match &node.data {
+        Ok(match &node.data {
             NodeData::Document(_) => NODE_TYPE_DOCUMENT,
             NodeData::Element(_) => NODE_TYPE_ELEMENT,
             NodeData::Text(_) => NODE_TYPE_TEXT,
             NodeData::Comment { .. } => NODE_TYPE_COMMENT,
             _ => NODE_TYPE_OTHER,
-        }
+        })
     }
 
     /// Local element tag name (lowercased), e.g. "div". Returns None for
     /// non-element nodes.
     #[napi]
-    pub fn tag_name(&self, node_id: BigInt) -> Option<String> {
-        let state = self.doc.base.borrow();
-        state
+    pub fn tag_name(&self, node_id: BigInt) -> Result<Option<String>> {
+        let state = self.doc.read()?;
+        Ok(state
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.element_data())
-            .map(|el| el.name.local.to_string())
+            .map(|el| el.name.local.to_string()))
     }
 
     /// Concatenated text content of this node and its descendants. Mirrors
     /// `Node.textContent`.
     #[napi]
-    pub fn text_content(&self, node_id: BigInt) -> Option<String> {
-        let state = self.doc.base.borrow();
-        state
+    pub fn text_content(&self, node_id: BigInt) -> Result<Option<String>> {
+        let state = self.doc.read()?;
+        Ok(state
             .get_node(js_to_node_id(&node_id))
-            .map(|n| n.text_content())
+            .map(|n| n.text_content()))
     }
 
     /// Get an attribute value, or None if missing or node is not an element.
     #[napi]
-    pub fn get_attribute(&self, node_id: BigInt, name: String) -> Option<String> {
-        let state = self.doc.base.borrow();
-        let node = state.get_node(js_to_node_id(&node_id))?;
+    pub fn get_attribute(&self, node_id: BigInt, name: String) -> Result<Option<String>> {
+        let state = self.doc.read()?;
         let local = LocalName::from(name.as_str());
-        node.attr(local).map(|s| s.to_string())
+        Ok(state
+            .get_node(js_to_node_id(&node_id))
+            .and_then(|node| node.attr(local))
+            .map(|s| s.to_string()))
     }
 
     /// All attribute (name, value) pairs on this node, or empty if not an
     /// element.
     #[napi]
-    pub fn get_attributes(&self, node_id: BigInt) -> Vec<AttrInit> {
-        let state = self.doc.base.borrow();
+    pub fn get_attributes(&self, node_id: BigInt) -> Result<Vec<AttrInit>> {
+        let state = self.doc.read()?;
         let Some(node) = state.get_node(js_to_node_id(&node_id)) else {
-            return Vec::new();
+            return Ok(Vec::new());
         };
         let Some(attrs) = node.attrs() else {
-            return Vec::new();
+            return Ok(Vec::new());
         };
-        attrs
+        Ok(attrs
             .iter()
             .map(|a| AttrInit {
                 name: a.name.local.to_string(),
                 value: a.value.clone(),
                 namespace: Some(a.name.ns.to_string()),
             })
-            .collect()
+            .collect())
     }
 }
 
@@ -505,60 +516,74 @@ impl NativeDoc {
         name: String,
         value: String,
         namespace: Option<String>,
-    ) {
-        let mut state = self.doc.base.borrow_mut();
+    ) -> Result<()> {
+        let mut state = self.doc.write()?;
         let node_id = js_to_node_id(&node_id);
         let name = make_qual_name(&name, namespace.as_deref());
         if set_detached_attribute(&mut state, node_id, name.clone(), &value) {
             drop(state);
             self.doc.mark_host_dirty();
-            return;
+            return Ok(());
         }
         let mut mutator = state.mutate();
         mutator.set_attribute(node_id, name, &value);
         drop(mutator);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Remove an attribute from an element.
     #[napi]
-    pub fn remove_attribute(&mut self, node_id: BigInt, name: String, namespace: Option<String>) {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn remove_attribute(
+        &mut self,
+        node_id: BigInt,
+        name: String,
+        namespace: Option<String>,
+    ) -> Result<()> {
+        let mut state = self.doc.write()?;
         let node_id = js_to_node_id(&node_id);
         let name = make_qual_name(&name, namespace.as_deref());
         if remove_detached_attribute(&mut state, node_id, &name) {
             drop(state);
             self.doc.mark_host_dirty();
-            return;
+            return Ok(());
         }
         let mut mutator = state.mutate();
         mutator.clear_attribute(node_id, name);
         drop(mutator);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Set a single inline style property (e.g. "color", "#ff0000").
     #[napi]
-    pub fn set_style_property(&mut self, node_id: BigInt, name: String, value: String) {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn set_style_property(
+        &mut self,
+        node_id: BigInt,
+        name: String,
+        value: String,
+    ) -> Result<()> {
+        let mut state = self.doc.write()?;
         let node_id = js_to_node_id(&node_id);
         mark_inline_style_mutated(&mut state, node_id);
         state.set_style_property(node_id, &name, &value);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Remove a single inline style property.
     #[napi]
-    pub fn remove_style_property(&mut self, node_id: BigInt, name: String) {
-        let mut state = self.doc.base.borrow_mut();
+    pub fn remove_style_property(&mut self, node_id: BigInt, name: String) -> Result<()> {
+        let mut state = self.doc.write()?;
         let node_id = js_to_node_id(&node_id);
         mark_inline_style_mutated(&mut state, node_id);
         state.remove_style_property(node_id, &name);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Read a single inline style property's serialized value, or
@@ -570,11 +595,18 @@ impl NativeDoc {
     /// property name (one stylo doesn't recognize) also returns `null`
     /// rather than throwing — matching browser semantics.
     #[napi]
-    pub fn get_style_property(&self, node_id: BigInt, name: String) -> Option<String> {
-        let state = self.doc.base.borrow();
-        let element_data = state.get_node(js_to_node_id(&node_id))?.element_data()?;
-        let block = element_data.style_attribute.as_ref()?;
-        let property_id = PropertyId::parse_enabled_for_all_content(&name).ok()?;
+    pub fn get_style_property(&self, node_id: BigInt, name: String) -> Result<Option<String>> {
+        let state = self.doc.read()?;
+        let Some(block) = state
+            .get_node(js_to_node_id(&node_id))
+            .and_then(|n| n.element_data())
+            .and_then(|el| el.style_attribute.as_ref())
+        else {
+            return Ok(None);
+        };
+        let Ok(property_id) = PropertyId::parse_enabled_for_all_content(&name) else {
+            return Ok(None);
+        };
 
         let guard = state.guard().read();
         let block = block.read_with(&guard);
@@ -584,8 +616,10 @@ impl NativeDoc {
         // `block.declarations()` would be more rigorous, but the
         // browser behavior of `getPropertyValue` is also "" for
         // unset, so we collapse the two: an empty result means absent.
-        block.property_value_to_css(&property_id, &mut buf).ok()?;
-        if buf.is_empty() { None } else { Some(buf) }
+        if block.property_value_to_css(&property_id, &mut buf).is_err() || buf.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(buf))
     }
 
     /// List the long-hand names of every property currently in this
@@ -596,54 +630,54 @@ impl NativeDoc {
     /// identifiers (e.g. `"color"`, `"margin-top"`). Custom properties
     /// (`--foo`) are included as-is.
     #[napi]
-    pub fn get_style_property_names(&self, node_id: BigInt) -> Vec<String> {
-        let state = self.doc.base.borrow();
+    pub fn get_style_property_names(&self, node_id: BigInt) -> Result<Vec<String>> {
+        let state = self.doc.read()?;
         let Some(element_data) = state
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.element_data())
         else {
-            return Vec::new();
+            return Ok(Vec::new());
         };
         let Some(block) = element_data.style_attribute.as_ref() else {
-            return Vec::new();
+            return Ok(Vec::new());
         };
         let guard = state.guard().read();
         let block = block.read_with(&guard);
-        block
+        Ok(block
             .declarations()
             .iter()
             .map(|d| d.id().name().into_owned())
-            .collect()
+            .collect())
     }
 
     /// Read the entire `style` attribute as a single CSS string. Used
     /// to back `CSSStyleDeclaration.cssText`. Returns the empty string
     /// when the element has no inline style at all.
     #[napi]
-    pub fn get_style_attribute(&self, node_id: BigInt) -> String {
-        let state = self.doc.base.borrow();
+    pub fn get_style_attribute(&self, node_id: BigInt) -> Result<String> {
+        let state = self.doc.read()?;
         let Some(element_data) = state
             .get_node(js_to_node_id(&node_id))
             .and_then(|n| n.element_data())
         else {
-            return String::new();
+            return Ok(String::new());
         };
         let Some(block) = element_data.style_attribute.as_ref() else {
-            return String::new();
+            return Ok(String::new());
         };
         let guard = state.guard().read();
         let block = block.read_with(&guard);
         let mut buf = String::new();
         let _ = block.to_css(&mut buf);
-        buf
+        Ok(buf)
     }
 
     /// Replace this node's text content. For elements this resets to a single
     /// text-node child; for text/comment nodes this updates their content.
     #[napi]
-    pub fn set_text_content(&mut self, node_id: BigInt, text: String, env: &Env) {
+    pub fn set_text_content(&mut self, node_id: BigInt, text: String, env: &Env) -> Result<()> {
         let nid = js_to_node_id(&node_id);
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         // For text nodes we update the existing data.
         let is_text = state
             .get_node(nid)
@@ -655,13 +689,13 @@ impl NativeDoc {
             drop(mutator);
             drop(state);
             self.doc.mark_host_dirty();
-            return;
+            return Ok(());
         }
 
         // Otherwise reset element children to a single text node.
         drop(state);
         self.doc.detach_children(nid, env).ok();
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         {
             let mut mutator = state.mutate();
             let text_id = mutator.create_text_node(&text);
@@ -669,6 +703,7 @@ impl NativeDoc {
         }
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 }
 
@@ -678,7 +713,7 @@ impl NativeDoc {
     #[napi]
     pub fn append_child(&mut self, parent_id: BigInt, child_id: BigInt, env: &Env) -> Result<()> {
         let child_nid = js_to_node_id(&child_id);
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         mutator.append_children(js_to_node_id(&parent_id), &[child_nid]);
         drop(mutator);
@@ -700,7 +735,7 @@ impl NativeDoc {
         env: &Env,
     ) -> Result<()> {
         let nid = js_to_node_id(&node_id);
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         match anchor_id {
             Some(anchor) => {
@@ -723,7 +758,7 @@ impl NativeDoc {
     #[napi]
     pub fn insert_after(&mut self, anchor_id: BigInt, node_id: BigInt, env: &Env) -> Result<()> {
         let nid = js_to_node_id(&node_id);
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         mutator.insert_nodes_after(js_to_node_id(&anchor_id), &[nid]);
         drop(mutator);
@@ -740,10 +775,11 @@ impl NativeDoc {
     /// release storage.
     #[napi]
     pub fn remove(&mut self, node_id: BigInt, env: &Env) -> Result<()> {
+        self.doc.check_live()?;
         let nid = js_to_node_id(&node_id);
         // Switch to weak before removing, while parent chain is intact.
         self.doc.make_in_document_subtree_weak(nid, env)?;
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         mutator.remove_node(nid);
         drop(mutator);
@@ -755,13 +791,14 @@ impl NativeDoc {
     /// Replace `anchor` with `node` in its parent.
     #[napi]
     pub fn replace_with(&mut self, anchor_id: BigInt, node_id: BigInt, env: &Env) -> Result<()> {
+        self.doc.check_live()?;
         let anchor_nid = js_to_node_id(&anchor_id);
         let node_nid = js_to_node_id(&node_id);
         // Switch the anchor to weak before detaching, while parent chain is intact.
         if let Err(e) = self.doc.make_in_document_subtree_weak(anchor_nid, env) {
             eprintln!("napi-blitz: make_in_document_subtree_weak failed: {e}");
         }
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         mutator.replace_node_with(anchor_nid, &[node_nid]);
         drop(mutator);
@@ -775,40 +812,44 @@ impl NativeDoc {
 
     /// Replace this element's inner HTML.
     #[napi]
-    pub fn set_inner_html(&mut self, node_id: BigInt, html: String, env: &Env) {
+    pub fn set_inner_html(&mut self, node_id: BigInt, html: String, env: &Env) -> Result<()> {
+        self.doc.check_live()?;
         let nid = js_to_node_id(&node_id);
         self.doc.detach_children(nid, env).ok();
-        let mut state = self.doc.base.borrow_mut();
+        let mut state = self.doc.write()?;
         let mut mutator = state.mutate();
         mutator.set_inner_html(nid, &html);
         drop(mutator);
         drop(state);
         self.doc.mark_host_dirty();
+        Ok(())
     }
 
     /// Serialize this node (including the node itself) to HTML. Mirrors
     /// `Element.outerHTML`. Returns None for unknown nodes.
     #[napi]
-    pub fn outer_html(&self, node_id: BigInt) -> Option<String> {
-        let state = self.doc.base.borrow();
-        state
+    pub fn outer_html(&self, node_id: BigInt) -> Result<Option<String>> {
+        let state = self.doc.read()?;
+        Ok(state
             .get_node(js_to_node_id(&node_id))
-            .map(|n| n.outer_html())
+            .map(|n| n.outer_html()))
     }
 
     /// Serialize the children of this node to HTML, without the node's own
     /// open/close tags. Mirrors `Element.innerHTML`.
     #[napi]
-    pub fn inner_html(&self, node_id: BigInt) -> Option<String> {
-        let state = self.doc.base.borrow();
-        let node = state.get_node(js_to_node_id(&node_id))?;
+    pub fn inner_html(&self, node_id: BigInt) -> Result<Option<String>> {
+        let state = self.doc.read()?;
+        let Some(node) = state.get_node(js_to_node_id(&node_id)) else {
+            return Ok(None);
+        };
         let mut out = String::new();
         for &child_id in &node.children {
             if let Some(child) = state.get_node(child_id) {
                 child.write_outer_html(&mut out);
             }
         }
-        Some(out)
+        Ok(Some(out))
     }
 
     // -- Fast tree lookups --------------------------------------------------
@@ -829,29 +870,34 @@ impl NativeDoc {
     /// or None if no element matches. Pre-order traversal from the
     /// document root.
     #[napi]
-    pub fn find_first_by_local_name(&self, name: String) -> Option<u64> {
-        let state = self.doc.base.borrow();
+    pub fn find_first_by_local_name(&self, name: String) -> Result<Option<u64>> {
+        let state = self.doc.read()?;
         let needle = LocalName::from(name.as_str());
-        dfs_find(&state, state.root_node().id, |n| {
+        Ok(dfs_find(&state, state.root_node().id, |n| {
             n.data.is_element_with_tag_name(&needle)
         })
-        .map(|id| id.as_u64())
+        .map(|id| id.as_u64()))
     }
 
     /// All element ids matching the given local tag name, in tree order.
     /// Mirrors `getElementsByTagName(name)` minus the live-collection
     /// semantics — JS gets a snapshot.
     #[napi]
-    pub fn find_all_by_local_name<'a>(&self, name: String, env: &'a Env) -> Vec<Object<'a>> {
-        let state = self.doc.base.borrow();
+    pub fn find_all_by_local_name<'a>(
+        &self,
+        name: String,
+        env: &'a Env,
+    ) -> Result<Vec<Object<'a>>> {
+        let state = self.doc.read()?;
         let needle = LocalName::from(name.as_str());
         let ids = dfs_collect(&state, state.root_node().id, |n| {
             n.data.is_element_with_tag_name(&needle)
         });
         drop(state);
-        ids.into_iter()
+        Ok(ids
+            .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     /// All element ids matching the given local tag name, scoped to the
@@ -863,44 +909,55 @@ impl NativeDoc {
         root: &NativeNode,
         name: String,
         env: &'a Env,
-    ) -> Vec<Object<'a>> {
-        let state = self.doc.base.borrow();
+    ) -> Result<Vec<Object<'a>>> {
+        let state = self.doc.read()?;
         let needle = LocalName::from(name.as_str());
         let ids = dfs_collect_children(&state, root.node_id, |n| {
             n.data.is_element_with_tag_name(&needle)
         });
         drop(state);
-        ids.into_iter()
+        Ok(ids
+            .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     /// All element ids in the subtree rooted at `root_id` (exclusive),
     /// i.e. every descendant element regardless of tag. Backs
     /// `element.getElementsByTagName("*")`.
     #[napi]
-    pub fn find_all_elements_in<'a>(&self, root: &NativeNode, env: &'a Env) -> Vec<Object<'a>> {
-        let state = self.doc.base.borrow();
+    pub fn find_all_elements_in<'a>(
+        &self,
+        root: &NativeNode,
+        env: &'a Env,
+    ) -> Result<Vec<Object<'a>>> {
+        let state = self.doc.read()?;
         let ids = dfs_collect_children(&state, root.node_id, |n| {
             n.data.downcast_element().is_some()
         });
         drop(state);
-        ids.into_iter()
+        Ok(ids
+            .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     /// All element ids whose `class` attribute contains `class_name` as
     /// one of its whitespace-separated tokens. Document-scoped.
     #[napi]
-    pub fn find_all_by_class_name<'a>(&self, class_name: String, env: &'a Env) -> Vec<Object<'a>> {
-        let state = self.doc.base.borrow();
+    pub fn find_all_by_class_name<'a>(
+        &self,
+        class_name: String,
+        env: &'a Env,
+    ) -> Result<Vec<Object<'a>>> {
+        let state = self.doc.read()?;
         let needle = class_name;
         let ids = dfs_collect(&state, state.root_node().id, |n| node_has_class(n, &needle));
         drop(state);
-        ids.into_iter()
+        Ok(ids
+            .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     /// All element ids whose `class` attribute contains `class_name`,
@@ -911,47 +968,332 @@ impl NativeDoc {
         root: &NativeNode,
         class_name: String,
         env: &'a Env,
-    ) -> Vec<Object<'a>> {
-        let state = self.doc.base.borrow();
+    ) -> Result<Vec<Object<'a>>> {
+        let state = self.doc.read()?;
         let needle = class_name;
         let ids = dfs_collect_children(&state, root.node_id, |n| node_has_class(n, &needle));
         drop(state);
-        ids.into_iter()
+        Ok(ids
+            .into_iter()
             .filter_map(|id| wrap_node(&self.doc, id, env).ok())
-            .collect()
+            .collect())
     }
 
     /// `<html>` element. Uses the `local_name!` macro for a zero-cost
     /// atom comparison. Returns None for documents without an `<html>`
     /// root (unusual but possible during partial parsing).
     #[napi]
-    pub fn html_element<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
-        let id = self.find_first_static(local_name!("html"))?;
-        wrap_node(&self.doc, id, env).ok()
+    pub fn html_element<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let Some(id) = self.find_first_static(local_name!("html"))? else {
+            return Ok(None);
+        };
+        Ok(wrap_node(&self.doc, id, env).ok())
+    }
+
+    #[napi]
+    pub fn head_element<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let Some(id) = self.find_first_static(local_name!("head"))? else {
+            return Ok(None);
+        };
+        Ok(wrap_node(&self.doc, id, env).ok())
     }
 
     #[napi]
-    pub fn head_element<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
-        let id = self.find_first_static(local_name!("head"))?;
-        wrap_node(&self.doc, id, env).ok()
+    pub fn body_element<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let Some(id) = self.find_first_static(local_name!("body"))? else {
+            return Ok(None);
+        };
+        Ok(wrap_node(&self.doc, id, env).ok())
     }
+}
 
+#[napi]
+impl NativeDoc {
+    // -- Hit testing --------------------------------------------------------
+    //
+    // Coordinates are CSS pixels relative to the viewport (like
+    // `MouseEvent.clientX/Y`). blitz hit-tests in document space, so the
+    // viewport scroll offset is added before calling `BaseDocument::hit`.
+    // Layout must be current; call `resolve()` first after mutating a
+    // detached (window-less) document.
+
+    /// Topmost element at the given viewport coordinate. Mirrors
+    /// `document.elementFromPoint`. Returns null for negative coordinates
+    /// or when nothing was hit.
     #[napi]
-    pub fn body_element<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
-        let id = self.find_first_static(local_name!("body"))?;
-        wrap_node(&self.doc, id, env).ok()
+    pub fn element_from_point<'a>(
+        &self,
+        x: f64,
+        y: f64,
+        env: &'a Env,
+    ) -> Result<Option<Object<'a>>> {
+        let hit = {
+            let state = self.doc.read()?;
+            hit_element(&state, x, y)
+        };
+        match hit {
+            Some(id) => Ok(Some(wrap_node(&self.doc, id, env)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Elements at the given viewport coordinate, topmost first. Mirrors
+    /// `document.elementsFromPoint`.
+    ///
+    /// blitz only reports a single hit, so this is the hit element followed
+    /// by all of its ancestors up to `<html>`, as browsers list them even
+    /// where `<body>` is shorter than the viewport. Siblings overlapping
+    /// the point underneath the hit element are not reported.
+    #[napi]
+    pub fn elements_from_point<'a>(&self, x: f64, y: f64, env: &'a Env) -> Result<Vec<Object<'a>>> {
+        let ids: Vec<NodeId> = {
+            let state = self.doc.read()?;
+            let Some(top) = hit_element(&state, x, y) else {
+                return Ok(Vec::new());
+            };
+            let mut ids = vec![top];
+            let mut cursor = state.get_node(top).and_then(|n| n.parent);
+            while let Some(id) = cursor {
+                let Some(node) = state.get_node(id) else {
+                    break;
+                };
+                if node.is_element() {
+                    ids.push(id);
+                }
+                cursor = node.parent;
+            }
+            ids
+        };
+        let mut out = Vec::with_capacity(ids.len());
+        for id in ids {
+            out.push(wrap_node(&self.doc, id, env)?);
+        }
+        Ok(out)
+    }
+
+    // -- Focus ----------------------------------------------------------------
+
+    /// The focused element, or null when nothing has focus. The JS
+    /// `document.activeElement` getter falls back to `<body>`.
+    #[napi]
+    pub fn focused_element<'a>(&self, env: &'a Env) -> Result<Option<Object<'a>>> {
+        let focused = self.doc.read()?.get_focussed_node_id();
+        match focused.filter(|&id| self.doc.is_in_document(id)) {
+            Some(id) => Ok(Some(wrap_node(&self.doc, id, env)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Move focus to the next (`forward`) or previous element in `tabindex`
+    /// order, as the Tab key does, firing the focus events. Returns false
+    /// when the document has nothing tabbable. Layout must be resolved:
+    /// unstyled (`display: none` or never resolved) elements are skipped.
+    #[napi]
+    pub fn move_focus(&self, forward: bool, env: Env) -> Result<bool> {
+        let target = focus_sequential(&mut self.doc.write()?, forward);
+        if target.is_none() {
+            return Ok(false);
+        }
+        self.doc.mark_host_dirty();
+        sync_focus(&self.doc, &env)?;
+        Ok(true)
+    }
+
+    // -- Selection / Range ----------------------------------------------------
+    //
+    // Backing for the JS `Selection` and `Range` classes, which hold their
+    // boundary points as (node, offset) pairs and pass them back in. See
+    // `dom/selection.rs` for how text offsets map onto the layout.
+
+    /// Anchor (`focus == false`) or focus node of the document selection.
+    #[napi]
+    pub fn selection_node<'a>(&self, focus: bool, env: &'a Env) -> Result<Option<Object<'a>>> {
+        self.doc.check_live()?;
+        let Some(selection) = self.doc.selection.get() else {
+            return Ok(None);
+        };
+        let point = if focus {
+            selection.focus
+        } else {
+            selection.anchor
+        };
+        Ok(Some(wrap_node(&self.doc, point.node, env)?))
+    }
+
+    /// Anchor (`focus == false`) or focus offset of the document selection.
+    #[napi]
+    pub fn selection_offset(&self, focus: bool) -> Result<Option<u32>> {
+        self.doc.check_live()?;
+        Ok(self.doc.selection.get().map(|selection| {
+            if focus {
+                selection.focus.offset
+            } else {
+                selection.anchor.offset
+            }
+        }))
     }
+
+    /// Replace the document selection. Fires `selectionchange` at the
+    /// document if it changed. Offsets beyond the node length are an error.
+    #[napi]
+    pub fn set_selection(
+        &self,
+        anchor_node: &NativeNode,
+        anchor_offset: u32,
+        focus_node: &NativeNode,
+        focus_offset: u32,
+        env: Env,
+    ) -> Result<()> {
+        let anchor = self.boundary(anchor_node, anchor_offset)?;
+        let focus = self.boundary(focus_node, focus_offset)?;
+        set_selection(&self.doc, Some(DocSelection { anchor, focus }), &env)
+    }
+
+    /// Remove the document selection, firing `selectionchange` if there
+    /// was one.
+    #[napi]
+    pub fn clear_selection(&self, env: Env) -> Result<()> {
+        self.doc.check_live()?;
+        set_selection(&self.doc, None, &env)
+    }
+
+    /// Tree order of two boundary points: -1, 0 or 1.
+    #[napi]
+    pub fn compare_boundary_points(
+        &self,
+        a_node: &NativeNode,
+        a_offset: u32,
+        b_node: &NativeNode,
+        b_offset: u32,
+    ) -> Result<i32> {
+        let a = Boundary {
+            node: a_node.node_id,
+            offset: a_offset,
+        };
+        let b = Boundary {
+            node: b_node.node_id,
+            offset: b_offset,
+        };
+        Ok(compare_boundaries(&self.doc.read()?, a, b) as i32)
+    }
+
+    /// Boundary length of a node: UTF-16 length for text, child count
+    /// otherwise.
+    #[napi]
+    pub fn node_length(&self, node: &NativeNode) -> Result<u32> {
+        Ok(node_length(&self.doc.read()?, node.node_id))
+    }
+
+    /// Text covered by a range. Mirrors `Range.toString()`.
+    #[napi]
+    pub fn range_text(
+        &self,
+        start_node: &NativeNode,
+        start_offset: u32,
+        end_node: &NativeNode,
+        end_offset: u32,
+    ) -> Result<String> {
+        let (start, end) = range_bounds(start_node, start_offset, end_node, end_offset);
+        Ok(range_text(&self.doc.read()?, start, end))
+    }
+
+    /// Rects of the text covered by a range, one per line fragment, in the
+    /// same coordinates as `getBoundingClientRect`. Uses the last resolved
+    /// layout.
+    #[napi]
+    pub fn range_client_rects(
+        &self,
+        start_node: &NativeNode,
+        start_offset: u32,
+        end_node: &NativeNode,
+        end_offset: u32,
+    ) -> Result<Vec<DomRect>> {
+        let (start, end) = range_bounds(start_node, start_offset, end_node, end_offset);
+        Ok(range_rects(&self.doc.read()?, start, end))
+    }
+}
+
+impl NativeDoc {
+    /// Validated boundary point: the offset must not exceed the node length.
+    fn boundary(&self, node: &NativeNode, offset: u32) -> Result<Boundary> {
+        if offset > node_length(&self.doc.read()?, node.node_id) {
+            return Err(Error::from_reason(format!(
+                "set_selection: offset {offset} is larger than the node's length"
+            )));
+        }
+        Ok(Boundary {
+            node: node.node_id,
+            offset,
+        })
+    }
+}
+
+fn range_bounds(
+    start_node: &NativeNode,
+    start_offset: u32,
+    end_node: &NativeNode,
+    end_offset: u32,
+) -> (Boundary, Boundary) {
+    (
+        Boundary {
+            node: start_node.node_id,
+            offset: start_offset,
+        },
+        Boundary {
+            node: end_node.node_id,
+            offset: end_offset,
+        },
+    )
+}
+
+/// Hit-test a viewport coordinate and resolve it to an element: anonymous
+/// blocks are normalized exactly like DOM event targets, and text hits are
+/// promoted to their parent element.
+pub(crate) fn hit_element(state: &BaseDocument, x: f64, y: f64) -> Option<NodeId> {
+    if x < 0.0 || y < 0.0 {
+        return None;
+    }
+    let scroll = state.viewport_scroll();
+    let hit = state.hit((x + scroll.x) as f32, (y + scroll.y) as f32)?;
+    let mut node_id = normalize_hit_target(state, hit.node_id);
+    loop {
+        let node = state.get_node(node_id)?;
+        if node.is_element() {
+            return Some(node_id);
+        }
+        node_id = node.parent?;
+    }
+}
+
+/// The element under the pointer: the hovered node or its nearest element
+/// ancestor.
+pub(crate) fn hovered_element(state: &BaseDocument) -> Option<NodeId> {
+    let mut node = state.get_node(state.get_hover_node_id()?)?;
+    while !node.is_element() {
+        node = state.get_node(node.parent?)?;
+    }
+    Some(node.id)
+}
+
+/// `<body>`, or the root element when there is none. Default target of
+/// events that have no better one (no focus, nothing under the cursor).
+pub(crate) fn body_or_root(state: &BaseDocument) -> NodeId {
+    state
+        .query_selector("body")
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| state.root_element().id)
 }
 
 impl NativeDoc {
     /// Shared fast-path for `local_name!`-constructed atoms. Bypasses the
     /// `LocalName::from(&str)` allocation that `find_first_by_local_name`
     /// has to do for the runtime-string case.
-    fn find_first_static(&self, needle: LocalName) -> Option<NodeId> {
-        let state = self.doc.base.borrow();
-        dfs_find(&state, state.root_node().id, |n| {
+    fn find_first_static(&self, needle: LocalName) -> Result<Option<NodeId>> {
+        let state = self.doc.read()?;
+        Ok(dfs_find(&state, state.root_node().id, |n| {
             n.data.is_element_with_tag_name(&needle)
-        })
+        }))
     }
 }
 
@@ -997,7 +1339,7 @@ where
 
 /// Collect every node id (pre-order, starting from `root` inclusive)
 /// where `pred` returns true.
-fn dfs_collect<F>(doc: &BaseDocument, root: NodeId, pred: F) -> Vec<NodeId>
+pub(crate) fn dfs_collect<F>(doc: &BaseDocument, root: NodeId, pred: F) -> Vec<NodeId>
 where
     F: Fn(&blitz::dom::Node) -> bool,
 {
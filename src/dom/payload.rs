@@ -5,10 +5,29 @@
 //! so cloning is cheap (one refcount bump). Values that require
 //! decomposition (enum-to-string etc.) are pre-computed at construction
 //! time in `event.rs` and stored alongside the `Arc`.
+//!
+//! The exception is `DataTransfer` state (`DataTransferItems`,
+//! `DragData::drop_effect`): listeners write it by design, and it is
+//! shared through an `Rc` so Rust reads their changes back.
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use std::sync::Arc;
+use blitz::{
+    dom::NodeId,
+    traits::events::{BlitzKeyEvent, BlitzPointerEvent, BlitzPointerId, BlitzWheelEvent},
+};
+use keyboard_types::Modifiers;
+use napi::{Env, bindgen_prelude::Object};
 
-use blitz::traits::events::{BlitzKeyEvent, BlitzPointerEvent, BlitzWheelEvent};
+use crate::dom::{
+    doc::{SharedDoc, wrap_node},
+    pointer_moves::PointerSamples,
+};
 
 // ── EventPayload ────────────────────────────────────────────────────
 
@@ -26,6 +45,57 @@ pub struct EventPayload {
     pub(crate) key: Option<KeyData>,
     pub(crate) input: Option<InputData>,
     pub(crate) ime: Option<ImeData>,
+    /// `DataTransfer` of `copy` / `cut` / `paste`. Shared with the Rust
+    /// side, which reads back what listeners set.
+    pub(crate) clipboard: Option<DataTransferItems>,
+    /// Dragged files and position of drag-and-drop events.
+    pub(crate) drag: Option<DragData>,
+    /// Details of window lifecycle events.
+    pub(crate) window: Option<WindowEventData>,
+    /// Contacts of touch events.
+    pub(crate) touch: Option<TouchData>,
+    /// Accumulated values of trackpad gesture events.
+    pub(crate) gesture: Option<GestureData>,
+    /// Node exposed as `event.relatedTarget` (focus and hover
+    /// transitions). Not a JS property; `dispatch_payload` installs a lazy
+    /// getter for it.
+    pub(crate) related_target: Option<NodeId>,
+    /// When the event was created, in milliseconds since the Unix epoch.
+    pub(crate) time_stamp: f64,
+}
+
+impl EventPayload {
+    /// A payload with no event-specific details, for events synthesized on
+    /// the Rust side (shell lifecycle, pointer capture, ...). Details are
+    /// filled in with struct update syntax.
+    pub(crate) fn new(event_type: &str, bubbles: bool, cancelable: bool) -> Self {
+        Self {
+            event_type: event_type.to_string(),
+            bubbles,
+            cancelable,
+            pointer: None,
+            wheel: None,
+            key: None,
+            input: None,
+            ime: None,
+            clipboard: None,
+            drag: None,
+            window: None,
+            touch: None,
+            gesture: None,
+            related_target: None,
+            time_stamp: now_ms(),
+        }
+    }
+}
+
+/// Current time in milliseconds since the Unix epoch, the clock of
+/// `performance.timeOrigin`.
+pub(crate) fn now_ms() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64() * 1000.0)
+        .unwrap_or(0.0)
 }
 
 #[napi]
@@ -45,6 +115,12 @@ impl EventPayload {
     pub fn cancelable(&self) -> bool {
         self.cancelable
     }
+    /// Creation time in milliseconds since the Unix epoch; JS subtracts
+    /// `performance.timeOrigin` for `event.timeStamp`.
+    #[napi(getter)]
+    pub fn time_stamp(&self) -> f64 {
+        self.time_stamp
+    }
     /// Pointer/mouse details, when applicable.
     #[napi(getter)]
     pub fn pointer(&self) -> Option<PointerData> {
@@ -70,6 +146,91 @@ impl EventPayload {
     pub fn ime(&self) -> Option<ImeData> {
         self.ime.clone()
     }
+    /// `event.clipboardData` of clipboard events.
+    #[napi(getter)]
+    pub fn clipboard_data(&self) -> Option<DataTransferItems> {
+        self.clipboard.clone()
+    }
+    /// Drag-and-drop details, when applicable.
+    #[napi(getter)]
+    pub fn drag(&self) -> Option<DragData> {
+        self.drag.clone()
+    }
+    /// Window lifecycle details (`resize`, `move`, ...), when applicable.
+    #[napi(getter)]
+    pub fn window(&self) -> Option<WindowEventData> {
+        self.window.clone()
+    }
+    /// Touch contacts (`touchstart`, ...), when applicable.
+    #[napi(getter)]
+    pub fn touch(&self) -> Option<TouchData> {
+        self.touch.clone()
+    }
+    /// Trackpad gesture details (`gesturestart`, ...), when applicable.
+    #[napi(getter)]
+    pub fn gesture(&self) -> Option<GestureData> {
+        self.gesture.clone()
+    }
+}
+
+/// `getModifierState(key)` for DOM modifier key values. Unknown names are
+/// never active.
+fn modifier_state(mods: Modifiers, key: &str) -> bool {
+    let flag = match key {
+        "Alt" => Modifiers::ALT,
+        "AltGraph" => Modifiers::ALT_GRAPH,
+        "CapsLock" => Modifiers::CAPS_LOCK,
+        "Control" => Modifiers::CONTROL,
+        "Fn" => Modifiers::FN,
+        "FnLock" => Modifiers::FN_LOCK,
+        "Meta" => Modifiers::META,
+        "NumLock" => Modifiers::NUM_LOCK,
+        "ScrollLock" => Modifiers::SCROLL_LOCK,
+        "Shift" => Modifiers::SHIFT,
+        "Symbol" => Modifiers::SYMBOL,
+        "SymbolLock" => Modifiers::SYMBOL_LOCK,
+        "Hyper" => Modifiers::HYPER,
+        "Super" => Modifiers::SUPER,
+        _ => return false,
+    };
+    mods.contains(flag)
+}
+
+/// `modsBits`, `ctrlKey` / `shiftKey` / `altKey` / `metaKey` and
+/// `getModifierState` of an event data class whose modifiers are at
+/// `self.<field path>`.
+macro_rules! modifier_getters {
+    ($ty:ty, $($mods:ident).+) => {
+        #[napi]
+        impl $ty {
+            #[napi(getter)]
+            pub fn mods_bits(&self) -> u32 {
+                self.$($mods).+.bits()
+            }
+            #[napi(getter)]
+            pub fn ctrl_key(&self) -> bool {
+                self.$($mods).+.contains(Modifiers::CONTROL)
+            }
+            #[napi(getter)]
+            pub fn shift_key(&self) -> bool {
+                self.$($mods).+.contains(Modifiers::SHIFT)
+            }
+            #[napi(getter)]
+            pub fn alt_key(&self) -> bool {
+                self.$($mods).+.contains(Modifiers::ALT)
+            }
+            #[napi(getter)]
+            pub fn meta_key(&self) -> bool {
+                self.$($mods).+.contains(Modifiers::META)
+            }
+            /// Whether the modifier or lock named by its DOM key value
+            /// (`"Control"`, `"CapsLock"`, ...) is active.
+            #[napi]
+            pub fn get_modifier_state(&self, key: String) -> bool {
+                modifier_state(self.$($mods).+, &key)
+            }
+        }
+    };
 }
 
 // ── PointerData ─────────────────────────────────────────────────────
@@ -80,6 +241,42 @@ pub struct PointerData {
     pub(crate) inner: Arc<BlitzPointerEvent>,
     pub(crate) kind: String,
     pub(crate) pointer_id: f64,
+    /// Coalesced and predicted samples; `pointermove` only.
+    pub(crate) samples: Option<Arc<PointerSamples>>,
+    /// Filled in per dispatch by `pointer_info::decorate`.
+    pub(crate) offset_x: f64,
+    pub(crate) offset_y: f64,
+    pub(crate) movement_x: f64,
+    pub(crate) movement_y: f64,
+    pub(crate) detail: u32,
+}
+
+impl PointerData {
+    pub(crate) fn new(event: BlitzPointerEvent) -> Self {
+        let (kind, pointer_id) = match event.id {
+            BlitzPointerId::Mouse => ("mouse", 1.0),
+            BlitzPointerId::Pen => ("pen", 1.0),
+            BlitzPointerId::Finger(id) => ("finger", id as f64),
+        };
+        Self {
+            inner: Arc::new(event),
+            kind: kind.to_string(),
+            pointer_id,
+            samples: None,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            movement_x: 0.0,
+            movement_y: 0.0,
+            detail: 0,
+        }
+    }
+
+    fn sample_list(&self, pick: impl Fn(&PointerSamples) -> &[BlitzPointerEvent]) -> Vec<Self> {
+        self.samples
+            .as_deref()
+            .map(|samples| pick(samples).iter().cloned().map(Self::new).collect())
+            .unwrap_or_default()
+    }
 }
 
 #[napi]
@@ -146,9 +343,173 @@ impl PointerData {
     pub fn twist(&self) -> u32 {
         self.inner.details.twist as u32
     }
+    /// Position relative to the target's border box.
+    #[napi(getter)]
+    pub fn offset_x(&self) -> f64 {
+        self.offset_x
+    }
+    #[napi(getter)]
+    pub fn offset_y(&self) -> f64 {
+        self.offset_y
+    }
+    /// Distance moved since the previous move of the same pointer, in
+    /// screen pixels; `0` for other events.
+    #[napi(getter)]
+    pub fn movement_x(&self) -> f64 {
+        self.movement_x
+    }
+    #[napi(getter)]
+    pub fn movement_y(&self) -> f64 {
+        self.movement_y
+    }
+    /// Click count of `mousedown` / `mouseup` / `click` (`2` for a
+    /// double click); `0` for other events.
+    #[napi(getter)]
+    pub fn detail(&self) -> u32 {
+        self.detail
+    }
+    /// Samples merged into this `pointermove`, oldest first and ending with
+    /// the event itself. Empty for other events.
+    #[napi]
+    pub fn get_coalesced_events(&self) -> Vec<PointerData> {
+        self.sample_list(|samples| &samples.coalesced)
+    }
+    /// Expected next samples of this `pointermove`, extrapolated from the
+    /// last movement. Empty for other events or before a second sample.
+    #[napi]
+    pub fn get_predicted_events(&self) -> Vec<PointerData> {
+        self.sample_list(|samples| &samples.predicted)
+    }
+}
+
+modifier_getters!(PointerData, inner.mods);
+
+// ── TouchData ───────────────────────────────────────────────────────
+
+/// Contact lists of a touch event (see `dom/touch.rs`).
+#[derive(Clone)]
+#[napi]
+pub struct TouchData {
+    pub(crate) touches: Vec<TouchPoint>,
+    pub(crate) target_touches: Vec<TouchPoint>,
+    pub(crate) changed_touches: Vec<TouchPoint>,
+    pub(crate) mods: Modifiers,
+}
+
+#[napi]
+impl TouchData {
+    /// Every finger still on the surface.
+    #[napi(getter)]
+    pub fn touches(&self) -> Vec<TouchPoint> {
+        self.touches.clone()
+    }
+    /// Fingers still on the surface that started on the event's target.
+    #[napi(getter)]
+    pub fn target_touches(&self) -> Vec<TouchPoint> {
+        self.target_touches.clone()
+    }
+    /// The finger this event is about.
+    #[napi(getter)]
+    pub fn changed_touches(&self) -> Vec<TouchPoint> {
+        self.changed_touches.clone()
+    }
+}
+
+modifier_getters!(TouchData, mods);
+
+/// One finger of a touch event.
+#[derive(Clone)]
+#[napi]
+pub struct TouchPoint {
+    pub(crate) doc: Rc<SharedDoc>,
+    pub(crate) identifier: f64,
+    /// Element the contact started on.
+    pub(crate) target: NodeId,
+    pub(crate) pointer: Arc<BlitzPointerEvent>,
+}
+
+#[napi]
+impl TouchPoint {
+    /// Finger id, stable while the finger stays down.
+    #[napi(getter)]
+    pub fn identifier(&self) -> f64 {
+        self.identifier
+    }
+    /// The element the contact started on.
+    #[napi]
+    pub fn target<'a>(&self, env: &'a Env) -> Option<Object<'a>> {
+        wrap_node(&self.doc, self.target, env).ok()
+    }
+    #[napi(getter)]
+    pub fn page_x(&self) -> f64 {
+        self.pointer.coords.page_x as f64
+    }
+    #[napi(getter)]
+    pub fn page_y(&self) -> f64 {
+        self.pointer.coords.page_y as f64
+    }
+    #[napi(getter)]
+    pub fn client_x(&self) -> f64 {
+        self.pointer.coords.client_x as f64
+    }
+    #[napi(getter)]
+    pub fn client_y(&self) -> f64 {
+        self.pointer.coords.client_y as f64
+    }
+    #[napi(getter)]
+    pub fn screen_x(&self) -> f64 {
+        self.pointer.coords.screen_x as f64
+    }
+    #[napi(getter)]
+    pub fn screen_y(&self) -> f64 {
+        self.pointer.coords.screen_y as f64
+    }
+    /// Touch pressure in `[0, 1]`; `0` when the screen does not report it.
+    #[napi(getter)]
+    pub fn force(&self) -> f64 {
+        self.pointer.details.pressure
+    }
+}
+
+// ── GestureData ─────────────────────────────────────────────────────
+
+/// Details of a trackpad gesture event (see `dom/gesture.rs`). Values are
+/// accumulated since `gesturestart`.
+#[derive(Clone)]
+#[napi]
+pub struct GestureData {
+    pub(crate) kind: String,
+    pub(crate) scale: f64,
+    pub(crate) rotation: f64,
+    pub(crate) delta_x: f64,
+    pub(crate) delta_y: f64,
+}
+
+#[napi]
+impl GestureData {
+    /// "pinch" | "rotate" | "pan"
+    #[napi(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+    /// Pinch scale; `1` at the start, `2` for twice the distance.
     #[napi(getter)]
-    pub fn mods_bits(&self) -> u32 {
-        self.inner.mods.bits()
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+    /// Rotation in degrees, counterclockwise positive.
+    #[napi(getter)]
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+    /// Pan distance in CSS pixels.
+    #[napi(getter)]
+    pub fn delta_x(&self) -> f64 {
+        self.delta_x
+    }
+    #[napi(getter)]
+    pub fn delta_y(&self) -> f64 {
+        self.delta_y
     }
 }
 
@@ -198,12 +559,10 @@ impl WheelData {
     pub fn buttons(&self) -> u32 {
         self.inner.buttons.bits() as u32
     }
-    #[napi(getter)]
-    pub fn mods_bits(&self) -> u32 {
-        self.inner.mods.bits()
-    }
 }
 
+modifier_getters!(WheelData, inner.mods);
+
 // ── KeyData ─────────────────────────────────────────────────────────
 
 #[derive(Clone)]
@@ -230,10 +589,6 @@ impl KeyData {
         self.inner.location as u32
     }
     #[napi(getter)]
-    pub fn mods_bits(&self) -> u32 {
-        self.inner.modifiers.bits()
-    }
-    #[napi(getter)]
     pub fn repeat(&self) -> bool {
         self.inner.is_auto_repeating
     }
@@ -252,6 +607,8 @@ impl KeyData {
     }
 }
 
+modifier_getters!(KeyData, inner.modifiers);
+
 // ── InputData ───────────────────────────────────────────────────────
 
 #[derive(Clone)]
@@ -268,6 +625,183 @@ impl InputData {
     }
 }
 
+// ── DataTransferItems ─────────────────────────────────────────────
+
+/// String data of a `DataTransfer` (`clipboardData`, drag data), keyed by
+/// format. Clones share their items, so Rust reads back what listeners
+/// set.
+#[napi]
+#[derive(Clone, Default)]
+pub struct DataTransferItems {
+    items: Rc<RefCell<Vec<(String, String)>>>,
+}
+
+/// `"text"` is an alias of `"text/plain"`; formats are case-insensitive.
+fn normalize_format(format: &str) -> String {
+    match format.to_ascii_lowercase().as_str() {
+        "text" => "text/plain".to_string(),
+        other => other.to_string(),
+    }
+}
+
+impl DataTransferItems {
+    pub(crate) fn get(&self, format: &str) -> Option<String> {
+        let format = normalize_format(format);
+        self.items
+            .borrow()
+            .iter()
+            .find(|(f, _)| *f == format)
+            .map(|(_, data)| data.clone())
+    }
+}
+
+#[napi]
+impl DataTransferItems {
+    /// Data stored for `format`, or "" when there is none.
+    #[napi]
+    pub fn get_data(&self, format: String) -> String {
+        self.get(&format).unwrap_or_default()
+    }
+
+    #[napi]
+    pub fn set_data(&self, format: String, data: String) {
+        let format = normalize_format(&format);
+        let mut items = self.items.borrow_mut();
+        match items.iter_mut().find(|(f, _)| *f == format) {
+            Some(item) => item.1 = data,
+            None => items.push((format, data)),
+        }
+    }
+
+    /// Remove `format`, or every format when omitted.
+    #[napi]
+    pub fn clear_data(&self, format: Option<String>) {
+        let mut items = self.items.borrow_mut();
+        match format {
+            Some(format) => {
+                let format = normalize_format(&format);
+                items.retain(|(f, _)| *f != format);
+            }
+            None => items.clear(),
+        }
+    }
+
+    /// Formats currently stored, in insertion order.
+    #[napi(getter)]
+    pub fn types(&self) -> Vec<String> {
+        self.items.borrow().iter().map(|(f, _)| f.clone()).collect()
+    }
+}
+
+// ── DragData ────────────────────────────────────────────────────────
+
+/// Details of a drag-and-drop event. Every event of one drag shares the
+/// same `data` and `drop_effect`, so values set by listeners (in
+/// `dragstart`, `dragover`) are seen by the later events and by Rust.
+#[derive(Clone)]
+#[napi]
+pub struct DragData {
+    pub(crate) files: Vec<String>,
+    pub(crate) data: DataTransferItems,
+    pub(crate) drop_effect: Rc<RefCell<String>>,
+    pub(crate) client_x: f64,
+    pub(crate) client_y: f64,
+}
+
+#[napi]
+impl DragData {
+    /// Paths of files dragged in from the OS; empty for element drags.
+    #[napi(getter)]
+    pub fn files(&self) -> Vec<String> {
+        self.files.clone()
+    }
+    /// String data set by `dragstart` listeners.
+    #[napi(getter)]
+    pub fn data(&self) -> DataTransferItems {
+        self.data.clone()
+    }
+    /// "none" | "copy" | "link" | "move"
+    #[napi(getter)]
+    pub fn drop_effect(&self) -> String {
+        self.drop_effect.borrow().clone()
+    }
+    #[napi(setter)]
+    pub fn set_drop_effect(&mut self, effect: String) {
+        if matches!(effect.as_str(), "none" | "copy" | "link" | "move") {
+            *self.drop_effect.borrow_mut() = effect;
+        }
+    }
+    #[napi(getter)]
+    pub fn client_x(&self) -> f64 {
+        self.client_x
+    }
+    #[napi(getter)]
+    pub fn client_y(&self) -> f64 {
+        self.client_y
+    }
+}
+
+// ── WindowEventData ─────────────────────────────────────────────────
+
+/// Details of a window lifecycle event. Only the fields relevant to the
+/// event are set; sizes and positions are physical pixels, like
+/// `NativeWindow.getSize`.
+#[derive(Clone, Default)]
+#[napi]
+pub struct WindowEventData {
+    pub(crate) width: Option<u32>,
+    pub(crate) height: Option<u32>,
+    pub(crate) x: Option<i32>,
+    pub(crate) y: Option<i32>,
+    pub(crate) scale_factor: Option<f64>,
+    pub(crate) theme: Option<String>,
+    pub(crate) movement_x: Option<f64>,
+    pub(crate) movement_y: Option<f64>,
+}
+
+#[napi]
+impl WindowEventData {
+    /// New surface width (`resize`).
+    #[napi(getter)]
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+    /// New surface height (`resize`).
+    #[napi(getter)]
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+    /// New outer position (`move`).
+    #[napi(getter)]
+    pub fn x(&self) -> Option<i32> {
+        self.x
+    }
+    #[napi(getter)]
+    pub fn y(&self) -> Option<i32> {
+        self.y
+    }
+    /// New scale factor (`scalefactorchange`).
+    #[napi(getter)]
+    pub fn scale_factor(&self) -> Option<f64> {
+        self.scale_factor
+    }
+    /// "light" | "dark" (`themechange`).
+    #[napi(getter)]
+    pub fn theme(&self) -> Option<String> {
+        self.theme.clone()
+    }
+    /// Raw pointer motion in device units (`motion`, while the cursor is
+    /// locked).
+    #[napi(getter)]
+    pub fn movement_x(&self) -> Option<f64> {
+        self.movement_x
+    }
+    #[napi(getter)]
+    pub fn movement_y(&self) -> Option<f64> {
+        self.movement_y
+    }
+}
+
 // ── ImeData ─────────────────────────────────────────────────────────
 
 #[derive(Clone)]
@@ -0,0 +1,165 @@
+//! Pointer capture: `setPointerCapture` / `releasePointerCapture`.
+//!
+//! Follows the Pointer Events model of a *pending* and an *active* capture
+//! target per pointer id:
+//!
+//! - `setPointerCapture` / `releasePointerCapture` only touch the pending
+//!   map. Nothing is dispatched from inside the JS call.
+//! - Right before the next pointer event for that pointer id is dispatched,
+//!   `process_pending_capture` compares pending against active and fires
+//!   `lostpointercapture` on the old target / `gotpointercapture` on the new
+//!   one, then promotes pending to active.
+//! - While a capture is active, `JsEventHandler` retargets `pointermove` /
+//!   `pointerup` (and their mouse compatibility events) to the capturing
+//!   node instead of the hit-tested chain.
+//! - `pointerup` and `pointercancel` implicitly release the capture;
+//!   `lostpointercapture` follows immediately. blitz has no DOM event for
+//!   a cancel, so `WindowDocument` releases it (`handle_capture_cancel`).
+//! - Removing the capturing node from the document drops the pending
+//!   capture; the next pointer event then fires `lostpointercapture` at the
+//!   document, since the node itself is no longer connected.
+
+use std::{collections::HashMap, rc::Rc};
+
+use blitz::{
+    dom::{BaseDocument, NodeId},
+    traits::events::UiEvent,
+};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::dispatch_synthetic,
+    payload::{EventPayload, PointerData},
+};
+
+/// Per-document pointer capture targets, keyed by pointer id.
+#[derive(Default)]
+pub struct PointerCaptures {
+    /// Capture target that pointer events are currently redirected to.
+    active: HashMap<u64, NodeId>,
+    /// Capture target requested from JS, applied at the next pointer event.
+    pending: HashMap<u64, NodeId>,
+}
+
+impl PointerCaptures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request capture of `pointer_id` by `node_id`.
+    pub fn set_pending(&mut self, pointer_id: u64, node_id: NodeId) {
+        self.pending.insert(pointer_id, node_id);
+    }
+
+    /// Release `pointer_id` if `node_id` holds (or requested) its capture.
+    pub fn release_pending(&mut self, pointer_id: u64, node_id: NodeId) {
+        if self.pending.get(&pointer_id) == Some(&node_id) {
+            self.pending.remove(&pointer_id);
+        }
+    }
+
+    /// Implicit release (pointerup / pointercancel).
+    pub fn release_pointer(&mut self, pointer_id: u64) {
+        self.pending.remove(&pointer_id);
+    }
+
+    /// `Element.hasPointerCapture`: per spec this reflects the pending
+    /// target, so it turns true synchronously after `setPointerCapture`.
+    pub fn has_capture(&self, pointer_id: u64, node_id: NodeId) -> bool {
+        self.pending.get(&pointer_id) == Some(&node_id)
+    }
+
+    /// Active capture target for `pointer_id`, if any.
+    pub fn target(&self, pointer_id: u64) -> Option<NodeId> {
+        self.active.get(&pointer_id).copied()
+    }
+
+    /// Drop pending captures whose target is `root` or one of its
+    /// descendants. Called before a subtree is detached from the document.
+    pub fn release_subtree(&mut self, base: &BaseDocument, root: NodeId) {
+        self.pending
+            .retain(|_, &mut target| !is_inclusive_descendant(base, target, root));
+    }
+
+    /// Promote the pending target to active and report the transition as
+    /// `(lost, got)`. `None` when nothing changed.
+    fn settle(&mut self, pointer_id: u64) -> Option<(Option<NodeId>, Option<NodeId>)> {
+        let pending = self.pending.get(&pointer_id).copied();
+        let active = self.active.get(&pointer_id).copied();
+        if pending == active {
+            return None;
+        }
+        match pending {
+            Some(id) => self.active.insert(pointer_id, id),
+            None => self.active.remove(&pointer_id),
+        };
+        Some((active, pending))
+    }
+}
+
+fn is_inclusive_descendant(base: &BaseDocument, node_id: NodeId, ancestor: NodeId) -> bool {
+    let mut cursor = Some(node_id);
+    while let Some(id) = cursor {
+        if id == ancestor {
+            return true;
+        }
+        cursor = base.get_node(id).and_then(|n| n.parent);
+    }
+    false
+}
+
+/// Apply a pending capture change for `pointer.pointer_id`, dispatching
+/// `lostpointercapture` / `gotpointercapture` as needed. `pointer` is the
+/// data of the pointer event that triggered the processing; the capture
+/// events carry the same coordinates.
+pub(crate) fn process_pending_capture(
+    doc: &Rc<SharedDoc>,
+    pointer: &PointerData,
+    env: &Env,
+) -> Result<()> {
+    let pointer_id = pointer.pointer_id as u64;
+    let Some((lost, got)) = doc.pointer_captures.borrow_mut().settle(pointer_id) else {
+        return Ok(());
+    };
+    if let Some(lost) = lost {
+        // A capturing node that has since left the document can no longer
+        // receive events; the spec retargets `lostpointercapture` to the
+        // document in that case.
+        let target = if doc.is_in_document(lost) {
+            lost
+        } else {
+            doc.base.borrow().root_node().id
+        };
+        dispatch_synthetic(
+            doc,
+            target,
+            capture_payload("lostpointercapture", pointer),
+            env,
+        )?;
+    }
+    if let Some(got) = got {
+        dispatch_synthetic(doc, got, capture_payload("gotpointercapture", pointer), env)?;
+    }
+    Ok(())
+}
+
+/// Implicit release on `pointercancel`: end any capture of the pointer and
+/// fire `lostpointercapture`.
+pub(crate) fn handle_capture_cancel(doc: &Rc<SharedDoc>, event: &UiEvent, env: &Env) -> Result<()> {
+    let UiEvent::PointerCancel(pointer) = event else {
+        return Ok(());
+    };
+    let pointer = PointerData::new(pointer.clone());
+    doc.pointer_captures
+        .borrow_mut()
+        .release_pointer(pointer.pointer_id as u64);
+    process_pending_capture(doc, &pointer, env)
+}
+
+fn capture_payload(event_type: &str, pointer: &PointerData) -> EventPayload {
+    EventPayload {
+        pointer: Some(pointer.clone()),
+        ..EventPayload::new(event_type, true, false)
+    }
+}
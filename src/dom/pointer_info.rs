@@ -0,0 +1,171 @@
+//! Pointer event fields blitz does not report: `movementX` / `movementY`,
+//! the click count in `detail`, `offsetX` / `offsetY` and the
+//! `relatedTarget` of hover transitions.
+//!
+//! `WindowDocument` calls `PointerInfo::track` with every UI event before
+//! blitz handles it, which records what the DOM events it turns into need
+//! to know; `decorate` then fills those in while each event is serialized.
+
+use std::time::{Duration, Instant};
+
+use blitz::{
+    dom::{BaseDocument, NodeId},
+    traits::events::{BlitzPointerEvent, BlitzPointerId, DomEvent, DomEventData, UiEvent},
+};
+
+use crate::dom::{
+    ops::{hit_element, hovered_element},
+    payload::EventPayload,
+};
+
+/// Presses closer together than this (in time and distance) count as one
+/// multi-click.
+const MULTI_CLICK_TIME: Duration = Duration::from_millis(500);
+const MULTI_CLICK_DISTANCE: f32 = 5.0;
+
+/// The last press, for counting clicks.
+struct Press {
+    at: Instant,
+    button: u8,
+    x: f32,
+    y: f32,
+}
+
+#[derive(Default)]
+pub struct PointerInfo {
+    /// Last screen position of each pointer.
+    positions: Vec<(PointerKey, f32, f32)>,
+    /// Movement of the UI event being handled.
+    movement: (f64, f64),
+    last_press: Option<Press>,
+    /// Click count of the current press.
+    clicks: u32,
+    /// Hovered element before and after the UI event being handled, when
+    /// it changes the hover.
+    hover: Option<(Option<NodeId>, Option<NodeId>)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PointerKey {
+    Mouse,
+    Pen,
+    Finger(u64),
+}
+
+impl From<&BlitzPointerId> for PointerKey {
+    fn from(id: &BlitzPointerId) -> Self {
+        match id {
+            BlitzPointerId::Mouse => Self::Mouse,
+            BlitzPointerId::Pen => Self::Pen,
+            BlitzPointerId::Finger(id) => Self::Finger(*id),
+        }
+    }
+}
+
+impl PointerInfo {
+    /// Record the state of `event` before blitz handles it.
+    pub fn track(&mut self, base: &BaseDocument, event: &UiEvent) {
+        self.movement = (0.0, 0.0);
+        self.hover = None;
+        match event {
+            UiEvent::PointerMove(pointer) => {
+                self.movement = self.move_to(pointer);
+                let from = hovered_element(base);
+                let to = hit_element(
+                    base,
+                    pointer.coords.client_x as f64,
+                    pointer.coords.client_y as f64,
+                );
+                if from != to {
+                    self.hover = Some((from, to));
+                }
+            }
+            UiEvent::PointerDown(pointer) => {
+                self.move_to(pointer);
+                let (x, y) = (pointer.coords.screen_x, pointer.coords.screen_y);
+                let button = pointer.button as u8;
+                let repeat = self.last_press.as_ref().is_some_and(|last| {
+                    last.button == button
+                        && last.at.elapsed() < MULTI_CLICK_TIME
+                        && (x - last.x).hypot(y - last.y) < MULTI_CLICK_DISTANCE
+                });
+                self.clicks = if repeat { self.clicks + 1 } else { 1 };
+                self.last_press = Some(Press {
+                    at: Instant::now(),
+                    button,
+                    x,
+                    y,
+                });
+            }
+            UiEvent::PointerUp(pointer) => {
+                self.move_to(pointer);
+            }
+            _ => {}
+        }
+    }
+
+    /// Store the new position of `pointer`; returns the distance moved.
+    fn move_to(&mut self, pointer: &BlitzPointerEvent) -> (f64, f64) {
+        let key = PointerKey::from(&pointer.id);
+        let (x, y) = (pointer.coords.screen_x, pointer.coords.screen_y);
+        match self.positions.iter_mut().find(|(k, ..)| *k == key) {
+            Some(position) => {
+                let movement = ((x - position.1) as f64, (y - position.2) as f64);
+                *position = (key, x, y);
+                movement
+            }
+            None => {
+                self.positions.push((key, x, y));
+                (0.0, 0.0)
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Fill in the fields of a serialized pointer / mouse event that depend on
+/// the document: movement, click count, offset from `target` and the hover
+/// transition's `relatedTarget`.
+pub(crate) fn decorate(
+    info: &PointerInfo,
+    base: &BaseDocument,
+    event: &DomEvent,
+    target: NodeId,
+    payload: &mut EventPayload,
+) {
+    let Some(pointer) = &mut payload.pointer else {
+        return;
+    };
+    if let Some(node) = base.get_node(target) {
+        let pos = node.absolute_position(0.0, 0.0);
+        pointer.offset_x = pointer.page_x() - pos.x as f64;
+        pointer.offset_y = pointer.page_y() - pos.y as f64;
+    }
+    match &event.data {
+        DomEventData::PointerMove(_) | DomEventData::MouseMove(_) => {
+            (pointer.movement_x, pointer.movement_y) = info.movement;
+        }
+        DomEventData::MouseDown(_)
+        | DomEventData::MouseUp(_)
+        | DomEventData::Click(_)
+        | DomEventData::ContextMenu(_) => pointer.detail = info.clicks,
+        DomEventData::DoubleClick(_) => pointer.detail = 2,
+        _ => {}
+    }
+    if let Some((from, to)) = info.hover {
+        payload.related_target = match &event.data {
+            DomEventData::PointerOver(_)
+            | DomEventData::PointerEnter(_)
+            | DomEventData::MouseOver(_)
+            | DomEventData::MouseEnter(_) => from,
+            DomEventData::PointerOut(_)
+            | DomEventData::PointerLeave(_)
+            | DomEventData::MouseOut(_)
+            | DomEventData::MouseLeave(_) => to,
+            _ => None,
+        };
+    }
+}
@@ -0,0 +1,139 @@
+//! Pointer move coalescing: at most one `pointermove` per pointer and frame.
+//!
+//! High-rate mice and pens deliver far more moves than frames. While
+//! coalescing is on (the default for windows, see
+//! `NativeWindow.setCoalescePointerMoves`), `WindowDocument` queues each
+//! `UiEvent::PointerMove` here instead of dispatching it. The queue is
+//! flushed before any other UI event, so ordering relative to `pointerdown`
+//! / `pointerup` / keys is kept, and right before the window redraws
+//! (`WindowEvent::RedrawRequested` in `AppHandler::window_event`). The
+//! first queued move marks the document dirty, which schedules that frame.
+//!
+//! A flush dispatches the latest sample of each pointer. All samples of the
+//! batch are kept for `PointerEvent.getCoalescedEvents()`, and the last
+//! step extrapolated once for `getPredictedEvents()`.
+
+use std::sync::Arc;
+
+use blitz::traits::events::{BlitzPointerEvent, BlitzPointerId};
+
+/// Samples behind one dispatched `pointermove`.
+#[derive(Default)]
+pub struct PointerSamples {
+    /// Every sample of the batch, oldest first; the last one is the
+    /// dispatched event itself.
+    pub coalesced: Vec<BlitzPointerEvent>,
+    /// Expected next samples.
+    pub predicted: Vec<BlitzPointerEvent>,
+}
+
+/// Queued moves of one document.
+pub struct PointerMoves {
+    /// Queue moves instead of dispatching each one.
+    pub enabled: bool,
+    /// One batch per pointer, in order of their first sample.
+    pending: Vec<Vec<BlitzPointerEvent>>,
+    /// Last dispatched sample per pointer until it is released, to predict
+    /// single-sample batches.
+    last: Vec<BlitzPointerEvent>,
+    /// Samples of the move being dispatched, read while serializing it.
+    pub current: Option<Arc<PointerSamples>>,
+}
+
+impl PointerMoves {
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            pending: Vec::new(),
+            last: Vec::new(),
+            current: None,
+        }
+    }
+
+    pub fn queue(&mut self, event: BlitzPointerEvent) {
+        match self
+            .pending
+            .iter_mut()
+            .find(|batch| same_pointer(&batch[0].id, &event.id))
+        {
+            Some(batch) => batch.push(event),
+            None => self.pending.push(vec![event]),
+        }
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Take the queued batches as (event to dispatch, its samples).
+    pub fn take(&mut self) -> Vec<(BlitzPointerEvent, PointerSamples)> {
+        let batches = std::mem::take(&mut self.pending);
+        batches
+            .into_iter()
+            .map(|coalesced| {
+                let latest = coalesced[coalesced.len() - 1].clone();
+                let previous = match coalesced.len() {
+                    1 => self
+                        .last
+                        .iter()
+                        .find(|last| same_pointer(&last.id, &latest.id))
+                        .cloned(),
+                    n => Some(coalesced[n - 2].clone()),
+                };
+                let predicted = previous
+                    .map(|previous| vec![extrapolate(&previous, &latest)])
+                    .unwrap_or_default();
+                self.last.retain(|last| !same_pointer(&last.id, &latest.id));
+                self.last.push(latest.clone());
+                (
+                    latest,
+                    PointerSamples {
+                        coalesced,
+                        predicted,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Forget the last sample of a pointer on `pointerup` /
+    /// `pointercancel`, so past fingers do not pile up and the next
+    /// contact is not predicted from the previous one.
+    pub fn release(&mut self, id: &BlitzPointerId) {
+        self.last.retain(|last| !same_pointer(&last.id, id));
+    }
+
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.last.clear();
+        self.current = None;
+    }
+}
+
+impl Default for PointerMoves {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn same_pointer(a: &BlitzPointerId, b: &BlitzPointerId) -> bool {
+    match (a, b) {
+        (BlitzPointerId::Mouse, BlitzPointerId::Mouse) => true,
+        (BlitzPointerId::Pen, BlitzPointerId::Pen) => true,
+        (BlitzPointerId::Finger(a), BlitzPointerId::Finger(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// `latest` moved on by the step from `previous` to `latest`.
+fn extrapolate(previous: &BlitzPointerEvent, latest: &BlitzPointerEvent) -> BlitzPointerEvent {
+    let (p, l) = (&previous.coords, &latest.coords);
+    let mut next = latest.clone();
+    next.coords.page_x = 2.0 * l.page_x - p.page_x;
+    next.coords.page_y = 2.0 * l.page_y - p.page_y;
+    next.coords.client_x = 2.0 * l.client_x - p.client_x;
+    next.coords.client_y = 2.0 * l.client_y - p.client_y;
+    next.coords.screen_x = 2.0 * l.screen_x - p.screen_x;
+    next.coords.screen_y = 2.0 * l.screen_y - p.screen_y;
+    next
+}
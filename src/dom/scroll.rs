@@ -0,0 +1,544 @@
+//! Scroll offsets, `scroll` / `scrollend` events and smooth scrolling.
+//!
+//! Offsets written through the API (`scrollTop`, `scrollTo`, `scrollBy`,
+//! `scrollIntoView`) are clamped to the scroll range from the last
+//! resolved layout. The root element scrolls the viewport, as it does in
+//! browsers.
+//!
+//! As in browsers, scroll events do not fire from inside the scroll: the
+//! targets that moved are queued in `SharedDoc.scrolls` and get their
+//! events on the next frame, once each however often they moved. Smooth
+//! scrolls are animated by the same frame clock: `AppHandler` ticks
+//! windowed documents from `about_to_wait` and `NativeDoc.resolve` ticks
+//! headless ones with the time it is given. Elements get non-bubbling
+//! events; viewport scrolls fire at the document, bubbling to the window.
+
+use std::rc::Rc;
+
+use blitz::{
+    dom::{BaseDocument, NodeId},
+    traits::events::UiEvent,
+};
+use napi::{Env, Error, Result, Status};
+use style::values::computed::Overflow;
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::{dispatch_payload, node_chain},
+    payload::EventPayload,
+};
+
+/// Duration of a smooth scroll.
+const SMOOTH_SCROLL_MS: f64 = 300.0;
+/// Quiet time after the last wheel scroll before `scrollend` fires.
+const SCROLLEND_DELAY_MS: f64 = 150.0;
+
+/// Options of `scrollTo` / `scrollBy`, as in the DOM's `ScrollToOptions`.
+/// `scrollBy` reads `left` / `top` as deltas.
+#[napi(object)]
+pub struct ScrollToOptions {
+    pub left: Option<f64>,
+    pub top: Option<f64>,
+    /// `"auto"`, `"instant"` or `"smooth"`.
+    pub behavior: Option<String>,
+}
+
+/// Options of `scrollIntoView`, as in the DOM's `ScrollIntoViewOptions`.
+#[napi(object)]
+pub struct ScrollIntoViewOptions {
+    /// `"start"` (default), `"center"`, `"end"` or `"nearest"`.
+    pub block: Option<String>,
+    /// `"start"`, `"center"`, `"end"` or `"nearest"` (default).
+    pub inline: Option<String>,
+    /// `"auto"`, `"instant"` or `"smooth"`.
+    pub behavior: Option<String>,
+}
+
+/// Something with a scroll offset.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScrollTarget {
+    Viewport,
+    Node(NodeId),
+}
+
+impl ScrollTarget {
+    /// The scroller of `node_id`: the viewport for the root element.
+    pub(crate) fn of(base: &BaseDocument, node_id: NodeId) -> Self {
+        if base.root_element().id == node_id {
+            Self::Viewport
+        } else {
+            Self::Node(node_id)
+        }
+    }
+}
+
+struct Animation {
+    target: ScrollTarget,
+    from: (f64, f64),
+    to: (f64, f64),
+    /// Frame time of the first tick.
+    start: Option<f64>,
+}
+
+/// Scroll state between frames.
+#[derive(Default)]
+pub struct Scrolls {
+    /// Targets that moved since the last frame.
+    scrolled: Vec<ScrollTarget>,
+    /// Targets whose scroll finished since the last frame.
+    ended: Vec<ScrollTarget>,
+    animations: Vec<Animation>,
+    /// Wheel-scrolled targets waiting for `scrollend`, with the frame time
+    /// of their last scroll.
+    settling: Vec<(ScrollTarget, Option<f64>)>,
+}
+
+impl Scrolls {
+    /// Whether the next frames have anything to do.
+    pub fn is_active(&self) -> bool {
+        !self.scrolled.is_empty()
+            || !self.ended.is_empty()
+            || !self.animations.is_empty()
+            || !self.settling.is_empty()
+    }
+
+    /// Whether a smooth scroll or wheel scroll is in progress, which needs
+    /// frames even when nothing else changes.
+    pub fn is_animating(&self) -> bool {
+        !self.animations.is_empty() || !self.settling.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn cancel(&mut self, target: ScrollTarget) {
+        self.animations.retain(|a| a.target != target);
+    }
+
+    /// Advance the animations to `now`. Returns the targets to fire
+    /// `scroll` and `scrollend` at.
+    fn step(
+        &mut self,
+        base: &mut BaseDocument,
+        now: f64,
+    ) -> (Vec<ScrollTarget>, Vec<ScrollTarget>) {
+        let mut scrolled = std::mem::take(&mut self.scrolled);
+        let mut ended = std::mem::take(&mut self.ended);
+        self.animations.retain_mut(|anim| {
+            let start = *anim.start.get_or_insert(now);
+            let t = ((now - start) / SMOOTH_SCROLL_MS).clamp(0.0, 1.0);
+            let e = ease_in_out(t);
+            let x = anim.from.0 + (anim.to.0 - anim.from.0) * e;
+            let y = anim.from.1 + (anim.to.1 - anim.from.1) * e;
+            if set_offset(base, anim.target, x, y) {
+                push_unique(&mut scrolled, anim.target);
+            }
+            if t < 1.0 {
+                return true;
+            }
+            push_unique(&mut ended, anim.target);
+            false
+        });
+        self.settling.retain_mut(|(target, last)| {
+            if now - *last.get_or_insert(now) < SCROLLEND_DELAY_MS {
+                return true;
+            }
+            push_unique(&mut ended, *target);
+            false
+        });
+        (scrolled, ended)
+    }
+}
+
+fn push_unique(targets: &mut Vec<ScrollTarget>, target: ScrollTarget) {
+    if !targets.contains(&target) {
+        targets.push(target);
+    }
+}
+
+fn ease_in_out(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+fn is_smooth(behavior: Option<&str>) -> Result<bool> {
+    match behavior {
+        None | Some("auto" | "instant") => Ok(false),
+        Some("smooth") => Ok(true),
+        Some(other) => Err(Error::new(
+            Status::InvalidArg,
+            format!("invalid scroll behavior {other:?}"),
+        )),
+    }
+}
+
+/// Whether `overflow` lets the node scroll.
+fn is_scroll_container(base: &BaseDocument, node_id: NodeId) -> bool {
+    base.get_node(node_id)
+        .and_then(|node| node.primary_styles())
+        .is_some_and(|styles| {
+            let b = styles.get_box();
+            [b.clone_overflow_x(), b.clone_overflow_y()]
+                .iter()
+                .any(|o| !matches!(o, Overflow::Visible | Overflow::Clip))
+        })
+}
+
+/// Size of the viewport in CSS pixels.
+fn viewport_size(base: &BaseDocument) -> (f64, f64) {
+    let viewport = base.viewport();
+    let scale = viewport.scale_f64();
+    let (width, height) = viewport.window_size;
+    (width as f64 / scale, height as f64 / scale)
+}
+
+/// Current scroll offset of `target`.
+pub(crate) fn offset(base: &BaseDocument, target: ScrollTarget) -> (f64, f64) {
+    match target {
+        ScrollTarget::Viewport => {
+            let scroll = base.viewport_scroll();
+            (scroll.x, scroll.y)
+        }
+        ScrollTarget::Node(id) => base
+            .get_node(id)
+            .map(|n| (n.scroll_offset().x, n.scroll_offset().y))
+            .unwrap_or((0.0, 0.0)),
+    }
+}
+
+/// Largest scroll offset of `target`; zero for elements that are not
+/// scroll containers.
+pub(crate) fn max_offset(base: &BaseDocument, target: ScrollTarget) -> (f64, f64) {
+    match target {
+        ScrollTarget::Viewport => {
+            let layout = base.root_element().final_layout();
+            let (width, height) = viewport_size(base);
+            let content_width = layout.size.width.max(layout.content_size.width) as f64;
+            let content_height = layout.size.height.max(layout.content_size.height) as f64;
+            (
+                (content_width - width).max(0.0),
+                (content_height - height).max(0.0),
+            )
+        }
+        ScrollTarget::Node(id) if is_scroll_container(base, id) => base
+            .get_node(id)
+            .map(|n| {
+                let layout = n.final_layout();
+                (layout.scroll_width() as f64, layout.scroll_height() as f64)
+            })
+            .unwrap_or((0.0, 0.0)),
+        ScrollTarget::Node(_) => (0.0, 0.0),
+    }
+}
+
+/// `(x, y)` clamped to the scroll range of `target`.
+fn clamp(base: &BaseDocument, target: ScrollTarget, x: f64, y: f64) -> (f64, f64) {
+    let (max_x, max_y) = max_offset(base, target);
+    (x.clamp(0.0, max_x), y.clamp(0.0, max_y))
+}
+
+/// Scroll `target` to `(x, y)`, clamped. Returns whether it moved.
+fn set_offset(base: &mut BaseDocument, target: ScrollTarget, x: f64, y: f64) -> bool {
+    let (x, y) = clamp(base, target, x, y);
+    if offset(base, target) == (x, y) {
+        return false;
+    }
+    match target {
+        ScrollTarget::Viewport => {
+            let mut scroll = base.viewport_scroll();
+            scroll.x = x;
+            scroll.y = y;
+            base.set_viewport_scroll(scroll);
+        }
+        ScrollTarget::Node(id) => {
+            if let Some(node) = base.get_node_mut(id) {
+                let offset = node.scroll_offset_mut();
+                offset.x = x;
+                offset.y = y;
+            }
+        }
+    }
+    true
+}
+
+/// Scroll `target` to `(x, y)`, clamped to its scroll range, instantly or
+/// as a smooth scroll. `None` keeps an axis where it is.
+pub(crate) fn scroll_to(
+    doc: &SharedDoc,
+    target: ScrollTarget,
+    x: Option<f64>,
+    y: Option<f64>,
+    smooth: bool,
+) {
+    let mut base = doc.base.borrow_mut();
+    let mut scrolls = doc.scrolls.borrow_mut();
+    let current = offset(&base, target);
+    let (x, y) = (x.unwrap_or(current.0), y.unwrap_or(current.1));
+    if smooth {
+        let to = clamp(&base, target, x, y);
+        scrolls.cancel(target);
+        if current != to {
+            scrolls.animations.push(Animation {
+                target,
+                from: current,
+                to,
+                start: None,
+            });
+        }
+    } else {
+        scrolls.cancel(target);
+        if set_offset(&mut base, target, x, y) {
+            push_unique(&mut scrolls.scrolled, target);
+            push_unique(&mut scrolls.ended, target);
+        }
+    }
+    drop(scrolls);
+    drop(base);
+    doc.mark_host_dirty();
+}
+
+/// `scrollTo` / `scrollBy` with DOM options; `relative` reads them as
+/// deltas from the current offset (or the end of a smooth scroll in
+/// progress).
+pub(crate) fn scroll_with_options(
+    doc: &SharedDoc,
+    target: ScrollTarget,
+    options: ScrollToOptions,
+    relative: bool,
+) -> Result<()> {
+    let smooth = is_smooth(options.behavior.as_deref())?;
+    let finite = |v: Option<f64>| v.filter(|v| v.is_finite());
+    let (left, top) = (finite(options.left), finite(options.top));
+    if !relative {
+        scroll_to(doc, target, left, top, smooth);
+        return Ok(());
+    }
+    let (x, y) = {
+        let scrolls = doc.scrolls.borrow();
+        match scrolls.animations.iter().find(|a| a.target == target) {
+            Some(anim) => anim.to,
+            None => offset(&doc.base.borrow(), target),
+        }
+    };
+    scroll_to(
+        doc,
+        target,
+        Some(x + left.unwrap_or(0.0)),
+        Some(y + top.unwrap_or(0.0)),
+        smooth,
+    );
+    Ok(())
+}
+
+/// Where `scrollIntoView` puts an element along one axis.
+#[derive(Clone, Copy)]
+enum Align {
+    Start,
+    Center,
+    End,
+    Nearest,
+}
+
+impl Align {
+    fn parse(value: Option<&str>, default: Self) -> Result<Self> {
+        match value {
+            None => Ok(default),
+            Some("start") => Ok(Self::Start),
+            Some("center") => Ok(Self::Center),
+            Some("end") => Ok(Self::End),
+            Some("nearest") => Ok(Self::Nearest),
+            Some(other) => Err(Error::new(
+                Status::InvalidArg,
+                format!("invalid scrollIntoView alignment {other:?}"),
+            )),
+        }
+    }
+
+    /// Offset that shows `start..end` in a scrollport of `size` currently
+    /// scrolled to `current`.
+    fn offset(self, start: f64, end: f64, size: f64, current: f64) -> f64 {
+        match self {
+            Self::Start => start,
+            Self::End => end - size,
+            Self::Center => (start + end - size) / 2.0,
+            Self::Nearest if start < current => start,
+            Self::Nearest if end > current + size && end - start <= size => end - size,
+            Self::Nearest if end > current + size => start,
+            Self::Nearest => current,
+        }
+    }
+}
+
+/// Scroll every scroll container around `node_id`, innermost first, and
+/// the viewport so that the node's border box is shown as `options` asks.
+pub(crate) fn scroll_into_view(
+    doc: &SharedDoc,
+    node_id: NodeId,
+    options: ScrollIntoViewOptions,
+) -> Result<()> {
+    let smooth = is_smooth(options.behavior.as_deref())?;
+    let block = Align::parse(options.block.as_deref(), Align::Start)?;
+    let inline = Align::parse(options.inline.as_deref(), Align::Nearest)?;
+
+    let scrolls: Vec<_> = {
+        let base = doc.base.borrow();
+        let Some(node) = base.get_node(node_id).filter(|n| n.flags.is_in_document()) else {
+            return Ok(());
+        };
+        let pos = node.absolute_position(0.0, 0.0);
+        let size = node.final_layout().size;
+        let (left, top) = (pos.x as f64, pos.y as f64);
+        let (width, height) = (size.width as f64, size.height as f64);
+
+        // Scroll containers between the node and the viewport, with their
+        // scrollport in document coordinates.
+        let mut scrollports = Vec::new();
+        let mut parent = node.parent;
+        while let Some(id) = parent {
+            let Some(ancestor) = base.get_node(id) else {
+                break;
+            };
+            parent = ancestor.parent;
+            let target = ScrollTarget::of(&base, id);
+            if target == ScrollTarget::Viewport || !is_scroll_container(&base, id) {
+                continue;
+            }
+            let pos = ancestor.absolute_position(0.0, 0.0);
+            let layout = ancestor.final_layout();
+            let origin = (
+                pos.x as f64 + layout.border.left as f64,
+                pos.y as f64 + layout.border.top as f64,
+            );
+            let port = (
+                (layout.size.width
+                    - layout.border.left
+                    - layout.border.right
+                    - layout.scrollbar_size.width) as f64,
+                (layout.size.height
+                    - layout.border.top
+                    - layout.border.bottom
+                    - layout.scrollbar_size.height) as f64,
+            );
+            scrollports.push((target, origin, port));
+        }
+        scrollports.push((ScrollTarget::Viewport, (0.0, 0.0), viewport_size(&base)));
+
+        // The node moves with each scroll container it is in, so the
+        // outer ones see it shifted by the new offsets of the inner ones.
+        let mut shift = (0.0, 0.0);
+        scrollports
+            .into_iter()
+            .map(|(target, origin, port)| {
+                let current = offset(&base, target);
+                let x = left - shift.0 - origin.0;
+                let y = top - shift.1 - origin.1;
+                let to = clamp(
+                    &base,
+                    target,
+                    inline.offset(x, x + width, port.0, current.0),
+                    block.offset(y, y + height, port.1, current.1),
+                );
+                shift = (shift.0 + to.0, shift.1 + to.1);
+                (target, to)
+            })
+            .collect()
+    };
+    for (target, (x, y)) in scrolls {
+        scroll_to(doc, target, Some(x), Some(y), smooth);
+    }
+    Ok(())
+}
+
+/// Offsets of the scrollers a wheel event may move: the viewport and the
+/// ancestors of the hovered node. `None` for other events.
+pub(crate) fn wheel_snapshot(
+    base: &BaseDocument,
+    event: &UiEvent,
+) -> Option<Vec<(ScrollTarget, (f64, f64))>> {
+    if !matches!(event, UiEvent::Wheel(_)) {
+        return None;
+    }
+    let mut targets = vec![ScrollTarget::Viewport];
+    let mut next = base.get_hover_node_id();
+    while let Some(node) = next.and_then(|id| base.get_node(id)) {
+        let target = ScrollTarget::of(base, node.id);
+        if target != ScrollTarget::Viewport {
+            targets.push(target);
+        }
+        next = node.parent;
+    }
+    Some(
+        targets
+            .into_iter()
+            .map(|target| (target, offset(base, target)))
+            .collect(),
+    )
+}
+
+/// Queue the scroll events of the scrollers a wheel event moved, given
+/// their offsets from `wheel_snapshot`. Their `scrollend` waits until the
+/// wheel has been quiet for a moment.
+pub(crate) fn finish_wheel(doc: &SharedDoc, before: Vec<(ScrollTarget, (f64, f64))>) {
+    let base = doc.base.borrow();
+    let mut scrolls = doc.scrolls.borrow_mut();
+    for (target, old) in before {
+        if offset(&base, target) == old {
+            continue;
+        }
+        scrolls.cancel(target);
+        push_unique(&mut scrolls.scrolled, target);
+        scrolls.settling.retain(|(t, _)| *t != target);
+        scrolls.settling.push((target, None));
+    }
+}
+
+/// Run one frame of scrolling at frame time `now` (ms): advance smooth
+/// scrolls, then fire the queued `scroll` events and the `scrollend`s of
+/// finished scrolls.
+pub(crate) fn tick_scrolls(doc: &Rc<SharedDoc>, now: f64, env: &Env) -> Result<()> {
+    let (scrolled, ended) = {
+        let mut scrolls = doc.scrolls.borrow_mut();
+        if !scrolls.is_active() {
+            return Ok(());
+        }
+        scrolls.step(&mut doc.base.borrow_mut(), now)
+    };
+    if !scrolled.is_empty() {
+        doc.mark_host_dirty();
+    }
+    for target in scrolled {
+        fire(doc, target, "scroll", env)?;
+    }
+    for target in ended {
+        fire(doc, target, "scrollend", env)?;
+    }
+    Ok(())
+}
+
+fn fire(doc: &Rc<SharedDoc>, target: ScrollTarget, event_type: &str, env: &Env) -> Result<()> {
+    if doc.is_disposed() {
+        return Ok(());
+    }
+    let (node_id, chain) = {
+        let base = doc.base.borrow();
+        let node_id = match target {
+            ScrollTarget::Viewport => base.root_node().id,
+            // Removed from the document since it scrolled.
+            ScrollTarget::Node(id)
+                if !base.get_node(id).is_some_and(|n| n.flags.is_in_document()) =>
+            {
+                return Ok(());
+            }
+            ScrollTarget::Node(id) => id,
+        };
+        (node_id, node_chain(&base, node_id))
+    };
+    let viewport = target == ScrollTarget::Viewport;
+    let payload = EventPayload::new(event_type, viewport, false);
+    dispatch_payload(payload, node_id, &chain, viewport, doc, env)?;
+    Ok(())
+}
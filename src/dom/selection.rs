@@ -0,0 +1,390 @@
+//! Document selection and `Range` geometry for non-editable text.
+//!
+//! Boundary points follow the DOM: `(text node, UTF-16 offset)` or
+//! `(element, child index)`. The document keeps one selection (anchor and
+//! focus) in `SharedDoc`, set from JS through `Selection` or by dragging
+//! the primary mouse button over text (`handle_selection_pointer`).
+//!
+//! Text geometry comes from the parley layout of the *inline root*, the
+//! nearest element with `inline_layout_data`, which lays out the text of
+//! all its inline descendants as one string. A text node's offsets are
+//! mapped into that string by summing the lengths of the text nodes laid
+//! out before it. This assumes each text node appears verbatim in the
+//! layout text; where blitz collapsed whitespace, offsets after the
+//! collapsed run are approximate.
+//!
+//! blitz's painter has no notion of a document selection. Both the window
+//! renderer and the CPU paint path (`BufferRenderer`,
+//! `NativeWindow.capture`) draw the highlight from `selection_rects` on
+//! top of the painted text (see `renderer`).
+
+use std::{cmp::Ordering, rc::Rc};
+
+use blitz::{
+    dom::{BaseDocument, Node, NodeData, NodeId},
+    traits::events::{BlitzPointerEvent, MouseEventButton, UiEvent},
+};
+use napi::{Env, Result};
+use parley::{Affinity, Cursor, Selection};
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::dispatch_synthetic,
+    node_handle::DomRect,
+    payload::EventPayload,
+    text_selection::{byte_to_utf16, utf16_to_byte},
+};
+
+/// A DOM boundary point.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Boundary {
+    pub node: NodeId,
+    pub offset: u32,
+}
+
+/// The document selection. `anchor` is where it started, `focus` where
+/// it currently ends; `focus` may precede `anchor`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DocSelection {
+    pub anchor: Boundary,
+    pub focus: Boundary,
+}
+
+// ── Tree order ────────────────────────────────────────────────────────
+
+/// Child-index path from the tree root to `(node, offset)`. Comparing two
+/// paths lexicographically (a prefix sorts first) yields DOM tree order
+/// of the boundary points.
+fn boundary_path(base: &BaseDocument, point: Boundary) -> Vec<u32> {
+    let mut path = vec![point.offset];
+    let mut cursor = point.node;
+    while let Some(parent) = base.get_node(cursor).and_then(|n| n.parent) {
+        let index = base
+            .get_node(parent)
+            .and_then(|p| p.children.iter().position(|&c| c == cursor))
+            .unwrap_or(0);
+        path.push(index as u32);
+        cursor = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Order two boundary points in tree order.
+pub(crate) fn compare_boundaries(base: &BaseDocument, a: Boundary, b: Boundary) -> Ordering {
+    if a.node == b.node {
+        return a.offset.cmp(&b.offset);
+    }
+    boundary_path(base, a).cmp(&boundary_path(base, b))
+}
+
+/// Content of a text node, or `None` for other nodes.
+fn text_content(node: &Node) -> Option<&str> {
+    match &node.data {
+        NodeData::Text(text) => Some(&text.content),
+        _ => None,
+    }
+}
+
+/// Length of a node for boundary purposes: UTF-16 length for text,
+/// child count otherwise.
+pub(crate) fn node_length(base: &BaseDocument, node_id: NodeId) -> u32 {
+    match base.get_node(node_id) {
+        Some(node) => match text_content(node) {
+            Some(text) => byte_to_utf16(text, text.len()),
+            None => node.children.len() as u32,
+        },
+        None => 0,
+    }
+}
+
+/// Text nodes between `start` and `end` in tree order, each with the
+/// UTF-16 sub-range covered by the range.
+fn text_nodes_in_range(
+    base: &BaseDocument,
+    start: Boundary,
+    end: Boundary,
+) -> Vec<(NodeId, u32, u32)> {
+    let mut out = Vec::new();
+    let mut stack = vec![base.root_node().id];
+    while let Some(id) = stack.pop() {
+        let Some(node) = base.get_node(id) else {
+            continue;
+        };
+        if let Some(text) = text_content(node) {
+            let len = byte_to_utf16(text, text.len());
+            let from = Boundary {
+                node: id,
+                offset: 0,
+            };
+            let to = Boundary {
+                node: id,
+                offset: len,
+            };
+            // Skip text entirely before the start or after the end.
+            if compare_boundaries(base, to, start) == Ordering::Less
+                || compare_boundaries(base, from, end) == Ordering::Greater
+            {
+                continue;
+            }
+            let lo = if id == start.node { start.offset } else { 0 };
+            let hi = if id == end.node { end.offset } else { len };
+            if lo < hi {
+                out.push((id, lo.min(len), hi.min(len)));
+            }
+        }
+        for &child in node.children.iter().rev() {
+            stack.push(child);
+        }
+    }
+    out
+}
+
+/// `Range.toString()`: the selected text of every text node in range.
+pub(crate) fn range_text(base: &BaseDocument, start: Boundary, end: Boundary) -> String {
+    let mut out = String::new();
+    for (id, lo, hi) in text_nodes_in_range(base, start, end) {
+        if let Some(text) = base.get_node(id).and_then(text_content) {
+            out.push_str(&text[utf16_to_byte(text, lo)..utf16_to_byte(text, hi)]);
+        }
+    }
+    out
+}
+
+// ── Inline layout mapping ─────────────────────────────────────────────
+
+fn has_inline_layout(node: &Node) -> bool {
+    node.element_data()
+        .is_some_and(|el| el.inline_layout_data.is_some())
+}
+
+/// Nearest inclusive ancestor of `node_id` that owns an inline layout.
+fn inline_root(base: &BaseDocument, node_id: NodeId) -> Option<NodeId> {
+    let mut cursor = Some(node_id);
+    while let Some(id) = cursor {
+        let node = base.get_node(id)?;
+        if has_inline_layout(node) {
+            return Some(id);
+        }
+        cursor = node.parent;
+    }
+    None
+}
+
+/// Text nodes laid out by `root`, in order, with the byte offset at which
+/// each starts in the root's layout text. Nested inline roots (inline
+/// blocks) lay out their own text and are skipped.
+fn text_segments(base: &BaseDocument, root: NodeId) -> Vec<(NodeId, usize)> {
+    let mut out = Vec::new();
+    let mut offset = 0;
+    let mut stack: Vec<NodeId> = match base.get_node(root) {
+        Some(node) => node.children.iter().rev().copied().collect(),
+        None => return out,
+    };
+    while let Some(id) = stack.pop() {
+        let Some(node) = base.get_node(id) else {
+            continue;
+        };
+        if let Some(text) = text_content(node) {
+            out.push((id, offset));
+            offset += text.len();
+        } else if !has_inline_layout(node) {
+            for &child in node.children.iter().rev() {
+                stack.push(child);
+            }
+        }
+    }
+    out
+}
+
+/// Top-left of the node's content box in document coordinates, where
+/// its inline layout is painted.
+fn content_origin(node: &Node) -> (f64, f64) {
+    let pos = node.absolute_position(0.0, 0.0);
+    let layout = node.final_layout();
+    (
+        (pos.x + layout.border.left + layout.padding.left) as f64,
+        (pos.y + layout.border.top + layout.padding.top) as f64,
+    )
+}
+
+/// Client rects of the text between `start` and `end` (document
+/// coordinates, like `getBoundingClientRect`): one per line fragment.
+pub(crate) fn range_rects(base: &BaseDocument, start: Boundary, end: Boundary) -> Vec<DomRect> {
+    // Group the covered text into one byte range per inline root. Text
+    // nodes arrive in tree order, so each root's range is contiguous.
+    let mut spans: Vec<(NodeId, usize, usize)> = Vec::new();
+    let mut segments: Option<(NodeId, Vec<(NodeId, usize)>)> = None;
+    for (id, lo, hi) in text_nodes_in_range(base, start, end) {
+        let Some(root) = inline_root(base, id) else {
+            continue;
+        };
+        if segments.as_ref().is_none_or(|(r, _)| *r != root) {
+            segments = Some((root, text_segments(base, root)));
+        }
+        let Some(&(_, seg_start)) = segments
+            .as_ref()
+            .and_then(|(_, segs)| segs.iter().find(|(n, _)| *n == id))
+        else {
+            continue;
+        };
+        let Some(text) = base.get_node(id).and_then(text_content) else {
+            continue;
+        };
+        let lo = seg_start + utf16_to_byte(text, lo);
+        let hi = seg_start + utf16_to_byte(text, hi);
+        match spans.last_mut() {
+            Some((r, _, span_hi)) if *r == root => *span_hi = hi,
+            _ => spans.push((root, lo, hi)),
+        }
+    }
+
+    let mut rects = Vec::new();
+    for (root, lo, hi) in spans {
+        let Some(node) = base.get_node(root) else {
+            continue;
+        };
+        let Some(inline) = node
+            .element_data()
+            .and_then(|el| el.inline_layout_data.as_ref())
+        else {
+            continue;
+        };
+        let len = inline.text.len();
+        let anchor = Cursor::from_byte_index(&inline.layout, lo.min(len), Affinity::Downstream);
+        let focus = Cursor::from_byte_index(&inline.layout, hi.min(len), Affinity::Upstream);
+        let (ox, oy) = content_origin(node);
+        for (bbox, _line) in Selection::new(anchor, focus).geometry(&inline.layout) {
+            rects.push(DomRect::new(
+                ox + bbox.x0,
+                oy + bbox.y0,
+                bbox.x1 - bbox.x0,
+                bbox.y1 - bbox.y0,
+            ));
+        }
+    }
+    rects
+}
+
+/// Highlight rects of the document selection in viewport coordinates
+/// (CSS pixels), one per line fragment.
+pub(crate) fn selection_rects(base: &BaseDocument, selection: DocSelection) -> Vec<DomRect> {
+    let (start, end) = match compare_boundaries(base, selection.anchor, selection.focus) {
+        Ordering::Greater => (selection.focus, selection.anchor),
+        _ => (selection.anchor, selection.focus),
+    };
+    let scroll = base.viewport_scroll();
+    range_rects(base, start, end)
+        .into_iter()
+        .map(|rect| {
+            DomRect::new(
+                rect.x - scroll.x,
+                rect.y - scroll.y,
+                rect.width,
+                rect.height,
+            )
+        })
+        .collect()
+}
+
+/// Text boundary point under a document coordinate, or `None` when the
+/// point is not over laid-out text.
+fn text_boundary_at(base: &BaseDocument, x: f32, y: f32) -> Option<Boundary> {
+    let hit = base.hit(x, y)?;
+    let hit_node = base.get_node(hit.node_id)?;
+    // Text controls run their own editor selection.
+    if hit_node
+        .element_data()
+        .is_some_and(|el| el.text_input_data().is_some())
+    {
+        return None;
+    }
+    let root = inline_root(base, hit.node_id)?;
+    let node = base.get_node(root)?;
+    let inline = node.element_data()?.inline_layout_data.as_ref()?;
+    let (ox, oy) = content_origin(node);
+    let cursor = Cursor::from_point(&inline.layout, x - ox as f32, y - oy as f32);
+    let byte = cursor.index();
+
+    // Map the layout byte offset back to the text node containing it.
+    let segments = text_segments(base, root);
+    let &(text_id, seg_start) = segments.iter().rev().find(|&&(_, s)| s <= byte)?;
+    let text = base.get_node(text_id).and_then(text_content)?;
+    Some(Boundary {
+        node: text_id,
+        offset: byte_to_utf16(text, byte - seg_start),
+    })
+}
+
+// ── Selection state ───────────────────────────────────────────────────
+
+/// Replace the document selection, firing `selectionchange` at the
+/// document when it changed.
+pub(crate) fn set_selection(
+    doc: &Rc<SharedDoc>,
+    selection: Option<DocSelection>,
+    env: &Env,
+) -> Result<()> {
+    if doc.selection.replace(selection) == selection {
+        return Ok(());
+    }
+    doc.mark_host_dirty();
+    let root = doc.base.borrow().root_node().id;
+    dispatch_synthetic(
+        doc,
+        root,
+        EventPayload::new("selectionchange", false, false),
+        env,
+    )?;
+    Ok(())
+}
+
+/// Mouse drag selection: a primary-button press over text starts a
+/// collapsed selection (a press elsewhere clears it), moves while the
+/// button is held extend its focus, and the release ends the drag. Runs
+/// after blitz and JS have handled the event.
+pub(crate) fn handle_selection_pointer(
+    doc: &Rc<SharedDoc>,
+    event: &UiEvent,
+    env: &Env,
+) -> Result<()> {
+    let at = |pointer: &BlitzPointerEvent| {
+        text_boundary_at(
+            &doc.base.borrow(),
+            pointer.coords.page_x,
+            pointer.coords.page_y,
+        )
+    };
+    match event {
+        UiEvent::PointerDown(pointer) if matches!(pointer.button, MouseEventButton::Main) => {
+            let point = at(pointer);
+            doc.selecting.set(point.is_some());
+            set_selection(
+                doc,
+                point.map(|p| DocSelection {
+                    anchor: p,
+                    focus: p,
+                }),
+                env,
+            )
+        }
+        UiEvent::PointerMove(pointer) if doc.selecting.get() => {
+            let (Some(focus), Some(current)) = (at(pointer), doc.selection.get()) else {
+                return Ok(());
+            };
+            set_selection(
+                doc,
+                Some(DocSelection {
+                    anchor: current.anchor,
+                    focus,
+                }),
+                env,
+            )
+        }
+        UiEvent::PointerUp(_) | UiEvent::PointerCancel(_) => {
+            doc.selecting.set(false);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
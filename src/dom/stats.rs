@@ -0,0 +1,255 @@
+//! Memory and cache introspection: `NativeDoc.stats()` and `addonStats()`.
+//!
+//! Counts are taken on demand by walking the document tree and the
+//! `NodeCache`; nothing is tracked incrementally. The main use is finding
+//! leaks: a detached subtree stays in blitz's node storage for as long as
+//! one of its cached JS wrappers is alive, and forever if one of them is
+//! held strong (`detachedStrongWrappers`).
+
+use std::{collections::HashSet, mem::size_of};
+
+use blitz::dom::{BaseDocument, Node, NodeData, NodeId, local_name};
+use napi::{Env, Error, Result};
+
+use crate::{
+    dom::doc::{NativeDoc, SharedDoc},
+    global::live_documents,
+    helpers::SwitchableRef,
+};
+
+/// Memory and cache counters of one document.
+#[napi(object)]
+pub struct DocStats {
+    /// Nodes connected to the document.
+    pub nodes: u32,
+    /// Nodes of detached subtrees that blitz still stores because a cached
+    /// JS wrapper in the subtree may be alive.
+    pub detached_nodes: u32,
+    /// `NodeCache` entries (JS wrappers known to the document).
+    pub cached_wrappers: u32,
+    /// Entries holding their wrapper strong (connected nodes).
+    pub strong_wrappers: u32,
+    /// Entries the GC may collect (detached nodes).
+    pub weak_wrappers: u32,
+    /// Strong entries whose node is detached. Normally `0`; anything else
+    /// keeps a detached subtree alive indefinitely.
+    pub detached_strong_wrappers: u32,
+    /// Entries whose wrapper was collected, waiting to be swept. Each
+    /// input event sweeps a bounded batch of them.
+    pub collected_wrappers: u32,
+    /// `<style>` and `<link rel=stylesheet>` elements connected to the
+    /// document. User-agent sheets are not counted.
+    pub stylesheets: u32,
+    /// Font families in the document's font collection, system fonts
+    /// included.
+    pub font_families: u32,
+    /// Rough size in bytes of the node storage, text, attribute values
+    /// and cache entries. Style and layout data are not included.
+    pub approx_bytes: f64,
+}
+
+/// `DocStats` summed over every live document of the calling environment
+/// (main thread or Worker).
+#[napi(object)]
+pub struct AddonStats {
+    /// Documents neither disposed nor garbage-collected.
+    pub documents: u32,
+    pub nodes: u32,
+    pub detached_nodes: u32,
+    pub cached_wrappers: u32,
+    pub strong_wrappers: u32,
+    pub weak_wrappers: u32,
+    pub detached_strong_wrappers: u32,
+    pub collected_wrappers: u32,
+    pub stylesheets: u32,
+    pub approx_bytes: f64,
+}
+
+/// Tree and cache counters shared by `DocStats` and `AddonStats`.
+#[derive(Default)]
+struct TreeStats {
+    nodes: u32,
+    detached_nodes: u32,
+    cached_wrappers: u32,
+    strong_wrappers: u32,
+    weak_wrappers: u32,
+    detached_strong_wrappers: u32,
+    collected_wrappers: u32,
+    stylesheets: u32,
+    approx_bytes: usize,
+}
+
+impl TreeStats {
+    fn collect(doc: &SharedDoc) -> Self {
+        let base = doc.base.borrow();
+        let cache = doc.node_cache.borrow();
+        let root = base.root_node().id;
+        let mut stats = Self::default();
+
+        let connected = walk(&base, root, &mut stats);
+        stats.nodes = connected;
+        stats.stylesheets = count_stylesheets(&base, root);
+
+        // Detached subtrees are only reachable through their cached wrappers.
+        let mut detached_roots = HashSet::new();
+        for (node_id, strong) in cache.entries() {
+            stats.cached_wrappers += 1;
+            if strong {
+                stats.strong_wrappers += 1;
+            } else {
+                stats.weak_wrappers += 1;
+            }
+            let Some(top) = subtree_root(&base, node_id) else {
+                continue;
+            };
+            if top != root {
+                detached_roots.insert(top);
+                if strong {
+                    stats.detached_strong_wrappers += 1;
+                }
+            }
+        }
+        for top in detached_roots {
+            let count = walk(&base, top, &mut stats);
+            stats.detached_nodes += count;
+        }
+
+        stats.collected_wrappers = cache.collected_len() as u32;
+        stats.approx_bytes += cache.len() * (size_of::<NodeId>() + size_of::<SwitchableRef>());
+        stats
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.nodes += other.nodes;
+        self.detached_nodes += other.detached_nodes;
+        self.cached_wrappers += other.cached_wrappers;
+        self.strong_wrappers += other.strong_wrappers;
+        self.weak_wrappers += other.weak_wrappers;
+        self.detached_strong_wrappers += other.detached_strong_wrappers;
+        self.collected_wrappers += other.collected_wrappers;
+        self.stylesheets += other.stylesheets;
+        self.approx_bytes += other.approx_bytes;
+    }
+}
+
+/// Count the subtree at `root` and add its size to `stats.approx_bytes`.
+fn walk(base: &BaseDocument, root: NodeId, stats: &mut TreeStats) -> u32 {
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(id) = stack.pop() {
+        let Some(node) = base.get_node(id) else {
+            continue;
+        };
+        count += 1;
+        stats.approx_bytes += node_bytes(node);
+        stack.extend(node.children.iter().copied());
+    }
+    count
+}
+
+fn node_bytes(node: &Node) -> usize {
+    let owned = match &node.data {
+        NodeData::Text(text) => text.content.len(),
+        _ => node
+            .attrs()
+            .map(|attrs| attrs.iter().map(|attr| attr.value.len()).sum())
+            .unwrap_or(0),
+    };
+    size_of::<Node>() + owned
+}
+
+/// The topmost stored ancestor of `node_id`, or `None` if the node itself
+/// is no longer stored.
+fn subtree_root(base: &BaseDocument, node_id: NodeId) -> Option<NodeId> {
+    base.get_node(node_id)?;
+    let mut top = node_id;
+    while let Some(parent) = base.get_node(top).and_then(|n| n.parent) {
+        if base.get_node(parent).is_none() {
+            break;
+        }
+        top = parent;
+    }
+    Some(top)
+}
+
+fn count_stylesheets(base: &BaseDocument, root: NodeId) -> u32 {
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(id) = stack.pop() {
+        let Some(node) = base.get_node(id) else {
+            continue;
+        };
+        if is_stylesheet(node) {
+            count += 1;
+        }
+        stack.extend(node.children.iter().copied());
+    }
+    count
+}
+
+fn is_stylesheet(node: &Node) -> bool {
+    let Some(el) = node.element_data() else {
+        return false;
+    };
+    if el.name.local == local_name!("style") {
+        return true;
+    }
+    el.name.local == local_name!("link")
+        && node.attr(local_name!("rel")).is_some_and(|rel| {
+            rel.split_ascii_whitespace()
+                .any(|token| token.eq_ignore_ascii_case("stylesheet"))
+        })
+}
+
+#[napi]
+impl NativeDoc {
+    /// Count the document's nodes, cached JS wrappers, stylesheets and
+    /// fonts. Walks the whole tree: meant for diagnostics, not per frame.
+    #[napi]
+    pub fn stats(&mut self) -> Result<DocStats> {
+        self.doc.check_live()?;
+        let tree = TreeStats::collect(&self.doc);
+        let font_families = self
+            .font_ctx
+            .as_mut()
+            .ok_or_else(|| Error::from_reason("document is disposed"))?
+            .collection
+            .family_names()
+            .count();
+        Ok(DocStats {
+            nodes: tree.nodes,
+            detached_nodes: tree.detached_nodes,
+            cached_wrappers: tree.cached_wrappers,
+            strong_wrappers: tree.strong_wrappers,
+            weak_wrappers: tree.weak_wrappers,
+            detached_strong_wrappers: tree.detached_strong_wrappers,
+            collected_wrappers: tree.collected_wrappers,
+            stylesheets: tree.stylesheets,
+            font_families: font_families as u32,
+            approx_bytes: tree.approx_bytes as f64,
+        })
+    }
+}
+
+/// Sum `NativeDoc.stats` over the live documents of the calling
+/// environment. Font collections are per document and not summed.
+#[napi]
+pub fn addon_stats(env: Env) -> AddonStats {
+    let documents = live_documents(&env);
+    let mut total = TreeStats::default();
+    for doc in &documents {
+        total.add(&TreeStats::collect(doc));
+    }
+    AddonStats {
+        documents: documents.len() as u32,
+        nodes: total.nodes,
+        detached_nodes: total.detached_nodes,
+        cached_wrappers: total.cached_wrappers,
+        strong_wrappers: total.strong_wrappers,
+        weak_wrappers: total.weak_wrappers,
+        detached_strong_wrappers: total.detached_strong_wrappers,
+        collected_wrappers: total.collected_wrappers,
+        stylesheets: total.stylesheets,
+        approx_bytes: total.approx_bytes as f64,
+    }
+}
@@ -0,0 +1,91 @@
+//! Selection state of `<input>` / `<textarea>` editors.
+//!
+//! The parley editor inside `TextInputData` addresses text by UTF-8 byte
+//! offset; the DOM (`selectionStart`, `setRangeText`, ...) counts UTF-16
+//! code units. The conversions live here, together with `sync_selection`,
+//! which fires `selectionchange` (and `select` for a non-empty selection)
+//! when an editor's selection differs from the one last reported to JS.
+//!
+//! Like focus, selection changes come from blitz's own key / pointer
+//! handling as well as from JS, so the check runs after each UI event
+//! (for the focused control) and after each JS selection API call.
+
+use std::rc::Rc;
+
+use blitz::dom::{BaseDocument, NodeId};
+use napi::{Env, Result};
+
+use crate::dom::{doc::SharedDoc, event::dispatch_synthetic, payload::EventPayload};
+
+/// Editor selection as `(anchor, focus)` byte offsets, or `None` when the
+/// node has no live text editor (not a text control, or not laid out yet).
+pub(crate) fn selection_bytes(base: &BaseDocument, node_id: NodeId) -> Option<(usize, usize)> {
+    let editor = &base
+        .get_node(node_id)?
+        .element_data()?
+        .text_input_data()?
+        .editor;
+    let selection = editor.raw_selection();
+    Some((selection.anchor().index(), selection.focus().index()))
+}
+
+/// Editor text, or `None` when the node has no live text editor.
+pub(crate) fn editor_text(base: &BaseDocument, node_id: NodeId) -> Option<String> {
+    let input = base.get_node(node_id)?.element_data()?.text_input_data()?;
+    Some(input.editor.text().to_string())
+}
+
+/// UTF-16 offset → byte offset, clamped to the text length and rounded
+/// down to a char boundary (an offset inside a surrogate pair lands
+/// before the pair).
+pub(crate) fn utf16_to_byte(text: &str, offset: u32) -> usize {
+    let mut units = 0u32;
+    for (byte, ch) in text.char_indices() {
+        units += ch.len_utf16() as u32;
+        if units > offset {
+            return byte;
+        }
+    }
+    text.len()
+}
+
+/// Byte offset → UTF-16 offset.
+pub(crate) fn byte_to_utf16(text: &str, byte: usize) -> u32 {
+    text[..byte.min(text.len())]
+        .chars()
+        .map(|c| c.len_utf16() as u32)
+        .sum()
+}
+
+/// Fire `selectionchange` at `node_id` if its editor selection differs
+/// from the last one reported, followed by `select` when the new
+/// selection is not collapsed.
+pub(crate) fn sync_selection(doc: &Rc<SharedDoc>, node_id: NodeId, env: &Env) -> Result<()> {
+    let Some((anchor, focus)) = selection_bytes(&doc.base.borrow(), node_id) else {
+        return Ok(());
+    };
+    let current = Some((node_id, anchor, focus));
+    if doc.reported_selection.replace(current) == current {
+        return Ok(());
+    }
+    dispatch_synthetic(
+        doc,
+        node_id,
+        EventPayload::new("selectionchange", true, false),
+        env,
+    )?;
+    if anchor != focus {
+        dispatch_synthetic(doc, node_id, EventPayload::new("select", true, false), env)?;
+    }
+    Ok(())
+}
+
+/// `sync_selection` for the focused node, if it is a text control. Run
+/// after each UI event.
+pub(crate) fn sync_focused_selection(doc: &Rc<SharedDoc>, env: &Env) -> Result<()> {
+    let focused = doc.base.borrow().get_focussed_node_id();
+    match focused {
+        Some(node_id) => sync_selection(doc, node_id, env),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,140 @@
+//! Touch events from finger pointers.
+//!
+//! blitz reports touchscreen contacts as pointer events with a
+//! `BlitzPointerId::Finger` id. After blitz and JS have handled such an
+//! event, `handle_touch_pointer` fires the matching `touchstart` /
+//! `touchmove` / `touchend` / `touchcancel`. As in browsers, every touch
+//! event of a contact targets the element it started on, even once the
+//! finger has moved off it.
+//!
+//! By then the pointer event's default actions (focus, selection, click)
+//! have run, so touch events are not cancelable: they behave like the
+//! passive touch listeners browsers default to. Cancel the pointer event
+//! instead.
+//!
+//! `SharedDoc.touches` tracks the contacts between events, so each event
+//! can list all of them (`touches`), those on its target (`targetTouches`)
+//! and the ones that changed (`changedTouches`, always a single contact).
+
+use std::{rc::Rc, sync::Arc};
+
+use blitz::{
+    dom::NodeId,
+    traits::events::{BlitzPointerEvent, BlitzPointerId, UiEvent},
+};
+use napi::{Env, Result};
+
+use crate::dom::{
+    doc::SharedDoc,
+    event::dispatch_synthetic,
+    ops::{body_or_root, hit_element},
+    payload::{EventPayload, TouchData, TouchPoint},
+};
+
+/// A finger on the screen.
+struct Contact {
+    id: u64,
+    /// Element the contact started on.
+    target: NodeId,
+    /// Latest sample.
+    pointer: BlitzPointerEvent,
+}
+
+/// Fingers currently down, in order of contact.
+#[derive(Default)]
+pub struct Touches(Vec<Contact>);
+
+impl Touches {
+    /// Whether finger `id` is, or becomes with its press, the primary
+    /// pointer: the first finger down.
+    pub fn is_primary(&self, id: u64) -> bool {
+        self.0.first().is_none_or(|c| c.id == id)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Fire the (non-cancelable) touch event for a finger pointer event. Runs
+/// after blitz and JS have handled the pointer event.
+pub(crate) fn handle_touch_pointer(doc: &Rc<SharedDoc>, event: &UiEvent, env: &Env) -> Result<()> {
+    let (pointer, event_type) = match event {
+        UiEvent::PointerDown(pointer) => (pointer, "touchstart"),
+        UiEvent::PointerMove(pointer) => (pointer, "touchmove"),
+        UiEvent::PointerUp(pointer) => (pointer, "touchend"),
+        UiEvent::PointerCancel(pointer) => (pointer, "touchcancel"),
+        _ => return Ok(()),
+    };
+    let BlitzPointerId::Finger(id) = pointer.id else {
+        return Ok(());
+    };
+
+    let (changed, touches, target_touches) = {
+        let mut touches = doc.touches.borrow_mut();
+        let contacts = &mut touches.0;
+        let index = contacts.iter().position(|c| c.id == id);
+        let changed = match (event_type, index) {
+            ("touchstart", _) => {
+                if let Some(index) = index {
+                    contacts.remove(index);
+                }
+                let target = {
+                    let base = doc.base.borrow();
+                    hit_element(
+                        &base,
+                        pointer.coords.client_x as f64,
+                        pointer.coords.client_y as f64,
+                    )
+                    .unwrap_or_else(|| body_or_root(&base))
+                };
+                contacts.push(Contact {
+                    id,
+                    target,
+                    pointer: pointer.clone(),
+                });
+                touch_point(doc, &contacts[contacts.len() - 1])
+            }
+            // A finger whose press never reached the document.
+            (_, None) => return Ok(()),
+            ("touchmove", Some(index)) => {
+                contacts[index].pointer = pointer.clone();
+                touch_point(doc, &contacts[index])
+            }
+            (_, Some(index)) => {
+                let mut contact = contacts.remove(index);
+                contact.pointer = pointer.clone();
+                touch_point(doc, &contact)
+            }
+        };
+        let all: Vec<_> = contacts.iter().map(|c| touch_point(doc, c)).collect();
+        let on_target = contacts
+            .iter()
+            .filter(|c| c.target == changed.target)
+            .map(|c| touch_point(doc, c))
+            .collect();
+        (changed, all, on_target)
+    };
+
+    let target = changed.target;
+    let payload = EventPayload {
+        touch: Some(TouchData {
+            touches,
+            target_touches,
+            changed_touches: vec![changed],
+            mods: pointer.mods,
+        }),
+        ..EventPayload::new(event_type, true, false)
+    };
+    dispatch_synthetic(doc, target, payload, env)?;
+    Ok(())
+}
+
+fn touch_point(doc: &Rc<SharedDoc>, contact: &Contact) -> TouchPoint {
+    TouchPoint {
+        doc: Rc::clone(doc),
+        identifier: contact.id as f64,
+        target: contact.target,
+        pointer: Arc::new(contact.pointer.clone()),
+    }
+}
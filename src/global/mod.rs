@@ -1,22 +1,25 @@
-//! Global addon-level state: JS constructor refs, event factory, napi env.
+//! Addon-level state: JS constructor refs, event factory and dispatch fn.
 //!
-//! These are registered once during addon init and never change.
-//! All documents share them. Accessed only from the JS thread.
+//! Registered once per N-API environment during addon init; all documents
+//! of that environment share them. The main thread, each `worker_threads`
+//! Worker and each context that loads the addon get their own environment,
+//! so the registry lives in that environment's instance data rather than in
+//! process- or thread-global storage. Node drops it (releasing the JS
+//! references) when the environment is torn down.
 //!
-//! Uses `thread_local!` because `Env` and `FunctionRef` are not
-//! `Send`/`Sync`. Node.js runs JS on a single thread, so thread-local
-//! storage is correct and avoids unsafe `Sync` impls.
+//! Callbacks that receive no `Env` (e.g. `EventHandler::handle_event`) use
+//! the one recorded by the document they run for (`SharedDoc::env`).
 
 use std::{
-    cell::{Cell, RefCell},
+    cell::RefCell,
     collections::HashMap,
-    rc::Rc,
+    rc::{Rc, Weak},
 };
 
-use crate::dom::{node_handle::NativeNode, payload::EventPayload};
+use crate::dom::{doc::SharedDoc, node_handle::NativeNode, payload::EventPayload};
 use blitz::dom::{LocalName, Namespace};
 use napi::{
-    Env, Error, Result, Status, Unknown,
+    Env, Error, Result, Unknown,
     bindgen_prelude::{FnArgs, FunctionRef, ObjectRef},
 };
 // ── Type aliases ──────────────────────────────────────────────────────
@@ -27,7 +30,8 @@ type ElementConstructor =
 type EventFactory = FunctionRef<FnArgs<(EventPayload,)>, Option<Unknown<'static>>>;
 type DispatchFn = FunctionRef<FnArgs<(ObjectRef, ObjectRef)>, Option<Unknown<'static>>>;
 
-struct GlobalRegistry {
+#[derive(Default)]
+struct Registry {
     /// nodeType -> JS constructor function: `new (handle, doc) -> Node`
     node_constructors: RefCell<HashMap<u32, Rc<NodeConstructor>>>,
     /// (namespace, local name) -> JS constructor function
@@ -36,125 +40,144 @@ struct GlobalRegistry {
     event_factory_ref: RefCell<Option<Rc<EventFactory>>>,
     /// JS dispatchEvent function: `(target, event) -> void`
     dispatch_fn_ref: RefCell<Option<Rc<DispatchFn>>>,
-    /// napi env (stable for addon lifetime in Node.js)
-    env: Cell<Option<Env>>,
-}
-
-thread_local! {
-    static GLOBAL_REGISTRY: GlobalRegistry = GlobalRegistry {
-        node_constructors: RefCell::new(HashMap::new()),
-        element_constructors: RefCell::new(HashMap::new()),
-        event_factory_ref: RefCell::new(None),
-        dispatch_fn_ref: RefCell::new(None),
-        env: Cell::new(None),
-    };
-}
-
-pub fn set_env(env: Env) {
-    GLOBAL_REGISTRY.with(|g| g.env.set(Some(env)));
-}
-
-pub fn env() -> Result<Env> {
-    GLOBAL_REGISTRY.with(|g| {
-        g.env
-            .get()
-            .ok_or_else(|| Error::new(Status::GenericFailure, "GlobalCreators env not initialized"))
-    })
-}
-
-pub fn insert_node_constructor(node_type: u32, ctor: NodeConstructor) {
-    GLOBAL_REGISTRY.with(|g| {
-        g.node_constructors
-            .borrow_mut()
-            .insert(node_type, Rc::new(ctor))
-    });
+    /// Documents created in this environment, for `addonStats`.
+    documents: RefCell<Vec<Weak<SharedDoc>>>,
+}
+
+/// The registry of `env`, created on first use.
+fn registry(env: &Env) -> Result<&'static Registry> {
+    if env.get_instance_data::<Registry>()?.is_none() {
+        // The finalizer runs at environment teardown, while the env can
+        // still release the registry's references.
+        env.set_instance_data(Registry::default(), (), |ctx| drop(ctx.value))?;
+    }
+    env.get_instance_data::<Registry>()?
+        .map(|registry| &*registry)
+        .ok_or_else(|| Error::from_reason("addon registry unavailable"))
+}
+
+pub fn insert_node_constructor(env: &Env, node_type: u32, ctor: NodeConstructor) -> Result<()> {
+    registry(env)?
+        .node_constructors
+        .borrow_mut()
+        .insert(node_type, Rc::new(ctor));
+    Ok(())
 }
 
-pub fn get_node_constructor(node_type: u32) -> Option<Rc<NodeConstructor>> {
-    GLOBAL_REGISTRY.with(|g| g.node_constructors.borrow().get(&node_type).cloned())
+pub fn get_node_constructor(env: &Env, node_type: u32) -> Option<Rc<NodeConstructor>> {
+    let registry = registry(env).ok()?;
+    registry.node_constructors.borrow().get(&node_type).cloned()
 }
 
-pub fn insert_element_constructor(ns: Namespace, local: LocalName, ctor: ElementConstructor) {
-    GLOBAL_REGISTRY.with(|g| {
-        g.element_constructors
-            .borrow_mut()
-            .insert((ns, local), Rc::new(ctor))
-    });
+pub fn insert_element_constructor(
+    env: &Env,
+    ns: Namespace,
+    local: LocalName,
+    ctor: ElementConstructor,
+) -> Result<()> {
+    registry(env)?
+        .element_constructors
+        .borrow_mut()
+        .insert((ns, local), Rc::new(ctor));
+    Ok(())
 }
 
 pub fn get_element_constructor(
+    env: &Env,
     ns: &Namespace,
     local: &LocalName,
 ) -> Option<Rc<ElementConstructor>> {
-    GLOBAL_REGISTRY.with(|g| {
-        g.element_constructors
-            .borrow()
-            .get(&(ns.clone(), local.clone()))
-            .cloned()
-    })
+    let registry = registry(env).ok()?;
+    registry
+        .element_constructors
+        .borrow()
+        .get(&(ns.clone(), local.clone()))
+        .cloned()
+}
+
+pub fn set_event_factory(env: &Env, factory: EventFactory) -> Result<()> {
+    *registry(env)?.event_factory_ref.borrow_mut() = Some(Rc::new(factory));
+    Ok(())
 }
 
-pub fn set_event_factory(factory: EventFactory) {
-    GLOBAL_REGISTRY.with(|g| {
-        *g.event_factory_ref.borrow_mut() = Some(Rc::new(factory));
-    });
+pub fn get_event_factory(env: &Env) -> Option<Rc<EventFactory>> {
+    let registry = registry(env).ok()?;
+    registry.event_factory_ref.borrow().as_ref().cloned()
 }
 
-pub fn get_event_factory() -> Option<Rc<EventFactory>> {
-    GLOBAL_REGISTRY.with(|g| g.event_factory_ref.borrow().as_ref().cloned())
+pub fn set_dispatch_fn(env: &Env, dispatch_fn: DispatchFn) -> Result<()> {
+    *registry(env)?.dispatch_fn_ref.borrow_mut() = Some(Rc::new(dispatch_fn));
+    Ok(())
 }
 
-pub fn set_dispatch_fn(dispatch_fn: DispatchFn) {
-    GLOBAL_REGISTRY.with(|g| {
-        *g.dispatch_fn_ref.borrow_mut() = Some(Rc::new(dispatch_fn));
-    });
+pub fn get_dispatch_fn(env: &Env) -> Option<Rc<DispatchFn>> {
+    let registry = registry(env).ok()?;
+    registry.dispatch_fn_ref.borrow().as_ref().cloned()
 }
 
-pub fn get_dispatch_fn() -> Option<Rc<DispatchFn>> {
-    GLOBAL_REGISTRY.with(|g| g.dispatch_fn_ref.borrow().as_ref().cloned())
+/// Record a new document of `env`. Collected documents are pruned here.
+pub fn track_document(env: &Env, doc: &Rc<SharedDoc>) -> Result<()> {
+    let mut documents = registry(env)?.documents.borrow_mut();
+    documents.retain(|doc| doc.strong_count() > 0);
+    documents.push(Rc::downgrade(doc));
+    Ok(())
+}
+
+/// The documents of `env` that are neither collected nor disposed.
+pub fn live_documents(env: &Env) -> Vec<Rc<SharedDoc>> {
+    let Ok(registry) = registry(env) else {
+        return Vec::new();
+    };
+    registry
+        .documents
+        .borrow()
+        .iter()
+        .filter_map(Weak::upgrade)
+        .filter(|doc| !doc.is_disposed())
+        .collect()
 }
 
 // ── Global registration functions ─────────────────────────────────────
 
-/// One-time env injection. JS calls this during addon init (before any
-/// register_* calls) so that `global::env()` works in callbacks that don't
-/// receive an `Env` parameter (e.g. `EventHandler::handle_event`).
+/// Create this environment's registry. JS calls this during addon init,
+/// before any register_* call; the register functions also create it on
+/// demand, so calling it again is harmless.
 #[napi]
 pub fn init_env(env: Env) -> Result<()> {
-    set_env(env);
-    Ok(())
+    registry(&env).map(drop)
 }
 
 #[napi(
     ts_args_type = "nodeType: number, constructor: { new (handle: NativeNode, document: object): object }"
 )]
-pub fn register_node_constructor(node_type: u32, constructor: NodeConstructor) -> Result<()> {
-    insert_node_constructor(node_type, constructor);
-    Ok(())
+pub fn register_node_constructor(
+    env: Env,
+    node_type: u32,
+    constructor: NodeConstructor,
+) -> Result<()> {
+    insert_node_constructor(&env, node_type, constructor)
 }
 
 #[napi(
     ts_args_type = "namespace: string, tagName: string, constructor: { new (handle: NativeNode, document: object, extra?: InputDataHandle): object }"
 )]
 pub fn register_element_constructor(
+    env: Env,
     namespace: String,
     tag_name: String,
     constructor: ElementConstructor,
 ) -> Result<()> {
     let ns = Namespace::from(namespace.as_str());
     let local = LocalName::from(tag_name.to_lowercase().as_str());
-    insert_element_constructor(ns, local, constructor);
-    Ok(())
+    insert_element_constructor(&env, ns, local, constructor)
 }
 
 #[napi(ts_args_type = "factory: (payload: EventPayload) => Event")]
-pub fn register_event_factory(factory: EventFactory) -> Result<()> {
-    set_event_factory(factory);
-    Ok(())
+pub fn register_event_factory(env: Env, factory: EventFactory) -> Result<()> {
+    set_event_factory(&env, factory)
 }
 
 #[napi(ts_args_type = "dispatchFn: (target: EventTarget, event: Event) => unknown")]
-pub fn register_dispatch_fn(dispatch_fn: DispatchFn) -> Result<()> {
-    set_dispatch_fn(dispatch_fn);
-    Ok(())
+pub fn register_dispatch_fn(env: Env, dispatch_fn: DispatchFn) -> Result<()> {
+    set_dispatch_fn(&env, dispatch_fn)
 }
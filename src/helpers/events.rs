@@ -30,7 +30,7 @@ pub(crate) fn resolve_window<'a>(doc: &Rc<SharedDoc>, env: &'a Env) -> Option<Ob
 /// (so any JS return value is accepted without a napi conversion error);
 /// `None` means the factory produced no event.
 pub(crate) fn build_event_object(payload: EventPayload, env: &Env) -> Result<Object<'_>> {
-    let factory_ref = global::get_event_factory()
+    let factory_ref = global::get_event_factory(env)
         .ok_or_else(|| Error::new(Status::GenericFailure, "event_factory not registered"))?;
     let factory_fn = factory_ref.borrow_back(env)?;
     let unknown = factory_fn.call(FnArgs::from((payload,)))?;
@@ -42,7 +42,7 @@ pub(crate) fn build_event_object(payload: EventPayload, env: &Env) -> Result<Obj
 /// Call `dispatchEvent(target, event)` via the registered dispatch fn.
 /// JS exceptions and napi boundary failures propagate via `?`.
 pub(crate) fn dispatch_event(target: &Object, event: &Object, env: &Env) -> Result<()> {
-    let dispatch_ref = global::get_dispatch_fn()
+    let dispatch_ref = global::get_dispatch_fn(env)
         .ok_or_else(|| Error::new(Status::GenericFailure, "dispatch_fn not registered"))?;
     let dispatch_fn = dispatch_ref.borrow_back(env)?;
     let target_ref = target.create_ref::<true>()?;
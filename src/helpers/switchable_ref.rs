@@ -61,7 +61,6 @@ impl SwitchableRef {
     }
 
     /// Whether the reference is currently strong.
-    #[allow(unused)]
     pub(crate) fn is_strong(&self) -> bool {
         self.strong
     }
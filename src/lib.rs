@@ -6,6 +6,8 @@
 //!   every host package.
 //! - [`app`] owns the winit event loop and window lifecycle exported by
 //!   `@ylcc/napi-blitz`.
+//! - [`clipboard`] owns the swappable clipboard backend behind
+//!   `navigator.clipboard` and the copy / cut / paste events.
 //! - [`window`] owns the window handle and options types.
 //! - [`buffer_surface`] owns the headless RGBA frame path exported by
 //!   `@ylcc/wasm-blitz`.
@@ -21,6 +23,8 @@ mod dom;
 
 mod dialog;
 
+mod clipboard;
+
 #[cfg(feature = "buffer-surface")]
 mod buffer_surface;
 
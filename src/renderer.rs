@@ -5,18 +5,301 @@
 //!   `vello-hybrid`   – GPU + CPU hybrid Vello (default)
 //!   `vello-cpu-*`    – CPU-only Vello
 //!   `skia` / `skia-pixels` / `skia-softbuffer` – Skia
+//!
+//! Windows render through `CurrentRenderer`, which wraps the chosen
+//! backend to paint what blitz's painter does not know about (the
+//! document selection and the drag image, `Overlays`) over each frame.
+//!
+//! Independently of the window renderer, the `capture` feature adds a CPU
+//! path that paints a document into an RGBA buffer. It backs both the
+//! headless `BufferRenderer` and `NativeWindow.capture`.
 
 #[cfg(feature = "vello")]
-pub use anyrender_vello::VelloWindowRenderer as CurrentRenderer;
+pub use anyrender_vello::VelloWindowRenderer as Backend;
 
 #[cfg(feature = "vello-hybrid")]
-pub use anyrender_vello_hybrid::VelloHybridWindowRenderer as CurrentRenderer;
+pub use anyrender_vello_hybrid::VelloHybridWindowRenderer as Backend;
 
 #[cfg(feature = "vello-cpu-base")]
-pub use anyrender_vello_cpu::VelloCpuWindowRenderer as CurrentRenderer;
+pub use anyrender_vello_cpu::VelloCpuWindowRenderer as Backend;
 
 #[cfg(feature = "skia")]
-pub use anyrender_skia::SkiaWindowRenderer as CurrentRenderer;
+pub use anyrender_skia::SkiaWindowRenderer as Backend;
 
 #[cfg(any(feature = "skia-pixels", feature = "skia-softbuffer"))]
-pub use anyrender_skia::raster::SkiaRasterWindowRenderer as CurrentRenderer;
+pub use anyrender_skia::raster::SkiaRasterWindowRenderer as Backend;
+
+#[cfg(feature = "native-window")]
+pub use window_renderer::CurrentRenderer;
+
+#[cfg(any(feature = "native-window", feature = "capture"))]
+pub use overlay::Overlays;
+
+#[cfg(any(feature = "native-window", feature = "capture"))]
+mod overlay {
+    use anyrender::{PaintScene, Scene};
+    use blitz::{
+        dom::{BaseDocument, util::Color},
+        paint::paint_scene,
+    };
+    use peniko::{
+        Fill, Mix,
+        kurbo::{Affine, Rect},
+    };
+
+    use crate::dom::{
+        doc::SharedDoc,
+        drag::{DragImageSpot, drag_image_spot},
+        node_handle::DomRect,
+        selection::selection_rects,
+    };
+
+    /// Fill of the document selection highlight. It is painted after
+    /// blitz has painted the text, so it is translucent.
+    const SELECTION_HIGHLIGHT: Color = Color::from_rgba8(0, 120, 215, 90);
+
+    /// Opacity of the drag image.
+    const DRAG_IMAGE_ALPHA: f32 = 0.6;
+
+    /// What blitz's painter knows nothing about and the renderers paint
+    /// over a document: the selection highlight and the drag image.
+    pub struct Overlays {
+        selection: Vec<DomRect>,
+        drag_image: Option<DragImageSpot>,
+    }
+
+    impl Overlays {
+        /// The overlays of `doc`, placed by the current layout of its
+        /// tree `base`.
+        pub fn of(doc: &SharedDoc, base: &BaseDocument) -> Self {
+            Self {
+                selection: doc
+                    .selection
+                    .get()
+                    .map(|s| selection_rects(base, s))
+                    .unwrap_or_default(),
+                drag_image: drag_image_spot(doc, base),
+            }
+        }
+
+        /// Paint over `base`, already painted into `scene` at `width` x
+        /// `height` physical pixels. `scale` maps CSS pixels to the
+        /// scene's pixels.
+        pub fn paint(
+            &self,
+            scene: &mut impl PaintScene,
+            base: &BaseDocument,
+            scale: f64,
+            width: u32,
+            height: u32,
+        ) {
+            let scaled = |rect: &DomRect, dx: f64, dy: f64| {
+                Rect::new(
+                    (rect.left + dx) * scale,
+                    (rect.top + dy) * scale,
+                    (rect.right + dx) * scale,
+                    (rect.bottom + dy) * scale,
+                )
+            };
+            for rect in &self.selection {
+                scene.fill(
+                    Fill::NonZero,
+                    Default::default(),
+                    SELECTION_HIGHLIGHT,
+                    Default::default(),
+                    &scaled(rect, 0.0, 0.0),
+                );
+            }
+
+            // The drag image is the document painted once more, clipped
+            // to the dragged element and moved to the pointer.
+            if let Some(spot) = &self.drag_image {
+                let mut image = Scene::new();
+                paint_scene(&mut image, base, scale, width, height, 0, 0);
+                scene.push_layer(
+                    Mix::Normal,
+                    DRAG_IMAGE_ALPHA,
+                    Affine::IDENTITY,
+                    &scaled(&spot.source, spot.dx, spot.dy),
+                );
+                scene.append_scene(image, Affine::translate((spot.dx * scale, spot.dy * scale)));
+                scene.pop_layer();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "native-window")]
+mod window_renderer {
+    use std::{rc::Weak, sync::Arc};
+
+    use anyrender::{WindowHandle, WindowRenderer};
+
+    use super::{Backend, Overlays};
+    use crate::dom::doc::SharedDoc;
+
+    /// The window renderer: the backend, plus the document's `Overlays`
+    /// painted over each frame blitz-shell's `View` draws.
+    pub struct CurrentRenderer {
+        inner: Backend,
+        doc: Weak<SharedDoc>,
+    }
+
+    impl CurrentRenderer {
+        pub fn new(doc: Weak<SharedDoc>) -> Self {
+            Self {
+                inner: Backend::new(),
+                doc,
+            }
+        }
+    }
+
+    impl WindowRenderer for CurrentRenderer {
+        type ScenePainter<'a>
+            = <Backend as WindowRenderer>::ScenePainter<'a>
+        where
+            Self: 'a;
+
+        fn resume(&mut self, window: Arc<dyn WindowHandle>, width: u32, height: u32) {
+            self.inner.resume(window, width, height);
+        }
+
+        fn suspend(&mut self) {
+            self.inner.suspend();
+        }
+
+        fn is_active(&self) -> bool {
+            self.inner.is_active()
+        }
+
+        fn set_size(&mut self, width: u32, height: u32) {
+            self.inner.set_size(width, height);
+        }
+
+        fn render<F: FnOnce(&mut Self::ScenePainter<'_>)>(&mut self, draw_fn: F) {
+            let doc = self.doc.upgrade();
+            self.inner.render(|scene| {
+                draw_fn(scene);
+                // The view only reads the tree while it paints, so this
+                // shared borrow succeeds unless the document is disposed.
+                if let Some(doc) = &doc
+                    && let Ok(base) = doc.read()
+                {
+                    let viewport = base.viewport();
+                    let (width, height) = viewport.window_size;
+                    Overlays::of(doc, &base).paint(
+                        scene,
+                        &base,
+                        viewport.scale_f64(),
+                        width,
+                        height,
+                    );
+                }
+            });
+        }
+    }
+}
+
+#[cfg(feature = "capture")]
+pub use capture::{BufferFrame, CaptureTask, record, render_to_rgba};
+
+#[cfg(feature = "capture")]
+mod capture {
+    use anyrender::{PaintScene as _, Scene, render_to_buffer};
+    use anyrender_vello_cpu::VelloCpuImageRenderer;
+    use blitz::{
+        dom::{BaseDocument, util::Color},
+        paint::paint_scene,
+    };
+    use napi::{Env, Result, Task, bindgen_prelude::Uint8Array};
+    use peniko::{
+        Fill,
+        kurbo::{Affine, Rect},
+    };
+
+    use super::Overlays;
+
+    /// RGBA frame rendered on the CPU.
+    #[napi(object)]
+    pub struct BufferFrame {
+        /// Frame width in physical pixels.
+        pub width: u32,
+        /// Frame height in physical pixels.
+        pub height: u32,
+        /// Device scale factor used to render the frame.
+        pub scale: f64,
+        /// RGBA8 pixels, row-major, 4 bytes per pixel.
+        pub data: Uint8Array,
+    }
+
+    /// Paint a resolved document over a white canvas into a fresh RGBA8
+    /// buffer of `width` x `height` physical pixels, `overlays` on top.
+    pub fn render_to_rgba(
+        base: &mut BaseDocument,
+        overlays: &Overlays,
+        width: u32,
+        height: u32,
+        scale: f64,
+    ) -> Vec<u8> {
+        rasterize(record(base, overlays, width, height, scale), width, height)
+    }
+
+    /// Record the paint commands of a resolved document, as
+    /// `render_to_rgba` paints it. Needs the document, so it runs on the
+    /// JS thread; the recording itself can be rasterized anywhere.
+    pub fn record(
+        base: &mut BaseDocument,
+        overlays: &Overlays,
+        width: u32,
+        height: u32,
+        scale: f64,
+    ) -> Scene {
+        let mut scene = Scene::new();
+        scene.fill(
+            Fill::NonZero,
+            Default::default(),
+            Color::WHITE,
+            Default::default(),
+            &Rect::new(0.0, 0.0, width as f64, height as f64),
+        );
+        paint_scene(&mut scene, base, scale, width, height, 0, 0);
+        overlays.paint(&mut scene, base, scale, width, height);
+        scene
+    }
+
+    fn rasterize(scene: Scene, width: u32, height: u32) -> Vec<u8> {
+        render_to_buffer::<VelloCpuImageRenderer, _>(
+            |painter| painter.append_scene(scene, Affine::IDENTITY),
+            width,
+            height,
+        )
+    }
+
+    /// Rasterizes a recorded frame on the libuv thread pool, for
+    /// `NativeWindow.capture`.
+    pub struct CaptureTask {
+        pub(crate) scene: Option<Scene>,
+        pub(crate) width: u32,
+        pub(crate) height: u32,
+        pub(crate) scale: f64,
+    }
+
+    impl Task for CaptureTask {
+        type Output = Vec<u8>;
+        type JsValue = BufferFrame;
+
+        fn compute(&mut self) -> Result<Self::Output> {
+            let scene = self.scene.take().unwrap_or_else(Scene::new);
+            Ok(rasterize(scene, self.width, self.height))
+        }
+
+        fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+            Ok(BufferFrame {
+                width: self.width,
+                height: self.height,
+                scale: self.scale,
+                data: output.into(),
+            })
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Window cursor: CSS `cursor` with `url()` images, the script overrides
+//! from `NativeWindow.setCursor*`, and cursor grabbing.
+//!
+//! blitz's `View` already shows the keyword cursor of the hovered element.
+//! After every pointer move `AppHandler` calls `update_cursor`, which
+//! settles the cursor in priority order: the script override, the hovered
+//! element's first loadable `url()` image, then its keyword.
+//!
+//! Custom cursors can only be created with the active event loop, so a
+//! `setCursorImage` image is queued and created by the next
+//! `update_cursor` / `realize_pending_cursor`. `url()` cursors are loaded
+//! once per URL; only `file:` URLs are supported, decoded as PNG or ICO.
+
+use std::collections::HashMap;
+
+use blitz::dom::BaseDocument;
+use style::values::computed::image::Image;
+use url::Url;
+use winit::{
+    cursor::{Cursor, CursorIcon, CustomCursor, CustomCursorSource},
+    event_loop::ActiveEventLoop,
+    window::Window as WinitWindow,
+};
+
+/// Cursor set from script. Takes precedence over CSS until cleared.
+pub(crate) enum CursorOverride {
+    Icon(CursorIcon),
+    /// A `setCursorImage` image waiting for the event loop.
+    Pending(CustomCursorSource),
+    Custom(CustomCursor),
+}
+
+#[derive(Default)]
+pub(crate) struct CursorState {
+    pub(crate) cursor_override: Option<CursorOverride>,
+    /// The cursor is grabbed in `"locked"` mode, so raw pointer motion is
+    /// delivered as `motion` events.
+    pub(crate) locked: bool,
+    /// Cursors created for CSS `url()` images, by URL. `None` records a
+    /// URL that failed to load, so it is not retried on every move.
+    css_cursors: HashMap<String, Option<CustomCursor>>,
+}
+
+/// Apply the cursor for the element under the pointer.
+pub(crate) fn update_cursor(
+    event_loop: &dyn ActiveEventLoop,
+    window: &dyn WinitWindow,
+    cursor: &mut CursorState,
+    base: &BaseDocument,
+) {
+    realize_pending_cursor(event_loop, window, cursor);
+    let target: Cursor = match &cursor.cursor_override {
+        Some(CursorOverride::Icon(icon)) => (*icon).into(),
+        Some(CursorOverride::Custom(custom)) => custom.clone().into(),
+        Some(CursorOverride::Pending(_)) => return,
+        None => match css_url_cursor(event_loop, &mut cursor.css_cursors, base) {
+            Some(custom) => custom.into(),
+            None => base.get_cursor().unwrap_or_default().into(),
+        },
+    };
+    window.set_cursor(target);
+}
+
+/// Create and show a queued `setCursorImage` cursor.
+pub(crate) fn realize_pending_cursor(
+    event_loop: &dyn ActiveEventLoop,
+    window: &dyn WinitWindow,
+    cursor: &mut CursorState,
+) {
+    let Some(CursorOverride::Pending(source)) = cursor
+        .cursor_override
+        .take_if(|o| matches!(o, CursorOverride::Pending(_)))
+    else {
+        return;
+    };
+    match event_loop.create_custom_cursor(source) {
+        Ok(custom) => {
+            window.set_cursor(custom.clone().into());
+            cursor.cursor_override = Some(CursorOverride::Custom(custom));
+        }
+        Err(e) => eprintln!("napi-blitz: setCursorImage: {e}"),
+    }
+}
+
+/// The first loadable `url()` image of the hovered element's `cursor`.
+fn css_url_cursor(
+    event_loop: &dyn ActiveEventLoop,
+    cache: &mut HashMap<String, Option<CustomCursor>>,
+    base: &BaseDocument,
+) -> Option<CustomCursor> {
+    // Text nodes carry no styles; theirs come from the parent element.
+    let mut node = base.get_node(base.get_hover_node_id()?)?;
+    let styles = loop {
+        if let Some(styles) = node.primary_styles() {
+            break styles;
+        }
+        node = base.get_node(node.parent?)?;
+    };
+    let cursor = styles.clone_cursor();
+    cursor.images.iter().find_map(|image| {
+        let Image::Url(url) = &image.image else {
+            return None;
+        };
+        let url = url.url()?.as_str();
+        let hotspot = if image.has_hotspot {
+            (image.hotspot_x, image.hotspot_y)
+        } else {
+            (0.0, 0.0)
+        };
+        cache
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                load_url_cursor(event_loop, url, hotspot)
+                    .inspect_err(|e| eprintln!("napi-blitz: cursor {url}: {e}"))
+                    .ok()
+            })
+            .clone()
+    })
+}
+
+fn load_url_cursor(
+    event_loop: &dyn ActiveEventLoop,
+    url: &str,
+    (hotspot_x, hotspot_y): (f32, f32),
+) -> Result<CustomCursor, String> {
+    let path = Url::parse(url)
+        .map_err(|e| e.to_string())?
+        .to_file_path()
+        .map_err(|()| "only file: URLs are supported".to_string())?;
+    let image = image::open(&path).map_err(|e| e.to_string())?.into_rgba8();
+    let (width, height) = image.dimensions();
+    let source = rgba_cursor_source(
+        image.into_raw(),
+        width,
+        height,
+        hotspot_x as u32,
+        hotspot_y as u32,
+    )?;
+    event_loop
+        .create_custom_cursor(source)
+        .map_err(|e| e.to_string())
+}
+
+/// Validate an RGBA image as a cursor. The hotspot is clamped into it.
+pub(crate) fn rgba_cursor_source(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+    hotspot_x: u32,
+    hotspot_y: u32,
+) -> Result<CustomCursorSource, String> {
+    let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(format!("{width}x{height} is too large for a cursor"));
+    };
+    let hotspot_x = hotspot_x.min(width.saturating_sub(1)) as u16;
+    let hotspot_y = hotspot_y.min(height.saturating_sub(1)) as u16;
+    CustomCursorSource::from_rgba(rgba, w, h, hotspot_x, hotspot_y).map_err(|e| e.to_string())
+}
@@ -16,34 +16,47 @@
 //! reference back to the live winit `Arc<dyn Window>` - the application
 //! does. The JS layer's `Window` class delegates these calls to the app.
 
+pub(crate) mod cursor;
 pub(crate) mod handle;
 pub(crate) mod monitor;
 pub(crate) mod options;
+pub(crate) mod region;
 pub(crate) mod util;
 
 use self::{
+    cursor::{CursorOverride, CursorState, rgba_cursor_source},
     handle::WindowHandle,
     monitor::{MonitorInfo, VideoModeInfo},
 };
+#[cfg(feature = "capture")]
+use crate::renderer::{CaptureTask, Overlays, record};
 use crate::{
-    dom::doc::{NativeDoc, WindowDocument},
-    window::util::{parse_dimension, parse_window_buttons},
+    app::AppState,
+    dom::doc::{NativeDoc, SharedDoc, WindowDocument},
+    window::util::{
+        parse_coordinate, parse_dimension, parse_resize_direction, parse_user_attention,
+        parse_window_buttons, parse_window_level,
+    },
 };
+#[cfg(feature = "capture")]
+use napi::bindgen_prelude::AsyncTask;
 use napi::{
     Error, Result,
     bindgen_prelude::{BigInt, Uint8Array},
 };
 use std::{
     cell::{Ref, RefCell},
-    rc::Rc,
+    rc::{Rc, Weak},
+    str::FromStr,
     sync::Arc,
 };
 use winit::{
-    dpi::PhysicalSize,
+    cursor::{Cursor, CursorIcon},
+    dpi::{PhysicalPosition, PhysicalSize},
     icon::{Icon, RgbaIcon},
     monitor::Fullscreen,
     raw_window_handle::{HasDisplayHandle, HasWindowHandle},
-    window::{Window as WinitWindow, WindowId},
+    window::{CursorGrabMode, Window as WinitWindow, WindowId},
 };
 
 /// Shared inner state between the JS-side `Window` handle and the
@@ -52,6 +65,35 @@ use winit::{
 pub(crate) struct WindowState {
     pub(crate) window: Option<Arc<dyn WinitWindow>>,
     pub(crate) closed: bool,
+    /// Minimized state as last reported through `minimize` / `restore`.
+    pub(crate) minimized: bool,
+    pub(crate) cursor: CursorState,
+    /// Pinch gestures zoom the document (`setPinchZoom`).
+    pub(crate) pinch_zoom: bool,
+    /// Animation time the view resolved its latest frame at, so `capture`
+    /// does not move animations back.
+    pub(crate) frame_time: f64,
+}
+
+/// Insets of a window's safe area: the part of the surface not covered
+/// by notches, rounded corners or overlaid title bars. Physical pixels.
+#[napi(object)]
+pub struct WindowInsets {
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+    pub left: u32,
+}
+
+/// An RGBA cursor image for `NativeWindow.setCursorImage`.
+#[napi(object)]
+pub struct CursorImage {
+    pub width: u32,
+    pub height: u32,
+    /// `width * height * 4` bytes of RGBA.
+    pub data: Uint8Array,
+    pub hotspot_x: Option<u32>,
+    pub hotspot_y: Option<u32>,
 }
 
 /// Handle to an open window. Construct via `BlitzApp.openWindow`.
@@ -65,6 +107,10 @@ pub struct NativeWindow {
     /// it is open. Internal-only - the JS layer does not need to see this.
     pub(crate) window_id: WindowId,
     pub(crate) state: Rc<RefCell<WindowState>>,
+    /// The app that owns the window, for `dispose`.
+    pub(crate) app: Weak<RefCell<AppState>>,
+    /// The attached document, for `capture` and pointer move coalescing.
+    pub(crate) doc: Rc<SharedDoc>,
 }
 
 impl NativeWindow {
@@ -80,6 +126,17 @@ impl NativeWindow {
 
 #[napi]
 impl NativeWindow {
+    /// Close the window now, without a cancelable `close`: it is torn down
+    /// at the next pump and then gets `closed`, like a window closing with
+    /// its owner. Its owned windows close too. The document is left alone;
+    /// dispose it separately once `closed` fired. No-op once closed.
+    #[napi]
+    pub fn dispose(&self) {
+        if let Some(app) = self.app.upgrade() {
+            app.borrow_mut().force_close(self.window_id);
+        }
+    }
+
     /// Whether `closeWindow` has run for this handle.
     #[napi(getter)]
     pub fn closed(&self) -> bool {
@@ -128,12 +185,195 @@ impl NativeWindow {
         Ok(())
     }
 
+    /// Render the current document into an RGBA buffer at the window's
+    /// surface size and scale (device scale factor times zoom). Painted by
+    /// the CPU renderer, so the frame matches the window's content but not
+    /// necessarily its GPU output pixel for pixel. The document is laid
+    /// out at the window's latest frame time and recorded right away;
+    /// only the rasterization runs off the JS thread.
+    #[cfg(feature = "capture")]
+    #[napi]
+    pub fn capture(&self) -> Result<AsyncTask<CaptureTask>> {
+        let size = self.native_window()?.surface_size();
+        let frame_time = self.state.borrow().frame_time;
+        let mut base = self.doc.write()?;
+        base.resolve(frame_time);
+        let scale = base.viewport().scale_f64();
+        let overlays = Overlays::of(&self.doc, &base);
+        let scene = record(&mut base, &overlays, size.width, size.height, scale);
+        Ok(AsyncTask::new(CaptureTask {
+            scene: Some(scene),
+            width: size.width,
+            height: size.height,
+            scale,
+        }))
+    }
+
     #[napi]
     pub fn get_size(&self) -> Result<Vec<u32>> {
         let size = self.native_window()?.surface_size();
         Ok(vec![size.width, size.height])
     }
 
+    /// Outer size including decorations, as `[width, height]`.
+    #[napi]
+    pub fn get_outer_size(&self) -> Result<Vec<u32>> {
+        let size = self.native_window()?.outer_size();
+        Ok(vec![size.width, size.height])
+    }
+
+    /// Position of the outer top-left corner on the virtual desktop, as
+    /// `[x, y]`.
+    #[napi]
+    pub fn get_position(&self) -> Result<Vec<i32>> {
+        let position = self
+            .native_window()?
+            .outer_position()
+            .map_err(|e| Error::from_reason(format!("window position is unavailable: {e}")))?;
+        Ok(vec![position.x, position.y])
+    }
+
+    /// Move the outer top-left corner. Ignored on platforms that do not
+    /// let clients position windows (Wayland).
+    #[napi]
+    pub fn set_position(&self, x: f64, y: f64) -> Result<()> {
+        let x = parse_coordinate("x", x)?;
+        let y = parse_coordinate("y", y)?;
+        self.native_window()?
+            .set_outer_position(PhysicalPosition::new(x, y).into());
+        Ok(())
+    }
+
+    /// Start an OS-driven window move, as if the user grabbed the title
+    /// bar. Call from a primary-button `pointerdown` listener; the window
+    /// manager ends it when the button is released.
+    #[napi]
+    pub fn start_drag(&self) -> Result<()> {
+        self.native_window()?
+            .drag_window()
+            .map_err(|e| Error::from_reason(format!("startDrag: {e}")))
+    }
+
+    /// Start an OS-driven resize from an edge or corner: `"n"`, `"ne"`,
+    /// `"e"`, `"se"`, `"s"`, `"sw"`, `"w"` or `"nw"`. Same rules as
+    /// `startDrag`.
+    #[napi]
+    pub fn start_resize(&self, direction: String) -> Result<()> {
+        let direction = parse_resize_direction(&direction)?;
+        self.native_window()?
+            .drag_resize_window(direction)
+            .map_err(|e| Error::from_reason(format!("startResize: {e}")))
+    }
+
+    /// Override the CSS cursor with a CSS cursor keyword (`"pointer"`,
+    /// `"ew-resize"`, ...). `null` or `"auto"` hands the cursor back to
+    /// CSS from the next pointer move.
+    #[napi]
+    pub fn set_cursor(&self, cursor: Option<String>) -> Result<()> {
+        let icon = match cursor.as_deref() {
+            None | Some("auto") => None,
+            Some(name) => Some(CursorIcon::from_str(name).map_err(|_| {
+                Error::from_reason(format!("setCursor: unknown cursor \"{name}\""))
+            })?),
+        };
+        let window = self.native_window()?;
+        window.set_cursor(Cursor::from(icon.unwrap_or_default()));
+        drop(window);
+        self.state.borrow_mut().cursor.cursor_override = icon.map(CursorOverride::Icon);
+        Ok(())
+    }
+
+    /// Override the CSS cursor with an image. It is shown from the next
+    /// event-loop pump on; clear it with `setCursor(null)`.
+    #[napi]
+    pub fn set_cursor_image(&self, image: CursorImage) -> Result<()> {
+        let expected = image.width as usize * image.height as usize * 4;
+        if image.data.len() != expected {
+            return Err(Error::from_reason(format!(
+                "setCursorImage: expected {expected} bytes for {}x{} RGBA, got {}",
+                image.width,
+                image.height,
+                image.data.len()
+            )));
+        }
+        let source = rgba_cursor_source(
+            image.data.to_vec(),
+            image.width,
+            image.height,
+            image.hotspot_x.unwrap_or(0),
+            image.hotspot_y.unwrap_or(0),
+        )
+        .map_err(|e| Error::from_reason(format!("setCursorImage: {e}")))?;
+        self.native_window()?;
+        self.state.borrow_mut().cursor.cursor_override = Some(CursorOverride::Pending(source));
+        Ok(())
+    }
+
+    #[napi]
+    pub fn set_cursor_visible(&self, visible: bool) -> Result<()> {
+        self.native_window()?.set_cursor_visible(visible);
+        Ok(())
+    }
+
+    /// `"none"` | `"confined"` (kept inside the window) | `"locked"` (held
+    /// in place; the window then receives raw `motion` events). Not every
+    /// platform supports both modes: X11 cannot lock and macOS cannot
+    /// confine, which is reported as an error.
+    #[napi]
+    pub fn set_cursor_grab(&self, mode: String) -> Result<()> {
+        let grab = match mode.as_str() {
+            "none" => CursorGrabMode::None,
+            "confined" => CursorGrabMode::Confined,
+            "locked" => CursorGrabMode::Locked,
+            other => {
+                return Err(Error::from_reason(format!(
+                    "setCursorGrab: unknown mode \"{other}\", expected none/confined/locked"
+                )));
+            }
+        };
+        self.native_window()?
+            .set_cursor_grab(grab)
+            .map_err(|e| Error::from_reason(format!("setCursorGrab: {e}")))?;
+        self.state.borrow_mut().cursor.locked = grab == CursorGrabMode::Locked;
+        Ok(())
+    }
+
+    /// Deliver at most one `pointermove` per pointer and frame (the
+    /// default), with the skipped samples available from
+    /// `getCoalescedEvents()`. `false` dispatches every move as it arrives.
+    #[napi]
+    pub fn set_coalesce_pointer_moves(&self, value: bool) {
+        self.doc.pointer_moves.borrow_mut().enabled = value;
+    }
+
+    #[napi]
+    pub fn get_coalesce_pointer_moves(&self) -> bool {
+        self.doc.pointer_moves.borrow().enabled
+    }
+
+    /// Let trackpad pinches zoom the document, like `NativeApp.setZoom`,
+    /// unless a `gesturechange` listener cancels them. Off by default.
+    #[napi]
+    pub fn set_pinch_zoom(&self, value: bool) {
+        self.state.borrow_mut().pinch_zoom = value;
+    }
+
+    #[napi]
+    pub fn get_pinch_zoom(&self) -> bool {
+        self.state.borrow().pinch_zoom
+    }
+
+    #[napi]
+    pub fn get_safe_area(&self) -> Result<WindowInsets> {
+        let insets = self.native_window()?.safe_area();
+        Ok(WindowInsets {
+            top: insets.top,
+            right: insets.right,
+            bottom: insets.bottom,
+            left: insets.left,
+        })
+    }
+
     #[napi]
     pub fn get_resizable(&self) -> Result<bool> {
         Ok(self.native_window()?.is_resizable())
@@ -177,6 +417,77 @@ impl NativeWindow {
         Ok(())
     }
 
+    #[napi]
+    pub fn set_minimized(&self, value: bool) -> Result<()> {
+        self.native_window()?.set_minimized(value);
+        Ok(())
+    }
+
+    /// `None` when the platform cannot tell (Wayland).
+    #[napi]
+    pub fn is_minimized(&self) -> Result<Option<bool>> {
+        Ok(self.native_window()?.is_minimized())
+    }
+
+    #[napi]
+    pub fn is_maximized(&self) -> Result<bool> {
+        Ok(self.native_window()?.is_maximized())
+    }
+
+    #[napi]
+    pub fn is_focused(&self) -> Result<bool> {
+        Ok(self.native_window()?.has_focus())
+    }
+
+    /// Bring the window to the front and give it input focus. Platforms
+    /// may refuse to steal focus from another application.
+    #[napi]
+    pub fn focus_window(&self) -> Result<()> {
+        self.native_window()?.focus_window();
+        Ok(())
+    }
+
+    /// Flash the taskbar entry / bounce the dock icon until the window
+    /// is focused: `"critical"` or `"informational"`. `null` cancels a
+    /// pending request.
+    #[napi]
+    pub fn request_user_attention(&self, kind: Option<String>) -> Result<()> {
+        let kind = kind.as_deref().map(parse_user_attention).transpose()?;
+        self.native_window()?.request_user_attention(kind);
+        Ok(())
+    }
+
+    /// `"normal"` | `"always-on-top"` | `"always-on-bottom"`.
+    #[napi]
+    pub fn set_window_level(&self, level: String) -> Result<()> {
+        let level = parse_window_level(&level)?;
+        self.native_window()?.set_window_level(level);
+        Ok(())
+    }
+
+    /// Keep the window out of the taskbar. Windows only; ignored
+    /// elsewhere.
+    #[napi]
+    pub fn set_skip_taskbar(&self, value: bool) -> Result<()> {
+        let window = self.native_window()?;
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::WindowExtWindows;
+            window.set_skip_taskbar(value);
+        }
+        #[cfg(not(target_os = "windows"))]
+        let _ = (window, value);
+        Ok(())
+    }
+
+    /// Exclude the window's content from screenshots and screen sharing
+    /// (Windows and macOS).
+    #[napi]
+    pub fn set_content_protected(&self, value: bool) -> Result<()> {
+        self.native_window()?.set_content_protected(value);
+        Ok(())
+    }
+
     #[napi]
     pub fn set_visible(&self, value: bool) -> Result<()> {
         self.native_window()?.set_visible(value);
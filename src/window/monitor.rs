@@ -61,6 +61,22 @@ impl MonitorInfo {
         self.inner.position().unwrap_or_default().y
     }
 
+    /// Width in physical pixels, from the current video mode. `0` when
+    /// the platform does not report it.
+    #[napi(getter)]
+    pub fn width(&self) -> u32 {
+        self.inner
+            .current_video_mode()
+            .map_or(0, |vm| vm.size().width)
+    }
+
+    #[napi(getter)]
+    pub fn height(&self) -> u32 {
+        self.inner
+            .current_video_mode()
+            .map_or(0, |vm| vm.size().height)
+    }
+
     #[napi(getter)]
     pub fn scale_factor(&self) -> f64 {
         self.inner.scale_factor()
@@ -3,7 +3,17 @@ use crate::window::{
     monitor::{MonitorInfo, VideoModeInfo},
 };
 use napi::bindgen_prelude::Uint8Array;
-use winit::monitor::Fullscreen;
+use winit::monitor::{Fullscreen, MonitorHandle};
+
+/// Where a new window opens. `position` and `center` both set it; the
+/// last call wins.
+#[derive(Clone)]
+pub(crate) enum Placement {
+    /// Outer top-left corner, in physical pixels.
+    Position(f64, f64),
+    /// Centered on the given monitor, or on the one the window opens on.
+    Center(Option<MonitorHandle>),
+}
 
 /// Options accepted by `BlitzApp.openWindow`. Construct via
 /// `WindowOptions.builder()`.
@@ -11,6 +21,7 @@ use winit::monitor::Fullscreen;
 pub struct WindowOptions {
     pub(crate) title: Option<String>,
     pub(crate) size: Option<(f64, f64)>,
+    pub(crate) placement: Option<Placement>,
     pub(crate) resizable: Option<bool>,
     pub(crate) min_size: Option<(f64, f64)>,
     pub(crate) max_size: Option<(f64, f64)>,
@@ -23,6 +34,9 @@ pub struct WindowOptions {
     pub(crate) enabled_buttons: Option<Vec<String>>,
     pub(crate) window_icon: Option<Uint8Array>,
     pub(crate) parent_window: Option<WindowHandle>,
+    pub(crate) window_level: Option<String>,
+    pub(crate) skip_taskbar: Option<bool>,
+    pub(crate) content_protected: Option<bool>,
 }
 
 #[napi]
@@ -33,6 +47,7 @@ impl WindowOptions {
         Self {
             title: None,
             size: None,
+            placement: None,
             resizable: None,
             min_size: None,
             max_size: None,
@@ -45,6 +60,9 @@ impl WindowOptions {
             enabled_buttons: None,
             window_icon: None,
             parent_window: None,
+            window_level: None,
+            skip_taskbar: None,
+            content_protected: None,
         }
     }
 
@@ -60,6 +78,23 @@ impl WindowOptions {
         self
     }
 
+    /// Initial position of the window's outer top-left corner, in
+    /// physical pixels. A position that would leave the window off every
+    /// monitor (e.g. a saved layout from a monitor that is gone) is
+    /// clamped onto the nearest one.
+    #[napi]
+    pub fn position(&mut self, x: f64, y: f64) -> &Self {
+        self.placement = Some(Placement::Position(x, y));
+        self
+    }
+
+    /// Center the window on `monitor`, or on the monitor it opens on.
+    #[napi]
+    pub fn center(&mut self, monitor: Option<&MonitorInfo>) -> &Self {
+        self.placement = Some(Placement::Center(monitor.map(|m| m.inner.clone())));
+        self
+    }
+
     #[napi]
     pub fn resizable(&mut self, value: bool) -> &Self {
         self.resizable = Some(value);
@@ -141,6 +176,29 @@ impl WindowOptions {
         self
     }
 
+    /// `"normal"` | `"always-on-top"` | `"always-on-bottom"`.
+    #[napi]
+    pub fn window_level(&mut self, value: String) -> &Self {
+        self.window_level = Some(value);
+        self
+    }
+
+    /// Keep the window out of the taskbar. Windows only; ignored
+    /// elsewhere.
+    #[napi]
+    pub fn skip_taskbar(&mut self, value: bool) -> &Self {
+        self.skip_taskbar = Some(value);
+        self
+    }
+
+    /// Exclude the window's content from screenshots and screen sharing
+    /// (Windows and macOS).
+    #[napi]
+    pub fn content_protected(&mut self, value: bool) -> &Self {
+        self.content_protected = Some(value);
+        self
+    }
+
     /// Set the parent window for this window.
     ///
     /// Pass a `RawWindowHandle` obtained from `NativeWindow.windowHandle()`.
@@ -0,0 +1,60 @@
+//! App regions: declarative window dragging / resizing for frameless
+//! windows with HTML titlebars.
+//!
+//! An element opts in with the `app-region` attribute, or an inline
+//! `-app-region` declaration in its `style` attribute (stylo drops unknown
+//! properties, so stylesheet rules are not seen):
+//!
+//! - `drag`: a primary press starts an OS window move.
+//! - `resize-n`, `resize-ne`, ... `resize-nw`: a primary press starts an OS
+//!   resize from that edge / corner.
+//! - `no-drag`: opts a descendant (e.g. a titlebar button) back out.
+//!
+//! The nearest ancestor of the hit element that declares a region decides.
+//! `AppHandler::window_event` checks every primary press before the `View`
+//! sees it; a press on a region goes to the window manager, so the document
+//! receives no pointer events for it.
+
+use blitz::dom::{BaseDocument, LocalName, local_name};
+use winit::window::ResizeDirection;
+
+use crate::{dom::ops::hit_element, window::util::parse_resize_direction};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AppRegion {
+    Drag,
+    Resize(ResizeDirection),
+}
+
+/// The app region under viewport point `(x, y)` (CSS pixels), if any.
+pub(crate) fn app_region_at(base: &BaseDocument, x: f64, y: f64) -> Option<AppRegion> {
+    let mut node_id = hit_element(base, x, y)?;
+    loop {
+        let node = base.get_node(node_id)?;
+        if let Some(value) = node
+            .attr(LocalName::from("app-region"))
+            .or_else(|| node.attr(local_name!("style")).and_then(inline_app_region))
+        {
+            return parse_region(value.trim());
+        }
+        node_id = node.parent?;
+    }
+}
+
+/// `-app-region` value of an inline style declaration block.
+fn inline_app_region(style: &str) -> Option<&str> {
+    style.split(';').find_map(|decl| {
+        let (name, value) = decl.split_once(':')?;
+        (name.trim() == "-app-region").then_some(value)
+    })
+}
+
+fn parse_region(value: &str) -> Option<AppRegion> {
+    match value {
+        "drag" => Some(AppRegion::Drag),
+        _ => value
+            .strip_prefix("resize-")
+            .and_then(|direction| parse_resize_direction(direction).ok())
+            .map(AppRegion::Resize),
+    }
+}
@@ -1,9 +1,13 @@
-use crate::window::options::WindowOptions;
+use crate::window::options::{Placement, WindowOptions};
 use napi::{Error, bindgen_prelude::Uint8Array};
 use winit::{
-    dpi::PhysicalSize,
+    dpi::{PhysicalPosition, PhysicalSize},
     icon::{Icon, RgbaIcon},
-    window::{WindowAttributes, WindowButtons},
+    monitor::MonitorHandle,
+    window::{
+        ResizeDirection, UserAttentionType, Window as WinitWindow, WindowAttributes, WindowButtons,
+        WindowLevel,
+    },
 };
 
 pub(crate) fn parse_dimension(name: &str, value: f64) -> napi::Result<u32> {
@@ -22,6 +26,21 @@ pub(crate) fn parse_dimension(name: &str, value: f64) -> napi::Result<u32> {
     Ok(value as u32)
 }
 
+pub(crate) fn parse_coordinate(name: &str, value: f64) -> napi::Result<i32> {
+    if !value.is_finite() {
+        return Err(Error::from_reason(format!("{name} must be finite")));
+    }
+    if value.fract() != 0.0 {
+        return Err(Error::from_reason(format!("{name} must be an integer")));
+    }
+    if value < i32::MIN as f64 || value > i32::MAX as f64 {
+        return Err(Error::from_reason(format!(
+            "{name} is out of the i32 range"
+        )));
+    }
+    Ok(value as i32)
+}
+
 /// Translate `WindowOptions` into a winit `WindowAttributes`. Skipped
 /// fields fall back to winit's platform default.
 pub(crate) fn build_window_attributes(
@@ -40,6 +59,11 @@ pub(crate) fn build_window_attributes(
         let h = parse_dimension("height", h)?;
         attrs = attrs.with_surface_size(PhysicalSize::new(w, h));
     }
+    if let Some(Placement::Position(x, y)) = options.placement {
+        let x = parse_coordinate("x", x)?;
+        let y = parse_coordinate("y", y)?;
+        attrs = attrs.with_position(PhysicalPosition::new(x, y));
+    }
     if let Some(resizable) = options.resizable {
         attrs = attrs.with_resizable(resizable);
     }
@@ -77,6 +101,17 @@ pub(crate) fn build_window_attributes(
     if let Some(icon_data) = options.window_icon.as_ref() {
         attrs = attrs.with_window_icon(Some(parse_window_icon(icon_data)?));
     }
+    if let Some(level) = options.window_level.as_deref() {
+        attrs = attrs.with_window_level(parse_window_level(level)?);
+    }
+    if let Some(protected) = options.content_protected {
+        attrs = attrs.with_content_protected(protected);
+    }
+    #[cfg(target_os = "windows")]
+    if let Some(skip) = options.skip_taskbar {
+        use winit::platform::windows::WindowAttributesExtWindows;
+        attrs = attrs.with_skip_taskbar(skip);
+    }
     if let Some(parent) = options.parent_window.as_ref() {
         attrs = unsafe { attrs.with_parent_window(Some(parent.window)) };
     }
@@ -102,6 +137,53 @@ pub(crate) fn parse_window_buttons(buttons: &[String]) -> napi::Result<WindowBut
     Ok(flags)
 }
 
+/// Parse `"normal"`, `"always-on-top"` or `"always-on-bottom"`.
+pub(crate) fn parse_window_level(level: &str) -> napi::Result<WindowLevel> {
+    Ok(match level {
+        "normal" => WindowLevel::Normal,
+        "always-on-top" => WindowLevel::AlwaysOnTop,
+        "always-on-bottom" => WindowLevel::AlwaysOnBottom,
+        other => {
+            return Err(Error::from_reason(format!(
+                "windowLevel: unknown level \"{other}\", expected normal/always-on-top/always-on-bottom"
+            )));
+        }
+    })
+}
+
+/// Parse `"critical"` or `"informational"`.
+pub(crate) fn parse_user_attention(kind: &str) -> napi::Result<UserAttentionType> {
+    Ok(match kind {
+        "critical" => UserAttentionType::Critical,
+        "informational" => UserAttentionType::Informational,
+        other => {
+            return Err(Error::from_reason(format!(
+                "requestUserAttention: unknown kind \"{other}\", expected critical/informational"
+            )));
+        }
+    })
+}
+
+/// Parse a compass direction (`"n"`, `"ne"`, ... `"nw"`) into the edge or
+/// corner a window resize starts from.
+pub(crate) fn parse_resize_direction(direction: &str) -> napi::Result<ResizeDirection> {
+    Ok(match direction {
+        "n" => ResizeDirection::North,
+        "ne" => ResizeDirection::NorthEast,
+        "e" => ResizeDirection::East,
+        "se" => ResizeDirection::SouthEast,
+        "s" => ResizeDirection::South,
+        "sw" => ResizeDirection::SouthWest,
+        "w" => ResizeDirection::West,
+        "nw" => ResizeDirection::NorthWest,
+        other => {
+            return Err(Error::from_reason(format!(
+                "resize direction: unknown direction \"{other}\", expected n/ne/e/se/s/sw/w/nw"
+            )));
+        }
+    })
+}
+
 /// Parse window icon from raw bytes. Expected layout:
 /// `[width_u32_le, height_u32_le, ...rgba8_pixels]` (8 byte header + w*h*4 bytes).
 pub(crate) fn parse_window_icon(data: &Uint8Array) -> napi::Result<Icon> {
@@ -130,3 +212,65 @@ pub(crate) fn parse_window_icon(data: &Uint8Array) -> napi::Result<Icon> {
         .map(Icon::from)
         .map_err(|e| Error::from_reason(format!("windowIcon: failed to create icon: {e}")))
 }
+
+/// Finish a window's `WindowOptions` placement once it exists: centering
+/// needs the real outer size, and an explicit position is clamped onto
+/// the nearest monitor when the window's top-left corner is on none of
+/// them.
+pub(crate) fn place_window(window: &dyn WinitWindow, placement: &Placement) {
+    let outer = window.outer_size();
+    match placement {
+        Placement::Center(monitor) => {
+            let Some((origin, size)) = monitor
+                .clone()
+                .or_else(|| window.current_monitor())
+                .or_else(|| window.primary_monitor())
+                .and_then(|m| monitor_rect(&m))
+            else {
+                return;
+            };
+            let x = origin.x + (size.width as i32 - outer.width as i32) / 2;
+            let y = origin.y + (size.height as i32 - outer.height as i32) / 2;
+            window.set_outer_position(PhysicalPosition::new(x, y).into());
+        }
+        Placement::Position(..) => {
+            let Ok(position) = window.outer_position() else {
+                return;
+            };
+            let rects: Vec<_> = window
+                .available_monitors()
+                .filter_map(|m| monitor_rect(&m))
+                .collect();
+            let on_monitor = |(origin, size): &(PhysicalPosition<i32>, PhysicalSize<u32>)| {
+                (origin.x..origin.x + size.width as i32).contains(&position.x)
+                    && (origin.y..origin.y + size.height as i32).contains(&position.y)
+            };
+            if rects.is_empty() || rects.iter().any(on_monitor) {
+                return;
+            }
+            let distance = |(origin, size): &(PhysicalPosition<i32>, PhysicalSize<u32>)| {
+                let dx = position.x - position.x.clamp(origin.x, origin.x + size.width as i32);
+                let dy = position.y - position.y.clamp(origin.y, origin.y + size.height as i32);
+                i64::from(dx).pow(2) + i64::from(dy).pow(2)
+            };
+            let (origin, size) = rects.iter().min_by_key(|&r| distance(r)).unwrap();
+            let x = position
+                .x
+                .min(origin.x + size.width as i32 - outer.width as i32)
+                .max(origin.x);
+            let y = position
+                .y
+                .min(origin.y + size.height as i32 - outer.height as i32)
+                .max(origin.y);
+            window.set_outer_position(PhysicalPosition::new(x, y).into());
+        }
+    }
+}
+
+/// Origin and size of a monitor in the virtual desktop, in physical
+/// pixels. `None` when the platform does not report them.
+pub(crate) fn monitor_rect(
+    monitor: &MonitorHandle,
+) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+    Some((monitor.position()?, monitor.current_video_mode()?.size()))
+}